@@ -0,0 +1,1144 @@
+use crate::base::{
+    Scalar,
+    ScalarFloat,
+};
+use crate::matrix::{
+    Matrix2x2,
+    Matrix2x3,
+    Matrix3x3,
+    Matrix3x4,
+    Matrix4x4,
+};
+use crate::vector::{
+    Vector2,
+    Vector3,
+};
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::isometry::{
+    Isometry2,
+    Isometry3,
+};
+use crate::reflection::{
+    Reflection2,
+    Reflection3,
+};
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+use crate::scale::{
+    Scale2,
+    Scale3,
+};
+use crate::shear::{
+    Shear2,
+    Shear3,
+};
+use crate::similarity::{
+    Similarity2,
+    Similarity3,
+};
+use crate::translation::{
+    Translation2,
+    Translation3,
+};
+use crate::transform::{
+    Transform2,
+    Transform3,
+};
+use crate::transformation::{
+    Transformation2,
+    Transformation3,
+};
+
+use core::fmt;
+use core::ops;
+
+
+/// A two-dimensional affine transformation.
+///
+/// An affine transformation is a linear transformation followed by a 
+/// translation. In homogeneous coordinates, its matrix has a last row of 
+/// `[0 0 1]`. Because the last row is always known, the 
+/// transformation stores only the top 2 rows of the homogeneous matrix, and 
+/// its inverse only requires inverting the 2x2 linear part, which is much 
+/// cheaper than the general inverse of a [`Transform2`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine2<S> {
+    /// The top 2 rows of the homogeneous matrix of the transformation.
+    matrix: Matrix2x3<S>,
+}
+
+impl<S> Affine2<S> 
+where 
+    S: Scalar 
+{
+    /// Construct an affine transformation from the top 2 rows of its 
+    /// homogeneous matrix.
+    ///
+    /// The first 2 columns of `matrix` are the linear part of the 
+    /// transformation, and the last column is the translation.
+    #[inline]
+    pub fn from_matrix(matrix: Matrix2x3<S>) -> Affine2<S> {
+        Affine2 {
+            matrix: matrix,
+        }
+    }
+
+    /// Construct an affine transformation from its linear part and its 
+    /// translation.
+    #[inline]
+    pub fn from_parts(linear: &Matrix2x2<S>, translation: &Vector2<S>) -> Affine2<S> {
+        let matrix = Matrix2x3::new(
+            linear.c0r0, linear.c0r1,
+            linear.c1r0, linear.c1r1,
+            translation.x, translation.y
+        );
+
+        Affine2 {
+            matrix: matrix,
+        }
+    }
+
+    /// Construct an affine transformation from a homogeneous matrix.
+    ///
+    /// The function returns `None` if the last row of `matrix` is not exactly
+    /// `[0 0 1]`.
+    #[inline]
+    pub fn try_from_affine_matrix(matrix: &Matrix3x3<S>) -> Option<Affine2<S>> {
+        if matrix.c0r2.is_zero() && matrix.c1r2.is_zero() && matrix.c2r2.is_one() {
+            Some(Affine2::from_affine_matrix_unchecked(matrix))
+        } else {
+            None
+        }
+    }
+
+    /// Construct an affine transformation from the top 2 rows of a 
+    /// homogeneous matrix, ignoring the last row. This function is for 
+    /// internal use in implementing type conversions.
+    #[inline]
+    pub(crate) fn from_affine_matrix_unchecked(matrix: &Matrix3x3<S>) -> Affine2<S> {
+        let affine_matrix = Matrix2x3::new(
+            matrix.c0r0, matrix.c0r1,
+            matrix.c1r0, matrix.c1r1,
+            matrix.c2r0, matrix.c2r1
+        );
+
+        Affine2::from_matrix(affine_matrix)
+    }
+
+    /// Get a reference to the top 2 rows of the homogeneous matrix of the 
+    /// transformation.
+    #[inline]
+    pub fn matrix(&self) -> &Matrix2x3<S> {
+        &self.matrix
+    }
+
+    /// Get the linear part of the affine transformation.
+    #[inline]
+    pub fn linear(&self) -> Matrix2x2<S> {
+        Matrix2x2::new(
+            self.matrix.c0r0, self.matrix.c0r1,
+            self.matrix.c1r0, self.matrix.c1r1
+        )
+    }
+
+    /// Get the translation part of the affine transformation.
+    #[inline]
+    pub fn translation(&self) -> Vector2<S> {
+        Vector2::new(self.matrix.c2r0, self.matrix.c2r1)
+    }
+
+    /// Construct the identity transformation.
+    #[inline]
+    pub fn identity() -> Affine2<S> {
+        Affine2::from_parts(&Matrix2x2::identity(), &Vector2::zero())
+    }
+
+    /// Convert an affine transformation into its homogeneous matrix.
+    #[rustfmt::skip]
+    #[inline]
+    pub fn to_affine_matrix(&self) -> Matrix3x3<S> {
+        let zero = S::zero();
+        let one = S::one();
+
+        Matrix3x3::new(
+            self.matrix.c0r0, self.matrix.c0r1, zero,
+            self.matrix.c1r0, self.matrix.c1r1, zero,
+            self.matrix.c2r0, self.matrix.c2r1, one
+        )
+    }
+
+    /// Convert an affine transformation into a generic transformation.
+    #[inline]
+    pub fn to_transform(&self) -> Transform2<S> {
+        Transform2::from_matrix_unchecked(self.to_affine_matrix())
+    }
+
+    /// Apply the affine transformation to a vector.
+    ///
+    /// Vectors are displacements, so the translation part of the 
+    /// transformation does not affect them.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine2,
+    /// #     Matrix2x3,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x3::new(
+    ///     2_f64, 0_f64,
+    ///     1_f64, 3_f64,
+    ///     4_f64, 5_f64
+    /// );
+    /// let affine = Affine2::from_matrix(matrix);
+    /// let vector = Vector2::new(1_f64, 2_f64);
+    /// let expected = Vector2::new(4_f64, 6_f64);
+    /// let result = affine.transform_vector(&vector);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        self.linear() * vector
+    }
+
+    /// Apply the affine transformation to a point.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine2,
+    /// #     Matrix2x3,
+    /// #     Point2,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x3::new(
+    ///     2_f64, 0_f64,
+    ///     1_f64, 3_f64,
+    ///     4_f64, 5_f64
+    /// );
+    /// let affine = Affine2::from_matrix(matrix);
+    /// let point = Point2::new(1_f64, 2_f64);
+    /// let expected = Point2::new(8_f64, 11_f64);
+    /// let result = affine.transform_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Point2<S>) -> Point2<S> {
+        Point2::from_vector(self.matrix * point.to_homogeneous())
+    }
+}
+
+impl<S> Affine2<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Compute the inverse of an affine transformation if it exists.
+    ///
+    /// The inverse of the affine transformation `x -> A * x + t` is the affine 
+    /// transformation `x -> inverse(A) * x - inverse(A) * t`, so only the 
+    /// linear part `A` needs to be inverted. The function returns `None` when 
+    /// the linear part is singular.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine2,
+    /// #     Matrix2x3,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let matrix = Matrix2x3::new(
+    ///     2_f64, 0_f64,
+    ///     1_f64, 3_f64,
+    ///     4_f64, 5_f64
+    /// );
+    /// let affine = Affine2::from_matrix(matrix);
+    /// let affine_inv = affine.inverse().unwrap();
+    /// let point = Point2::new(1_f64, 2_f64);
+    /// let result = affine_inv.transform_point(&affine.transform_point(&point));
+    ///
+    /// assert!(relative_eq!(result, point, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Affine2<S>> {
+        self.linear().inverse().map(|linear_inv| {
+            let translation_inv = -(linear_inv * self.translation());
+
+            Affine2::from_parts(&linear_inv, &translation_inv)
+        })
+    }
+
+    /// Determine whether an affine transformation is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.linear().is_invertible()
+    }
+
+    /// Apply the inverse of the affine transformation to a vector.
+    ///
+    /// The function returns `None` when the transformation is not invertible.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: &Vector2<S>) -> Option<Vector2<S>> {
+        self.linear().inverse().map(|linear_inv| linear_inv * vector)
+    }
+
+    /// Apply the inverse of the affine transformation to a point.
+    ///
+    /// The function returns `None` when the transformation is not invertible.
+    #[inline]
+    pub fn inverse_transform_point(&self, point: &Point2<S>) -> Option<Point2<S>> {
+        self.inverse().map(|affine_inv| affine_inv.transform_point(point))
+    }
+
+    /// Apply the affine transformation to a surface normal.
+    ///
+    /// Normal vectors do not transform like ordinary vectors under 
+    /// non-uniform scaling and shearing. To remain perpendicular to the 
+    /// transformed surface, a normal is transformed by the inverse transpose 
+    /// of the linear part of the transformation. The result is renormalized. 
+    /// The function returns `None` when the transformation is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine2,
+    /// #     Matrix2x2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let affine = Affine2::from_parts(&Matrix2x2::new(2_f64, 0_f64, 0_f64, 1_f64), &Vector2::zero());
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, 2_f64));
+    /// let result = affine.transform_normal(&normal).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Option<Normal2<S>> {
+        self.linear().inverse().map(|linear_inv| {
+            Normal2::from_vector(&(linear_inv.transpose() * normal.as_vector()))
+        })
+    }
+}
+
+impl<S> AsRef<Matrix2x3<S>> for Affine2<S> {
+    #[inline]
+    fn as_ref(&self) -> &Matrix2x3<S> {
+        &self.matrix
+    }
+}
+
+impl<S> fmt::Display for Affine2<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Affine2 [{}]",
+            self.matrix
+        )
+    }
+}
+
+impl<S> From<Affine2<S>> for Matrix3x3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: Affine2<S>) -> Matrix3x3<S> {
+        affine.to_affine_matrix()
+    }
+}
+
+impl<S> From<Affine2<S>> for Transform2<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: Affine2<S>) -> Transform2<S> {
+        affine.to_transform()
+    }
+}
+
+impl<S> approx::AbsDiffEq for Affine2<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Matrix2x3::abs_diff_eq(&self.matrix, &other.matrix, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Affine2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Matrix2x3::relative_eq(&self.matrix, &other.matrix, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Affine2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Matrix2x3::ulps_eq(&self.matrix, &other.matrix, epsilon, max_ulps)
+    }
+}
+
+impl<S> Transformation2 for Affine2<S> 
+where 
+    S: ScalarFloat 
+{
+    type Scalar = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Affine2::identity()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point2<S>) -> Point2<S> {
+        Affine2::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        Affine2::transform_vector(self, vector)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform2<S> {
+        Affine2::to_transform(self)
+    }
+}
+
+impl<S> ops::Mul<Point2<S>> for Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: Point2<S>) -> Self::Output {
+        self.transform_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point2<S>> for Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: &Point2<S>) -> Self::Output {
+        self.transform_point(other)
+    }
+}
+
+impl<S> ops::Mul<Point2<S>> for &Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: Point2<S>) -> Self::Output {
+        self.transform_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point2<S>> for &Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: &Point2<S>) -> Self::Output {
+        self.transform_point(other)
+    }
+}
+
+impl<S> ops::Mul<Affine2<S>> for Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Affine2<S>;
+
+    #[inline]
+    fn mul(self, other: Affine2<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine2::from_parts(&linear, &translation)
+    }
+}
+
+impl<S> ops::Mul<&Affine2<S>> for Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Affine2<S>;
+
+    #[inline]
+    fn mul(self, other: &Affine2<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine2::from_parts(&linear, &translation)
+    }
+}
+
+impl<S> ops::Mul<Affine2<S>> for &Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Affine2<S>;
+
+    #[inline]
+    fn mul(self, other: Affine2<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine2::from_parts(&linear, &translation)
+    }
+}
+
+impl<S> ops::Mul<&Affine2<S>> for &Affine2<S>
+where
+    S: Scalar
+{
+    type Output = Affine2<S>;
+
+    #[inline]
+    fn mul(self, other: &Affine2<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine2::from_parts(&linear, &translation)
+    }
+}
+
+
+macro_rules! impl_affine2_from_transformation {
+    ($($Type:ident),* $(,)*) => {
+        $(
+            impl<S> From<$Type<S>> for Affine2<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: $Type<S>) -> Affine2<S> {
+                    Affine2::from_affine_matrix_unchecked(transformation.to_transform().matrix())
+                }
+            }
+
+            impl<S> From<&$Type<S>> for Affine2<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: &$Type<S>) -> Affine2<S> {
+                    Affine2::from_affine_matrix_unchecked(transformation.to_transform().matrix())
+                }
+            }
+        )*
+    }
+}
+
+impl_affine2_from_transformation!(
+    Translation2, Rotation2, Scale2, Shear2, Reflection2, Isometry2, Similarity2,
+);
+
+
+
+/// A three-dimensional affine transformation.
+///
+/// An affine transformation is a linear transformation followed by a 
+/// translation. In homogeneous coordinates, its matrix has a last row of 
+/// `[0 0 0 1]`. Because the last row is always known, the 
+/// transformation stores only the top 3 rows of the homogeneous matrix, and 
+/// its inverse only requires inverting the 3x3 linear part, which is much 
+/// cheaper than the general inverse of a [`Transform3`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine3<S> {
+    /// The top 3 rows of the homogeneous matrix of the transformation.
+    matrix: Matrix3x4<S>,
+}
+
+impl<S> Affine3<S> 
+where 
+    S: Scalar 
+{
+    /// Construct an affine transformation from the top 3 rows of its 
+    /// homogeneous matrix.
+    ///
+    /// The first 3 columns of `matrix` are the linear part of the 
+    /// transformation, and the last column is the translation.
+    #[inline]
+    pub fn from_matrix(matrix: Matrix3x4<S>) -> Affine3<S> {
+        Affine3 {
+            matrix: matrix,
+        }
+    }
+
+    /// Construct an affine transformation from its linear part and its 
+    /// translation.
+    #[inline]
+    pub fn from_parts(linear: &Matrix3x3<S>, translation: &Vector3<S>) -> Affine3<S> {
+        let matrix = Matrix3x4::new(
+            linear.c0r0, linear.c0r1, linear.c0r2,
+            linear.c1r0, linear.c1r1, linear.c1r2,
+            linear.c2r0, linear.c2r1, linear.c2r2,
+            translation.x, translation.y, translation.z
+        );
+
+        Affine3 {
+            matrix: matrix,
+        }
+    }
+
+    /// Construct an affine transformation from a homogeneous matrix.
+    ///
+    /// The function returns `None` if the last row of `matrix` is not exactly
+    /// `[0 0 0 1]`.
+    #[inline]
+    pub fn try_from_affine_matrix(matrix: &Matrix4x4<S>) -> Option<Affine3<S>> {
+        if matrix.c0r3.is_zero() && matrix.c1r3.is_zero() && matrix.c2r3.is_zero() && matrix.c3r3.is_one() {
+            Some(Affine3::from_affine_matrix_unchecked(matrix))
+        } else {
+            None
+        }
+    }
+
+    /// Construct an affine transformation from the top 3 rows of a 
+    /// homogeneous matrix, ignoring the last row. This function is for 
+    /// internal use in implementing type conversions.
+    #[inline]
+    pub(crate) fn from_affine_matrix_unchecked(matrix: &Matrix4x4<S>) -> Affine3<S> {
+        let affine_matrix = Matrix3x4::new(
+            matrix.c0r0, matrix.c0r1, matrix.c0r2,
+            matrix.c1r0, matrix.c1r1, matrix.c1r2,
+            matrix.c2r0, matrix.c2r1, matrix.c2r2,
+            matrix.c3r0, matrix.c3r1, matrix.c3r2
+        );
+
+        Affine3::from_matrix(affine_matrix)
+    }
+
+    /// Get a reference to the top 3 rows of the homogeneous matrix of the 
+    /// transformation.
+    #[inline]
+    pub fn matrix(&self) -> &Matrix3x4<S> {
+        &self.matrix
+    }
+
+    /// Get the linear part of the affine transformation.
+    #[inline]
+    pub fn linear(&self) -> Matrix3x3<S> {
+        Matrix3x3::new(
+            self.matrix.c0r0, self.matrix.c0r1, self.matrix.c0r2,
+            self.matrix.c1r0, self.matrix.c1r1, self.matrix.c1r2,
+            self.matrix.c2r0, self.matrix.c2r1, self.matrix.c2r2
+        )
+    }
+
+    /// Get the translation part of the affine transformation.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        Vector3::new(self.matrix.c3r0, self.matrix.c3r1, self.matrix.c3r2)
+    }
+
+    /// Construct the identity transformation.
+    #[inline]
+    pub fn identity() -> Affine3<S> {
+        Affine3::from_parts(&Matrix3x3::identity(), &Vector3::zero())
+    }
+
+    /// Convert an affine transformation into its homogeneous matrix.
+    #[rustfmt::skip]
+    #[inline]
+    pub fn to_affine_matrix(&self) -> Matrix4x4<S> {
+        let zero = S::zero();
+        let one = S::one();
+
+        Matrix4x4::new(
+            self.matrix.c0r0, self.matrix.c0r1, self.matrix.c0r2, zero,
+            self.matrix.c1r0, self.matrix.c1r1, self.matrix.c1r2, zero,
+            self.matrix.c2r0, self.matrix.c2r1, self.matrix.c2r2, zero,
+            self.matrix.c3r0, self.matrix.c3r1, self.matrix.c3r2, one
+        )
+    }
+
+    /// Convert an affine transformation into a generic transformation.
+    #[inline]
+    pub fn to_transform(&self) -> Transform3<S> {
+        Transform3::from_matrix_unchecked(self.to_affine_matrix())
+    }
+
+    /// Apply the affine transformation to a vector.
+    ///
+    /// Vectors are displacements, so the translation part of the 
+    /// transformation does not affect them.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine3,
+    /// #     Matrix3x4,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x4::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     1_f64, 3_f64, 0_f64,
+    ///     0_f64, 0_f64, 4_f64,
+    ///     4_f64, 5_f64, 6_f64
+    /// );
+    /// let affine = Affine3::from_matrix(matrix);
+    /// let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = Vector3::new(4_f64, 6_f64, 12_f64);
+    /// let result = affine.transform_vector(&vector);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        self.linear() * vector
+    }
+
+    /// Apply the affine transformation to a point.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine3,
+    /// #     Matrix3x4,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x4::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     1_f64, 3_f64, 0_f64,
+    ///     0_f64, 0_f64, 4_f64,
+    ///     4_f64, 5_f64, 6_f64
+    /// );
+    /// let affine = Affine3::from_matrix(matrix);
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = Point3::new(8_f64, 11_f64, 18_f64);
+    /// let result = affine.transform_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        Point3::from_vector(self.matrix * point.to_homogeneous())
+    }
+}
+
+impl<S> Affine3<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Compute the inverse of an affine transformation if it exists.
+    ///
+    /// The inverse of the affine transformation `x -> A * x + t` is the affine 
+    /// transformation `x -> inverse(A) * x - inverse(A) * t`, so only the 
+    /// linear part `A` needs to be inverted. The function returns `None` when 
+    /// the linear part is singular.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine3,
+    /// #     Matrix3x4,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x4::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     1_f64, 3_f64, 0_f64,
+    ///     0_f64, 0_f64, 4_f64,
+    ///     4_f64, 5_f64, 6_f64
+    /// );
+    /// let affine = Affine3::from_matrix(matrix);
+    /// let affine_inv = affine.inverse().unwrap();
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let result = affine_inv.transform_point(&affine.transform_point(&point));
+    ///
+    /// assert!(relative_eq!(result, point, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Affine3<S>> {
+        self.linear().inverse().map(|linear_inv| {
+            let translation_inv = -(linear_inv * self.translation());
+
+            Affine3::from_parts(&linear_inv, &translation_inv)
+        })
+    }
+
+    /// Determine whether an affine transformation is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.linear().is_invertible()
+    }
+
+    /// Apply the inverse of the affine transformation to a vector.
+    ///
+    /// The function returns `None` when the transformation is not invertible.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: &Vector3<S>) -> Option<Vector3<S>> {
+        self.linear().inverse().map(|linear_inv| linear_inv * vector)
+    }
+
+    /// Apply the inverse of the affine transformation to a point.
+    ///
+    /// The function returns `None` when the transformation is not invertible.
+    #[inline]
+    pub fn inverse_transform_point(&self, point: &Point3<S>) -> Option<Point3<S>> {
+        self.inverse().map(|affine_inv| affine_inv.transform_point(point))
+    }
+
+    /// Apply the affine transformation to a surface normal.
+    ///
+    /// Normal vectors do not transform like ordinary vectors under 
+    /// non-uniform scaling and shearing. To remain perpendicular to the 
+    /// transformed surface, a normal is transformed by the inverse transpose 
+    /// of the linear part of the transformation. The result is renormalized. 
+    /// The function returns `None` when the transformation is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Affine3,
+    /// #     Matrix3x3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let affine = Affine3::from_parts(&Matrix3x3::from_diagonal(&Vector3::new(2_f64, 1_f64, 1_f64)), &Vector3::zero());
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(1_f64, 2_f64, 0_f64));
+    /// let result = affine.transform_normal(&normal).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Option<Normal3<S>> {
+        self.linear().inverse().map(|linear_inv| {
+            Normal3::from_vector(&(linear_inv.transpose() * normal.as_vector()))
+        })
+    }
+}
+
+impl<S> AsRef<Matrix3x4<S>> for Affine3<S> {
+    #[inline]
+    fn as_ref(&self) -> &Matrix3x4<S> {
+        &self.matrix
+    }
+}
+
+impl<S> fmt::Display for Affine3<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Affine3 [{}]",
+            self.matrix
+        )
+    }
+}
+
+impl<S> From<Affine3<S>> for Matrix4x4<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: Affine3<S>) -> Matrix4x4<S> {
+        affine.to_affine_matrix()
+    }
+}
+
+impl<S> From<Affine3<S>> for Transform3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: Affine3<S>) -> Transform3<S> {
+        affine.to_transform()
+    }
+}
+
+impl<S> approx::AbsDiffEq for Affine3<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Matrix3x4::abs_diff_eq(&self.matrix, &other.matrix, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Affine3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Matrix3x4::relative_eq(&self.matrix, &other.matrix, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Affine3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Matrix3x4::ulps_eq(&self.matrix, &other.matrix, epsilon, max_ulps)
+    }
+}
+
+impl<S> Transformation3 for Affine3<S> 
+where 
+    S: ScalarFloat 
+{
+    type Scalar = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Affine3::identity()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        Affine3::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        Affine3::transform_vector(self, vector)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3<S> {
+        Affine3::to_transform(self)
+    }
+}
+
+impl<S> ops::Mul<Point3<S>> for Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: Point3<S>) -> Self::Output {
+        self.transform_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point3<S>> for Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: &Point3<S>) -> Self::Output {
+        self.transform_point(other)
+    }
+}
+
+impl<S> ops::Mul<Point3<S>> for &Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: Point3<S>) -> Self::Output {
+        self.transform_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point3<S>> for &Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: &Point3<S>) -> Self::Output {
+        self.transform_point(other)
+    }
+}
+
+impl<S> ops::Mul<Affine3<S>> for Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Affine3<S>;
+
+    #[inline]
+    fn mul(self, other: Affine3<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine3::from_parts(&linear, &translation)
+    }
+}
+
+impl<S> ops::Mul<&Affine3<S>> for Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Affine3<S>;
+
+    #[inline]
+    fn mul(self, other: &Affine3<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine3::from_parts(&linear, &translation)
+    }
+}
+
+impl<S> ops::Mul<Affine3<S>> for &Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Affine3<S>;
+
+    #[inline]
+    fn mul(self, other: Affine3<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine3::from_parts(&linear, &translation)
+    }
+}
+
+impl<S> ops::Mul<&Affine3<S>> for &Affine3<S>
+where
+    S: Scalar
+{
+    type Output = Affine3<S>;
+
+    #[inline]
+    fn mul(self, other: &Affine3<S>) -> Self::Output {
+        let linear = self.linear() * other.linear();
+        let translation = self.linear() * other.translation() + self.translation();
+
+        Affine3::from_parts(&linear, &translation)
+    }
+}
+
+
+macro_rules! impl_affine3_from_transformation {
+    ($($Type:ident),* $(,)*) => {
+        $(
+            impl<S> From<$Type<S>> for Affine3<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: $Type<S>) -> Affine3<S> {
+                    Affine3::from_affine_matrix_unchecked(transformation.to_transform().matrix())
+                }
+            }
+
+            impl<S> From<&$Type<S>> for Affine3<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: &$Type<S>) -> Affine3<S> {
+                    Affine3::from_affine_matrix_unchecked(transformation.to_transform().matrix())
+                }
+            }
+        )*
+    }
+}
+
+impl_affine3_from_transformation!(
+    Translation3, Rotation3, Scale3, Shear3, Reflection3, Isometry3, Similarity3,
+);
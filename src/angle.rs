@@ -2,6 +2,11 @@ use crate::base::{
     Scalar,
     ScalarSigned,
     ScalarFloat,
+    fast_acos,
+    fast_atan2,
+    fast_cos,
+    fast_sin,
+    use_fast_math,
 };
 use num_traits::{
     Float,
@@ -153,6 +158,124 @@ where
         Self::normalize((other - self) * one_half + self)
     }
 
+    /// Compute the signed angle of the shortest rotation from `self` to `other`.
+    ///
+    /// The result lies in the range `[-full_turn / 2, full_turn / 2]`, and it is
+    /// positive when the shortest rotation from `self` to `other` is 
+    /// counterclockwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let angle1 = Degrees(350_f64);
+    /// let angle2 = Degrees(10_f64);
+    ///
+    /// assert!(relative_eq!(angle1.signed_difference(angle2), Degrees(20_f64), epsilon = 1e-10));
+    /// assert!(relative_eq!(angle2.signed_difference(angle1), Degrees(-20_f64), epsilon = 1e-10));
+    /// ```
+    #[inline]
+    fn signed_difference(self, other: Self) -> Self {
+        Self::normalize_signed(other - self)
+    }
+
+    /// Linearly interpolate between `self` and `other` along the shorter of
+    /// the two arcs between them.
+    ///
+    /// The result is not normalized, so that it varies continuously with
+    /// `amount` starting from `self`. An `amount` of zero yields `self`, and 
+    /// an `amount` of one yields an angle congruent to `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let angle1 = Degrees(350_f64);
+    /// let angle2 = Degrees(30_f64);
+    /// let result = angle1.lerp_shortest(angle2, 0.25_f64);
+    ///
+    /// assert!(relative_eq!(result, Degrees(360_f64), epsilon = 1e-10));
+    /// ```
+    #[inline]
+    fn lerp_shortest(self, other: Self, amount: Self::Dimensionless) -> Self {
+        self + Self::signed_difference(self, other) * amount
+    }
+
+    /// Gradually move `self` towards `target` along the shorter arc, with a
+    /// critically damped spring that never overshoots the target.
+    ///
+    /// The parameter `velocity` holds the angular velocity of the motion in 
+    /// units of angle per unit time, and it is updated on each call. The 
+    /// parameter `smooth_time` is the approximate time it takes to reach the
+    /// target, and `delta_time` is the time elapsed since the previous call.
+    /// The result is congruent to an angle between `self` and `target`, and it
+    /// is not normalized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Angle,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let target = Degrees(10_f64);
+    /// let mut heading = Degrees(340_f64);
+    /// let mut velocity = Degrees(0_f64);
+    /// for _ in 0..200 {
+    ///     heading = heading.smooth_damp(target, &mut velocity, 0.3_f64, 1_f64 / 60_f64);
+    /// }
+    ///
+    /// assert!(relative_eq!(heading.signed_difference(target), Degrees(0_f64), epsilon = 1e-3));
+    /// ```
+    fn smooth_damp(
+        self,
+        target: Self,
+        velocity: &mut Self,
+        smooth_time: Self::Dimensionless,
+        delta_time: Self::Dimensionless) -> Self
+    {
+        let one: Self::Dimensionless = num_traits::cast(1_f64).unwrap();
+        let two: Self::Dimensionless = num_traits::cast(2_f64).unwrap();
+        let c2: Self::Dimensionless = num_traits::cast(0.48_f64).unwrap();
+        let c3: Self::Dimensionless = num_traits::cast(0.235_f64).unwrap();
+        let smooth_time = smooth_time.max(Self::Dimensionless::epsilon());
+        // Move the target next to `self` so the motion follows the shorter arc.
+        let target = self + Self::signed_difference(self, target);
+        let omega = two / smooth_time;
+        let x = omega * delta_time;
+        // A Pade approximation of `exp(-x)`.
+        let decay = one / (one + x + c2 * x * x + c3 * x * x * x);
+        let change = self - target;
+        let temp = (*velocity + change * omega) * delta_time;
+        *velocity = (*velocity - temp * omega) * decay;
+        let result = target + (change + temp) * decay;
+        // Stop at the target instead of overshooting it.
+        if (target > self) == (result > target) {
+            *velocity = Self::zero();
+            target
+        } else {
+            result
+        }
+    }
+
     /// Compute the cosecant of a typed angle.
     #[inline]
     fn csc(self) -> Self::Dimensionless {
@@ -265,6 +388,76 @@ where
     }
 }
 
+/// The angle along the unit circle in units of turns, i.e. fractions of a full
+/// revolution. One turn is `2 * pi` radians.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Turns<S>(pub S);
+
+impl<S> Turns<S> 
+where 
+    S: Scalar 
+{
+    /// Construct a typed angle of zero turns.
+    #[inline]
+    pub fn zero() -> Self {
+        Turns(S::zero())
+    }
+
+    /// Determine whether a typed angle is zero turns.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<S> Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Returns `true` if the underlying floating point number of the typed
+    /// angle is finite.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+/// The angle along the unit circle in units of gradians. One full revolution
+/// is `400` gradians, so a right angle is `100` gradians.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Gradians<S>(pub S);
+
+impl<S> Gradians<S> 
+where 
+    S: Scalar 
+{
+    /// Construct a typed angle of zero gradians.
+    #[inline]
+    pub fn zero() -> Self {
+        Gradians(S::zero())
+    }
+
+    /// Determine whether a typed angle is zero gradians.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<S> Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Returns `true` if the underlying floating point number of the typed
+    /// angle is finite.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+}
+
 impl<S> From<Degrees<S>> for Radians<S> 
 where 
     S: ScalarFloat
@@ -285,6 +478,97 @@ where
     }
 }
 
+impl<S> From<Turns<S>> for Radians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Turns<S>) -> Radians<S> {
+        Radians(angle.0 * num_traits::cast(2_f64 * f64::consts::PI).unwrap())
+    }
+}
+impl<S> From<Radians<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Radians<S>) -> Turns<S> {
+        Turns(angle.0 / num_traits::cast(2_f64 * f64::consts::PI).unwrap())
+    }
+}
+impl<S> From<Turns<S>> for Degrees<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Turns<S>) -> Degrees<S> {
+        Degrees(angle.0 * num_traits::cast(360_f64).unwrap())
+    }
+}
+impl<S> From<Degrees<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Degrees<S>) -> Turns<S> {
+        Turns(angle.0 / num_traits::cast(360_f64).unwrap())
+    }
+}
+impl<S> From<Gradians<S>> for Radians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Gradians<S>) -> Radians<S> {
+        Radians(angle.0 * num_traits::cast(f64::consts::PI / 200_f64).unwrap())
+    }
+}
+impl<S> From<Radians<S>> for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Radians<S>) -> Gradians<S> {
+        Gradians(angle.0 * num_traits::cast(200_f64 / f64::consts::PI).unwrap())
+    }
+}
+impl<S> From<Gradians<S>> for Degrees<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Gradians<S>) -> Degrees<S> {
+        Degrees(angle.0 * num_traits::cast(9_f64).unwrap() / num_traits::cast(10_f64).unwrap())
+    }
+}
+impl<S> From<Degrees<S>> for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Degrees<S>) -> Gradians<S> {
+        Gradians(angle.0 * num_traits::cast(10_f64).unwrap() / num_traits::cast(9_f64).unwrap())
+    }
+}
+impl<S> From<Turns<S>> for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Turns<S>) -> Gradians<S> {
+        Gradians(angle.0 * num_traits::cast(400_f64).unwrap())
+    }
+}
+impl<S> From<Gradians<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Gradians<S>) -> Turns<S> {
+        Turns(angle.0 / num_traits::cast(400_f64).unwrap())
+    }
+}
+
 impl<S> fmt::Display for Degrees<S> 
 where 
     S: fmt::Display 
@@ -303,6 +587,24 @@ where
     }
 }
 
+impl<S> fmt::Display for Turns<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} turns", self.0)
+    }
+}
+
+impl<S> fmt::Display for Gradians<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} gradians", self.0)
+    }
+}
+
 impl<S> ops::Add<Degrees<S>> for Degrees<S> 
 where 
     S: Scalar
@@ -1054,63 +1356,925 @@ where
         S::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
     }
 }
+impl<S> ops::Add<Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn add(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
 
-impl<S> Angle for Radians<S> 
+impl<'a, S> ops::Add<&'a Turns<S>> for Turns<S> 
 where 
-    S: ScalarFloat
+    S: Scalar
 {
-    type Dimensionless = S;
+    type Output = Turns<S>;
 
     #[inline]
-    fn full_turn() -> Self {
-        Radians(num_traits::cast(2_f64 * f64::consts::PI).unwrap())
-    }
+    fn add(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<'a, S> ops::Add<Turns<S>> for &'a Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn sin(self) -> Self::Dimensionless {
-        S::sin(self.0)
-    }
+    fn add(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<'a, 'b, S> ops::Add<&'a Turns<S>> for &'b Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn cos(self) -> Self::Dimensionless {
-        S::cos(self.0)
-    }
+    fn add(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 + other.0)
+    } 
+}
+
+impl<S> ops::Sub<Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn tan(self) -> Self::Dimensionless {
-        S::tan(self.0)
-    }
+    fn sub(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<'a, S> ops::Sub<&'a Turns<S>> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn asin(ratio: Self::Dimensionless) -> Self {
-        Radians(Self::Dimensionless::asin(ratio))
-    }
+    fn sub(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<'a, S> ops::Sub<Turns<S>> for &'a Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn acos(ratio: Self::Dimensionless) -> Self {
-        Radians(Self::Dimensionless::acos(ratio))
-    }
+    fn sub(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<'a, 'b, S> ops::Sub<&'a Turns<S>> for &'b Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn atan(ratio: Self::Dimensionless) -> Self {
-        Radians(Self::Dimensionless::atan(ratio))
-    }
+    fn sub(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 - other.0)
+    } 
+}
+
+impl<S> ops::Mul<S> for Turns<S> 
+where 
+    S: Scalar
+{
+    type Output = Turns<S>;
 
     #[inline]
-    fn atan2(a: Self::Dimensionless, b: Self::Dimensionless) -> Self {
-        Radians(Self::Dimensionless::atan2(a, b))
+    fn mul(self, other: S) -> Self::Output {
+        Turns(self.0 * other)
     }
 }
 
-impl<S> Angle for Degrees<S> 
+impl<'a, S> ops::Mul<S> for &'a Turns<S> 
 where 
-    S: ScalarFloat
+    S: Scalar
 {
-    type Dimensionless = S;
+    type Output = Turns<S>;
 
     #[inline]
-    fn full_turn() -> Self {
-        Degrees(num_traits::cast(360).unwrap())
+    fn mul(self, other: S) -> Self::Output {
+        Turns(self.0 * other)
+    }
+}
+
+impl<S> ops::Div<S> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Turns(self.0 / other)
+    }
+}
+
+impl<'a, S> ops::Div<S> for &'a Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Turns(self.0 / other)
+    }
+}
+
+impl<S> ops::Div<Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, S> ops::Div<&'a Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: &'a Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, S> ops::Div<Turns<S>> for &'a Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, 'b, S> ops::Div<&'a Turns<S>> for &'b Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: &'a Turns<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<S> ops::Rem<Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<'a, S> ops::Rem<&'a Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<'a, S> ops::Rem<Turns<S>> for &'a Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<'a, 'b, S> ops::Rem<&'a Turns<S>> for &'b Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn rem(self, other: &'a Turns<S>) -> Self::Output {
+        Turns(self.0 % other.0)
+    }
+}
+
+impl<S> ops::Neg for Turns<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Turns(-self.0)
+    }
+}
+
+impl<'a, S> ops::Neg for &'a Turns<S> 
+where 
+    S: ScalarSigned
+{
+    type Output = Turns<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Turns(-self.0)
+    }
+}
+
+impl<S> ops::AddAssign<Turns<S>> for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn add_assign(&mut self, other: Turns<S>) {
+        *self = *self + other;
+    } 
+}
+
+impl<S> ops::SubAssign<Turns<S>> for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Turns<S>) {
+        *self = *self - other;
+    } 
+}
+
+impl<S> ops::MulAssign<S> for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn mul_assign(&mut self, other: S) {
+        *self = *self * other;
+    } 
+}
+
+impl<S> ops::DivAssign<S> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn div_assign(&mut self, other: S) {
+        *self = *self / other;
+    } 
+}
+
+impl<S> ops::RemAssign<Turns<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn rem_assign(&mut self, other: Turns<S>) {
+        *self = *self % other;
+    } 
+}
+
+impl<S> Zero for Turns<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn zero() -> Turns<S> {
+        Turns(S::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<S> approx::AbsDiffEq for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.0, &other.0, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.0, &other.0, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
+    }
+}
+
+impl<S> ops::Add<Gradians<S>> for Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn add(self, other: Gradians<S>) -> Self::Output {
+        Gradians(self.0 + other.0)
+    } 
+}
+
+impl<'a, S> ops::Add<&'a Gradians<S>> for Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn add(self, other: &'a Gradians<S>) -> Self::Output {
+        Gradians(self.0 + other.0)
+    } 
+}
+
+impl<'a, S> ops::Add<Gradians<S>> for &'a Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn add(self, other: Gradians<S>) -> Self::Output {
+        Gradians(self.0 + other.0)
+    } 
+}
+
+impl<'a, 'b, S> ops::Add<&'a Gradians<S>> for &'b Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn add(self, other: &'a Gradians<S>) -> Self::Output {
+        Gradians(self.0 + other.0)
+    } 
+}
+
+impl<S> ops::Sub<Gradians<S>> for Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn sub(self, other: Gradians<S>) -> Self::Output {
+        Gradians(self.0 - other.0)
+    } 
+}
+
+impl<'a, S> ops::Sub<&'a Gradians<S>> for Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn sub(self, other: &'a Gradians<S>) -> Self::Output {
+        Gradians(self.0 - other.0)
+    } 
+}
+
+impl<'a, S> ops::Sub<Gradians<S>> for &'a Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn sub(self, other: Gradians<S>) -> Self::Output {
+        Gradians(self.0 - other.0)
+    } 
+}
+
+impl<'a, 'b, S> ops::Sub<&'a Gradians<S>> for &'b Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn sub(self, other: &'a Gradians<S>) -> Self::Output {
+        Gradians(self.0 - other.0)
+    } 
+}
+
+impl<S> ops::Mul<S> for Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Gradians(self.0 * other)
+    }
+}
+
+impl<'a, S> ops::Mul<S> for &'a Gradians<S> 
+where 
+    S: Scalar
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Gradians(self.0 * other)
+    }
+}
+
+impl<S> ops::Div<S> for Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Gradians(self.0 / other)
+    }
+}
+
+impl<'a, S> ops::Div<S> for &'a Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Gradians(self.0 / other)
+    }
+}
+
+impl<S> ops::Div<Gradians<S>> for Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: Gradians<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, S> ops::Div<&'a Gradians<S>> for Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: &'a Gradians<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, S> ops::Div<Gradians<S>> for &'a Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: Gradians<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<'a, 'b, S> ops::Div<&'a Gradians<S>> for &'b Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = S;
+
+    #[inline]
+    fn div(self, other: &'a Gradians<S>) -> Self::Output {
+        self.0 / other.0
+    }
+}
+
+impl<S> ops::Rem<Gradians<S>> for Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn rem(self, other: Gradians<S>) -> Self::Output {
+        Gradians(self.0 % other.0)
+    }
+}
+
+impl<'a, S> ops::Rem<&'a Gradians<S>> for Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn rem(self, other: &'a Gradians<S>) -> Self::Output {
+        Gradians(self.0 % other.0)
+    }
+}
+
+impl<'a, S> ops::Rem<Gradians<S>> for &'a Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn rem(self, other: Gradians<S>) -> Self::Output {
+        Gradians(self.0 % other.0)
+    }
+}
+
+impl<'a, 'b, S> ops::Rem<&'a Gradians<S>> for &'b Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn rem(self, other: &'a Gradians<S>) -> Self::Output {
+        Gradians(self.0 % other.0)
+    }
+}
+
+impl<S> ops::Neg for Gradians<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Gradians(-self.0)
+    }
+}
+
+impl<'a, S> ops::Neg for &'a Gradians<S> 
+where 
+    S: ScalarSigned
+{
+    type Output = Gradians<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Gradians(-self.0)
+    }
+}
+
+impl<S> ops::AddAssign<Gradians<S>> for Gradians<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn add_assign(&mut self, other: Gradians<S>) {
+        *self = *self + other;
+    } 
+}
+
+impl<S> ops::SubAssign<Gradians<S>> for Gradians<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Gradians<S>) {
+        *self = *self - other;
+    } 
+}
+
+impl<S> ops::MulAssign<S> for Gradians<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn mul_assign(&mut self, other: S) {
+        *self = *self * other;
+    } 
+}
+
+impl<S> ops::DivAssign<S> for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn div_assign(&mut self, other: S) {
+        *self = *self / other;
+    } 
+}
+
+impl<S> ops::RemAssign<Gradians<S>> for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn rem_assign(&mut self, other: Gradians<S>) {
+        *self = *self % other;
+    } 
+}
+
+impl<S> Zero for Gradians<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn zero() -> Gradians<S> {
+        Gradians(S::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<S> approx::AbsDiffEq for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.0, &other.0, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.0, &other.0, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
+    }
+}
+
+
+impl<S> Angle for Radians<S> 
+where 
+    S: ScalarFloat
+{
+    type Dimensionless = S;
+
+    #[inline]
+    fn full_turn() -> Self {
+        Radians(num_traits::cast(2_f64 * f64::consts::PI).unwrap())
+    }
+
+    #[inline]
+    fn sin(self) -> Self::Dimensionless {
+        if use_fast_math::<S>() {
+            fast_sin(self.0)
+        } else {
+            S::sin(self.0)
+        }
+    }
+
+    #[inline]
+    fn cos(self) -> Self::Dimensionless {
+        if use_fast_math::<S>() {
+            fast_cos(self.0)
+        } else {
+            S::cos(self.0)
+        }
+    }
+
+    #[inline]
+    fn tan(self) -> Self::Dimensionless {
+        S::tan(self.0)
+    }
+
+    #[inline]
+    fn asin(ratio: Self::Dimensionless) -> Self {
+        Radians(Self::Dimensionless::asin(ratio))
+    }
+
+    #[inline]
+    fn acos(ratio: Self::Dimensionless) -> Self {
+        if use_fast_math::<S>() {
+            Radians(fast_acos(ratio))
+        } else {
+            Radians(Self::Dimensionless::acos(ratio))
+        }
+    }
+
+    #[inline]
+    fn atan(ratio: Self::Dimensionless) -> Self {
+        Radians(Self::Dimensionless::atan(ratio))
+    }
+
+    #[inline]
+    fn atan2(a: Self::Dimensionless, b: Self::Dimensionless) -> Self {
+        if use_fast_math::<S>() {
+            Radians(fast_atan2(a, b))
+        } else {
+            Radians(Self::Dimensionless::atan2(a, b))
+        }
+    }
+}
+
+impl<S> Angle for Degrees<S> 
+where 
+    S: ScalarFloat
+{
+    type Dimensionless = S;
+
+    #[inline]
+    fn full_turn() -> Self {
+        Degrees(num_traits::cast(360).unwrap())
+    }
+
+    #[inline]
+    fn sin(self) -> Self::Dimensionless {
+        Radians::from(self).sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Self::Dimensionless {
+        Radians::from(self).cos()
+    }
+
+    #[inline]
+    fn tan(self) -> Self::Dimensionless {
+        Radians::from(self).tan()
+    }
+
+    #[inline]
+    fn asin(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.asin()).into()
+    }
+
+    #[inline]
+    fn acos(ratio: Self::Dimensionless) -> Self {
+        Radians::acos(ratio).into()
+    }
+
+    #[inline]
+    fn atan(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.atan()).into()
+    }
+
+    #[inline]
+    fn atan2(a: Self::Dimensionless, b: Self::Dimensionless) -> Self {
+        Radians::atan2(a, b).into()
+    }
+}
+
+impl<S> Angle for Turns<S> 
+where 
+    S: ScalarFloat
+{
+    type Dimensionless = S;
+
+    #[inline]
+    fn full_turn() -> Self {
+        Turns(num_traits::cast(1).unwrap())
+    }
+
+    #[inline]
+    fn sin(self) -> Self::Dimensionless {
+        Radians::from(self).sin()
+    }
+
+    #[inline]
+    fn cos(self) -> Self::Dimensionless {
+        Radians::from(self).cos()
+    }
+
+    #[inline]
+    fn tan(self) -> Self::Dimensionless {
+        Radians::from(self).tan()
+    }
+
+    #[inline]
+    fn asin(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.asin()).into()
+    }
+
+    #[inline]
+    fn acos(ratio: Self::Dimensionless) -> Self {
+        Radians::acos(ratio).into()
+    }
+
+    #[inline]
+    fn atan(ratio: Self::Dimensionless) -> Self {
+        Radians(ratio.atan()).into()
+    }
+
+    #[inline]
+    fn atan2(a: Self::Dimensionless, b: Self::Dimensionless) -> Self {
+        Radians::atan2(a, b).into()
+    }
+}
+
+impl<S> Angle for Gradians<S> 
+where 
+    S: ScalarFloat
+{
+    type Dimensionless = S;
+
+    #[inline]
+    fn full_turn() -> Self {
+        Gradians(num_traits::cast(400).unwrap())
     }
 
     #[inline]
@@ -1135,7 +2299,7 @@ where
 
     #[inline]
     fn acos(ratio: Self::Dimensionless) -> Self {
-        Radians(ratio.acos()).into()
+        Radians::acos(ratio).into()
     }
 
     #[inline]
@@ -1145,7 +2309,416 @@ where
 
     #[inline]
     fn atan2(a: Self::Dimensionless, b: Self::Dimensionless) -> Self {
-        Radians(Self::Dimensionless::atan2(a, b)).into()
+        Radians::atan2(a, b).into()
+    }
+}
+
+/// An angle in degrees, minutes, and seconds of arc.
+///
+/// A degree divides into sixty minutes of arc, and a minute of arc divides
+/// into sixty seconds of arc. The sign of the angle is stored separately from
+/// its components, so that angles smaller than one degree in magnitude keep
+/// their sign. The degrees and minutes are always whole numbers, and the
+/// minutes and seconds always lie in the range `[0, 60)`.
+///
+/// Conversions go through the total number of seconds of arc. Splitting a
+/// total number of seconds of arc into components and recombining them is
+/// exact floating point arithmetic, so the only rounding in a conversion to or
+/// from [`Degrees`] is the single division or multiplication by `3600`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DegreesMinutesSeconds<S> {
+    is_negative: bool,
+    degrees: S,
+    minutes: S,
+    seconds: S,
+}
+
+impl<S> DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct an angle from degrees, minutes, and seconds of arc.
+    ///
+    /// The sign of the angle is the sign of `degrees`. Use `-0.0` degrees for
+    /// negative angles smaller than one degree. The function returns `None`
+    /// if `degrees` or `minutes` is not a whole number, or if `minutes` or
+    /// `seconds` lies outside the range `[0, 60)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     DegreesMinutesSeconds,
+    /// # };
+    /// #
+    /// let angle = DegreesMinutesSeconds::new(-0_f64, 30_f64, 0_f64).unwrap();
+    ///
+    /// assert!(angle.is_negative());
+    /// assert_eq!(Degrees::from(angle), Degrees(-0.5_f64));
+    /// assert!(DegreesMinutesSeconds::new(12_f64, 60_f64, 0_f64).is_none());
+    /// ```
+    pub fn new(degrees: S, minutes: S, seconds: S) -> Option<DegreesMinutesSeconds<S>> {
+        let zero = S::zero();
+        let sixty: S = num_traits::cast(60_f64).unwrap();
+        let is_whole = |value: S| value.is_finite() && value.trunc() == value;
+        if !is_whole(degrees) || !is_whole(minutes) || !seconds.is_finite() {
+            return None;
+        }
+        if minutes < zero || minutes >= sixty || seconds < zero || seconds >= sixty {
+            return None;
+        }
+
+        Some(DegreesMinutesSeconds {
+            is_negative: degrees.is_sign_negative(),
+            degrees: degrees.abs(),
+            minutes: minutes,
+            seconds: seconds,
+        })
+    }
+
+    /// Determine whether the angle is negative.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get the whole degrees of the magnitude of the angle.
+    #[inline]
+    pub fn degrees(&self) -> S {
+        self.degrees
+    }
+
+    /// Get the whole minutes of arc of the magnitude of the angle.
+    #[inline]
+    pub fn minutes(&self) -> S {
+        self.minutes
+    }
+
+    /// Get the seconds of arc of the magnitude of the angle.
+    #[inline]
+    pub fn seconds(&self) -> S {
+        self.seconds
+    }
+
+    /// Compute the total number of seconds of arc of the angle.
+    #[inline]
+    pub fn total_seconds(&self) -> S {
+        let sixty: S = num_traits::cast(60_f64).unwrap();
+        let magnitude = (self.degrees * sixty + self.minutes) * sixty + self.seconds;
+        if self.is_negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Construct an angle from a total number of seconds of arc.
+    pub fn from_total_seconds(total_seconds: S) -> DegreesMinutesSeconds<S> {
+        let zero = S::zero();
+        let sixty: S = num_traits::cast(60_f64).unwrap();
+        let seconds_per_degree = sixty * sixty;
+        let magnitude = total_seconds.abs();
+        let mut degrees = (magnitude / seconds_per_degree).floor();
+        let mut remainder = magnitude - degrees * seconds_per_degree;
+        // Correct for rounding in the division.
+        if remainder < zero {
+            degrees -= S::one();
+            remainder += seconds_per_degree;
+        } else if remainder >= seconds_per_degree {
+            degrees += S::one();
+            remainder -= seconds_per_degree;
+        }
+        let mut minutes = (remainder / sixty).floor();
+        let mut seconds = remainder - minutes * sixty;
+        if seconds < zero {
+            minutes -= S::one();
+            seconds += sixty;
+        } else if seconds >= sixty {
+            minutes += S::one();
+            seconds -= sixty;
+        }
+
+        DegreesMinutesSeconds {
+            is_negative: total_seconds.is_sign_negative(),
+            degrees: degrees,
+            minutes: minutes,
+            seconds: seconds,
+        }
+    }
+}
+
+impl<S> From<DegreesMinutesSeconds<S>> for Degrees<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: DegreesMinutesSeconds<S>) -> Degrees<S> {
+        Degrees(angle.total_seconds() / num_traits::cast(3600_f64).unwrap())
+    }
+}
+
+impl<S> From<Degrees<S>> for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Degrees<S>) -> DegreesMinutesSeconds<S> {
+        DegreesMinutesSeconds::from_total_seconds(angle.0 * num_traits::cast(3600_f64).unwrap())
+    }
+}
+
+impl<S> From<DegreesMinutesSeconds<S>> for Radians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: DegreesMinutesSeconds<S>) -> Radians<S> {
+        Radians::from(Degrees::from(angle))
+    }
+}
+
+impl<S> From<Radians<S>> for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Radians<S>) -> DegreesMinutesSeconds<S> {
+        DegreesMinutesSeconds::from(Degrees::from(angle))
+    }
+}
+
+impl<S> From<DegreesMinutesSeconds<S>> for Turns<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: DegreesMinutesSeconds<S>) -> Turns<S> {
+        Turns::from(Degrees::from(angle))
+    }
+}
+
+impl<S> From<Turns<S>> for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Turns<S>) -> DegreesMinutesSeconds<S> {
+        DegreesMinutesSeconds::from(Degrees::from(angle))
+    }
+}
+
+impl<S> From<DegreesMinutesSeconds<S>> for Gradians<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: DegreesMinutesSeconds<S>) -> Gradians<S> {
+        Gradians::from(Degrees::from(angle))
+    }
+}
+
+impl<S> From<Gradians<S>> for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(angle: Gradians<S>) -> DegreesMinutesSeconds<S> {
+        DegreesMinutesSeconds::from(Degrees::from(angle))
+    }
+}
+
+impl<S> fmt::Display for DegreesMinutesSeconds<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.is_negative { "-" } else { "" };
+        write!(formatter, "{}{}° {}' {}\"", sign, self.degrees, self.minutes, self.seconds)
+    }
+}
+
+impl<S> approx::AbsDiffEq for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Degrees::abs_diff_eq(&Degrees::from(*self), &Degrees::from(*other), epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Degrees::relative_eq(&Degrees::from(*self), &Degrees::from(*other), epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for DegreesMinutesSeconds<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Degrees::ulps_eq(&Degrees::from(*self), &Degrees::from(*other), epsilon, max_ulps)
+    }
+}
+
+/// A counterclockwise arc of the unit circle.
+///
+/// An angle range starts at the angle `start` and sweeps counterclockwise 
+/// through the angle `length` to its end point. The length of a range lies in
+/// the range `[0, full_turn]`. Angles are compared modulo a full turn, so an
+/// angle range may contain the zero angle, e.g. the range from `350` degrees to 
+/// `10` degrees.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     AngleRange,
+/// #     Degrees,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// let range = AngleRange::new(Degrees(350_f64), Degrees(10_f64));
+///
+/// assert!(range.contains(Degrees(0_f64)));
+/// assert!(range.contains(Degrees(-5_f64)));
+/// assert!(!range.contains(Degrees(180_f64)));
+/// assert!(relative_eq!(range.clamp(Degrees(40_f64)), Degrees(10_f64), epsilon = 1e-10));
+/// assert!(relative_eq!(range.clamp(Degrees(300_f64)), Degrees(350_f64), epsilon = 1e-10));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AngleRange<A> {
+    start: A,
+    length: A,
+}
+
+impl<A> AngleRange<A> 
+where 
+    A: Angle 
+{
+    /// Construct the counterclockwise arc from `start` to `end`.
+    ///
+    /// When `start` and `end` are congruent, the range contains only the angle
+    /// `start`. Use [`AngleRange::from_length`] or [`AngleRange::full`] to
+    /// construct a range covering the whole circle.
+    #[inline]
+    pub fn new(start: A, end: A) -> AngleRange<A> {
+        AngleRange {
+            start: start.normalize(),
+            length: (end - start).normalize(),
+        }
+    }
+
+    /// Construct the counterclockwise arc starting at `start` and sweeping
+    /// through the angle `length`. 
+    ///
+    /// The function returns `None` if `length` lies outside the range 
+    /// `[0, full_turn]`.
+    #[inline]
+    pub fn from_length(start: A, length: A) -> Option<AngleRange<A>> {
+        if length >= A::zero() && length <= A::full_turn() {
+            Some(AngleRange {
+                start: start.normalize(),
+                length: length,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Construct the range covering the whole circle.
+    #[inline]
+    pub fn full() -> AngleRange<A> {
+        AngleRange {
+            start: A::zero(),
+            length: A::full_turn(),
+        }
+    }
+
+    /// Get the start angle of the range, normalized to `[0, full_turn)`.
+    #[inline]
+    pub fn start(&self) -> A {
+        self.start
+    }
+
+    /// Get the end angle of the range, normalized to `[0, full_turn)`.
+    #[inline]
+    pub fn end(&self) -> A {
+        (self.start + self.length).normalize()
+    }
+
+    /// Get the angle the range sweeps through.
+    #[inline]
+    pub fn length(&self) -> A {
+        self.length
+    }
+
+    /// Get the angle half-way along the range.
+    #[inline]
+    pub fn midpoint(&self) -> A {
+        let one_half = num_traits::cast(0.5_f64).unwrap();
+        (self.start + self.length * one_half).normalize()
+    }
+
+    /// Determine whether an angle lies in the range, modulo a full turn.
+    #[inline]
+    pub fn contains(&self, angle: A) -> bool {
+        (angle - self.start).normalize() <= self.length
+    }
+
+    /// Map an angle to the closest angle in the range.
+    ///
+    /// An angle inside the range maps to itself. An angle outside the range 
+    /// maps to whichever end point of the range is closer along the circle,
+    /// normalized to `[0, full_turn)`.
+    pub fn clamp(&self, angle: A) -> A {
+        if self.contains(angle) {
+            return angle;
+        }
+
+        let end = self.end();
+        let distance_to_start = A::signed_difference(angle, self.start);
+        let distance_to_end = A::signed_difference(angle, end);
+        let abs = |value: A| if value < A::zero() { -value } else { value };
+        if abs(distance_to_start) <= abs(distance_to_end) {
+            self.start
+        } else {
+            end
+        }
     }
 }
 
+impl<A> fmt::Display for AngleRange<A> 
+where 
+    A: Angle + fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "AngleRange [{}, {}]", self.start, self.end())
+    }
+}
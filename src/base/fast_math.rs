@@ -0,0 +1,275 @@
+use crate::base::scalar::{
+    ScalarFloat,
+};
+
+use core::f64;
+
+
+/// The constant `pi / 2` in the requested floating point type.
+#[inline]
+fn frac_pi_2<S>() -> S 
+where 
+    S: ScalarFloat 
+{
+    num_traits::cast(f64::consts::FRAC_PI_2).unwrap()
+}
+
+/// The constant `pi` in the requested floating point type.
+#[inline]
+fn pi<S>() -> S 
+where 
+    S: ScalarFloat 
+{
+    num_traits::cast(f64::consts::PI).unwrap()
+}
+
+/// Determine whether the `fast-math` feature replaces the reference
+/// implementations of the elementary functions for the scalar type `S`.
+///
+/// The feature only applies to scalar types with at most single precision,
+/// such as `f32`. Types with more precision, such as `f64`, always use the
+/// reference implementations, so that enabling the feature anywhere in a
+/// dependency graph does not change double precision results.
+#[inline]
+pub(crate) fn use_fast_math<S>() -> bool 
+where 
+    S: ScalarFloat 
+{
+    let f32_epsilon: S = num_traits::cast(f32::EPSILON).unwrap();
+
+    cfg!(feature = "fast-math") && S::epsilon() >= f32_epsilon
+}
+
+/// Evaluate a polynomial with the coefficients in order of increasing degree
+/// using Horner's method.
+#[inline]
+fn horner<S>(x: S, coefficients: &[f64]) -> S 
+where 
+    S: ScalarFloat 
+{
+    coefficients.iter().rev().fold(S::zero(), |acc, &coefficient| {
+        acc * x + num_traits::cast(coefficient).unwrap()
+    })
+}
+
+/// Compute an approximation of the sine of an angle in radians.
+///
+/// The function reduces its argument to the range `[-pi / 2, pi / 2]` and
+/// evaluates a degree eleven polynomial. For `f64` arguments with magnitude
+/// at most `1000`, the absolute error is at most `1e-7`. For `f32` arguments
+/// in the range `[-2 * pi, 2 * pi]`, the absolute error is at most `1e-6`. The
+/// range reduction loses precision proportionally to the magnitude of the
+/// argument, so the bounds grow for larger arguments.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     fast_sin,
+/// # };
+/// # use core::f64;
+/// #
+/// let angle = f64::consts::FRAC_PI_6;
+///
+/// assert!((fast_sin(angle) - 0.5_f64).abs() <= 1e-7);
+/// ```
+#[inline]
+pub fn fast_sin<S>(angle: S) -> S 
+where 
+    S: ScalarFloat 
+{
+    const COEFFICIENTS: [f64; 6] = [
+        1_f64,
+        -1_f64 / 6_f64,
+        1_f64 / 120_f64,
+        -1_f64 / 5040_f64,
+        1_f64 / 362_880_f64,
+        -1_f64 / 39_916_800_f64,
+    ];
+    let pi = pi::<S>();
+    let two_pi = pi + pi;
+    let frac_pi_2 = frac_pi_2::<S>();
+    let mut reduced = angle - (angle / two_pi).round() * two_pi;
+    // Use the symmetry `sin(pi - x) == sin(x)`.
+    if reduced > frac_pi_2 {
+        reduced = pi - reduced;
+    } else if reduced < -frac_pi_2 {
+        reduced = -pi - reduced;
+    }
+
+    reduced * horner(reduced * reduced, &COEFFICIENTS)
+}
+
+/// Compute an approximation of the cosine of an angle in radians.
+///
+/// This function has the same error bounds as [`fast_sin`].
+#[inline]
+pub fn fast_cos<S>(angle: S) -> S 
+where 
+    S: ScalarFloat 
+{
+    fast_sin(angle + frac_pi_2())
+}
+
+/// Compute an approximation of the arc tangent of a ratio in the range `[0, 1]`.
+#[inline]
+fn fast_atan_unit<S>(ratio: S) -> S 
+where 
+    S: ScalarFloat 
+{
+    // Abramowitz and Stegun, Handbook of Mathematical Functions, 4.4.49.
+    const COEFFICIENTS: [f64; 9] = [
+         1_f64,
+        -0.333_331_452_8_f64,
+         0.199_935_508_5_f64,
+        -0.142_088_994_4_f64,
+         0.106_562_639_3_f64,
+        -0.075_289_640_0_f64,
+         0.042_909_613_8_f64,
+        -0.016_165_736_7_f64,
+         0.002_866_225_7_f64,
+    ];
+
+    ratio * horner(ratio * ratio, &COEFFICIENTS)
+}
+
+/// Compute an approximation of the four quadrant arc tangent of `y` and `x`
+/// in radians.
+///
+/// The function follows the same quadrant conventions as [`num_traits::Float::atan2`]
+/// and returns zero when both arguments are zero. For finite `f64` arguments,
+/// the absolute error is at most `5e-8`, and for finite `f32` arguments, the
+/// absolute error is at most `1e-6`.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     fast_atan2,
+/// # };
+/// # use core::f64;
+/// #
+/// let result = fast_atan2(-1_f64, -1_f64);
+/// let expected = -3_f64 * f64::consts::FRAC_PI_4;
+///
+/// assert!((result - expected).abs() <= 5e-8);
+/// ```
+#[inline]
+pub fn fast_atan2<S>(y: S, x: S) -> S 
+where 
+    S: ScalarFloat 
+{
+    let abs_x = x.abs();
+    let abs_y = y.abs();
+    let max = abs_x.max(abs_y);
+    if max.is_zero() {
+        return S::zero();
+    }
+
+    let min = abs_x.min(abs_y);
+    let mut angle = fast_atan_unit(min / max);
+    if abs_y > abs_x {
+        angle = frac_pi_2::<S>() - angle;
+    }
+    if x < S::zero() {
+        angle = pi::<S>() - angle;
+    }
+    if y < S::zero() {
+        angle = -angle;
+    }
+
+    angle
+}
+
+/// Compute an approximation of the arc cosine of a ratio in radians.
+///
+/// The function returns `NaN` when the ratio lies outside the range `[-1, 1]`.
+/// For `f64` arguments, the absolute error is at most `5e-8`, and for `f32`
+/// arguments, the absolute error is at most `1e-6`.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     fast_acos,
+/// # };
+/// # use core::f64;
+/// #
+/// let result = fast_acos(0.5_f64);
+/// let expected = f64::consts::FRAC_PI_3;
+///
+/// assert!((result - expected).abs() <= 5e-8);
+/// ```
+#[inline]
+pub fn fast_acos<S>(ratio: S) -> S 
+where 
+    S: ScalarFloat 
+{
+    // Abramowitz and Stegun, Handbook of Mathematical Functions, 4.4.46.
+    const COEFFICIENTS: [f64; 8] = [
+         1.570_796_305_0_f64,
+        -0.214_598_801_6_f64,
+         0.088_978_987_4_f64,
+        -0.050_174_304_6_f64,
+         0.030_891_881_0_f64,
+        -0.017_088_125_6_f64,
+         0.006_670_090_1_f64,
+        -0.001_262_491_1_f64,
+    ];
+    let abs_ratio = ratio.abs();
+    let angle = (S::one() - abs_ratio).sqrt() * horner(abs_ratio, &COEFFICIENTS);
+    if ratio < S::zero() {
+        pi::<S>() - angle
+    } else {
+        angle
+    }
+}
+
+/// Compute an approximation of the reciprocal square root of a number.
+///
+/// For scalar types with at most single precision, such as `f32`, the function
+/// refines a bit-level initial estimate with two Newton iterations. For
+/// positive arguments in the normal range of `f32`, the relative error is at
+/// most `5e-6`. Scalar types with more precision than `f32`, such as `f64`,
+/// and all other arguments fall back to computing `1 / sqrt(value)`, so that
+/// double precision results keep full precision.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     fast_rsqrt,
+/// # };
+/// #
+/// let result = fast_rsqrt(4_f32);
+///
+/// assert!((result - 0.5_f32).abs() <= 0.5_f32 * 5e-6);
+/// assert_eq!(fast_rsqrt(4_f64), 0.5_f64);
+/// ```
+#[inline]
+pub fn fast_rsqrt<S>(value: S) -> S 
+where 
+    S: ScalarFloat 
+{
+    let f32_epsilon: S = num_traits::cast(f32::EPSILON).unwrap();
+    if S::epsilon() < f32_epsilon {
+        return value.sqrt().recip();
+    }
+
+    let value_f32: f32 = match num_traits::cast(value) {
+        Some(value_f32) => value_f32,
+        None => return value.sqrt().recip(),
+    };
+    if !value_f32.is_normal() || value_f32 < 0_f32 {
+        return value.sqrt().recip();
+    }
+
+    let bits = 0x5f37_5a86_u32 - (value_f32.to_bits() >> 1);
+    let estimate: S = num_traits::cast(f32::from_bits(bits)).unwrap();
+    let one_half: S = num_traits::cast(0.5_f64).unwrap();
+    let three_halves: S = num_traits::cast(1.5_f64).unwrap();
+    let half_value = value * one_half;
+    let estimate = estimate * (three_halves - half_value * estimate * estimate);
+
+    estimate * (three_halves - half_value * estimate * estimate)
+}
@@ -1,9 +1,11 @@
 mod coordinates;
+mod fast_math;
 mod magnitude;
 mod scalar;
 mod unit;
 
 pub use coordinates::*;
+pub use fast_math::*;
 pub use magnitude::*;
 pub use scalar::*;
 pub use unit::*;
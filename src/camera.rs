@@ -0,0 +1,804 @@
+use crate::base::{
+    ScalarFloat,
+    Unit,
+};
+use crate::angle::{
+    Angle,
+    Radians,
+};
+use crate::matrix::{
+    Matrix3x3,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::projection::{
+    Perspective3,
+    PerspectiveFov3,
+};
+use crate::vector::{
+    Vector3,
+};
+
+use core::fmt;
+
+
+/// The maximum number of fixed-point iterations used to invert a lens
+/// distortion model.
+const MAX_UNDISTORT_ITERATIONS: usize = 64;
+
+
+/// The Brown-Conrady lens distortion model.
+///
+/// The model acts on normalized image coordinates, i.e. the coordinates
+/// `(x / z, y / z)` of a point in camera space before the camera intrinsics
+/// are applied. A normalized image point `(x, y)` with `r^2 = x^2 + y^2` is
+/// distorted to
+/// ```text
+/// x' = x * (1 + k1 * r^2 + k2 * r^4 + k3 * r^6) + 2 * p1 * x * y + p2 * (r^2 + 2 * x^2)
+/// y' = y * (1 + k1 * r^2 + k2 * r^4 + k3 * r^6) + p1 * (r^2 + 2 * y^2) + 2 * p2 * x * y
+/// ```
+/// where `k1`, `k2`, and `k3` are the radial distortion coefficients, and
+/// `p1` and `p2` are the tangential distortion coefficients. This is the same
+/// coefficient convention that OpenCV uses.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BrownConrady<S> {
+    /// The second order radial distortion coefficient.
+    pub k1: S,
+    /// The fourth order radial distortion coefficient.
+    pub k2: S,
+    /// The sixth order radial distortion coefficient.
+    pub k3: S,
+    /// The first tangential distortion coefficient.
+    pub p1: S,
+    /// The second tangential distortion coefficient.
+    pub p2: S,
+}
+
+impl<S> BrownConrady<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a new lens distortion model from its radial distortion
+    /// coefficients `k1`, `k2`, `k3`, and its tangential distortion
+    /// coefficients `p1`, `p2`.
+    #[inline]
+    pub const fn new(k1: S, k2: S, k3: S, p1: S, p2: S) -> BrownConrady<S> {
+        BrownConrady {
+            k1: k1,
+            k2: k2,
+            k3: k3,
+            p1: p1,
+            p2: p2,
+        }
+    }
+
+    /// Construct the lens distortion model of an ideal pinhole camera.
+    /// All of its distortion coefficients are zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BrownConrady,
+    /// #     Point2,
+    /// # };
+    /// #
+    /// let distortion: BrownConrady<f64> = BrownConrady::zero();
+    /// let point = Point2::new(0.3_f64, -0.2_f64);
+    ///
+    /// assert!(distortion.is_zero());
+    /// assert_eq!(distortion.distort(&point), point);
+    /// ```
+    #[inline]
+    pub fn zero() -> BrownConrady<S> {
+        let zero = S::zero();
+
+        BrownConrady::new(zero, zero, zero, zero, zero)
+    }
+
+    /// Determine whether all the distortion coefficients are zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.k1.is_zero() && self.k2.is_zero() && self.k3.is_zero()
+            && self.p1.is_zero() && self.p2.is_zero()
+    }
+
+    /// Apply the lens distortion to a point in normalized image coordinates.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BrownConrady,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let distortion = BrownConrady::new(-0.1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+    /// let point = Point2::new(0.5_f64, 0_f64);
+    /// let expected = Point2::new(0.5_f64 * (1_f64 - 0.1_f64 * 0.25_f64), 0_f64);
+    /// let result = distortion.distort(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn distort(&self, point: &Point2<S>) -> Point2<S> {
+        let one = S::one();
+        let two = one + one;
+        let x = point.x;
+        let y = point.y;
+        let x2 = x * x;
+        let y2 = y * y;
+        let xy = x * y;
+        let r2 = x2 + y2;
+        let radial = one + r2 * (self.k1 + r2 * (self.k2 + r2 * self.k3));
+        let tangential_x = two * self.p1 * xy + self.p2 * (r2 + two * x2);
+        let tangential_y = self.p1 * (r2 + two * y2) + two * self.p2 * xy;
+
+        Point2::new(x * radial + tangential_x, y * radial + tangential_y)
+    }
+
+    /// Remove the lens distortion from a point in normalized image coordinates.
+    ///
+    /// The distortion model has no closed form inverse, so the undistorted
+    /// point is computed by fixed-point iteration starting from the distorted
+    /// point. The function returns `None` if the iteration does not converge,
+    /// which happens when the distortion is too strong to be inverted at the
+    /// given point.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BrownConrady,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let distortion = BrownConrady::new(
+    ///     -0.2_f64, 0.05_f64, 0_f64, 0.001_f64, -0.002_f64
+    /// );
+    /// let point = Point2::new(0.4_f64, -0.3_f64);
+    /// let distorted = distortion.distort(&point);
+    /// let result = distortion.undistort(&distorted).unwrap();
+    ///
+    /// assert!(relative_eq!(result, point, epsilon = 1e-10));
+    /// ```
+    pub fn undistort(&self, point: &Point2<S>) -> Option<Point2<S>> {
+        let one = S::one();
+        let two = one + one;
+        let tolerance = S::default_epsilon().sqrt();
+        let mut x = point.x;
+        let mut y = point.y;
+        for _ in 0..MAX_UNDISTORT_ITERATIONS {
+            let x2 = x * x;
+            let y2 = y * y;
+            let xy = x * y;
+            let r2 = x2 + y2;
+            let radial = one + r2 * (self.k1 + r2 * (self.k2 + r2 * self.k3));
+            let tangential_x = two * self.p1 * xy + self.p2 * (r2 + two * x2);
+            let tangential_y = self.p1 * (r2 + two * y2) + two * self.p2 * xy;
+            let next_x = (point.x - tangential_x) / radial;
+            let next_y = (point.y - tangential_y) / radial;
+            if !next_x.is_finite() || !next_y.is_finite() {
+                return None;
+            }
+
+            let step = (next_x - x).abs().max((next_y - y).abs());
+            x = next_x;
+            y = next_y;
+            if step <= S::default_epsilon() * (one + x.abs().max(y.abs())) {
+                break;
+            }
+        }
+
+        let undistorted = Point2::new(x, y);
+        let residual = self.distort(&undistorted) - point;
+        if residual.x.abs().max(residual.y.abs()) <= tolerance * (one + point.x.abs().max(point.y.abs())) {
+            Some(undistorted)
+        } else {
+            None
+        }
+    }
+}
+
+impl<S> fmt::Display for BrownConrady<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "BrownConrady [k1={}, k2={}, k3={}, p1={}, p2={}]",
+            self.k1, self.k2, self.k3, self.p1, self.p2
+        )
+    }
+}
+
+impl<S> approx::AbsDiffEq for BrownConrady<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.k1, &other.k1, epsilon)
+            && S::abs_diff_eq(&self.k2, &other.k2, epsilon)
+            && S::abs_diff_eq(&self.k3, &other.k3, epsilon)
+            && S::abs_diff_eq(&self.p1, &other.p1, epsilon)
+            && S::abs_diff_eq(&self.p2, &other.p2, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for BrownConrady<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.k1, &other.k1, epsilon, max_relative)
+            && S::relative_eq(&self.k2, &other.k2, epsilon, max_relative)
+            && S::relative_eq(&self.k3, &other.k3, epsilon, max_relative)
+            && S::relative_eq(&self.p1, &other.p1, epsilon, max_relative)
+            && S::relative_eq(&self.p2, &other.p2, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for BrownConrady<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.k1, &other.k1, epsilon, max_ulps)
+            && S::ulps_eq(&self.k2, &other.k2, epsilon, max_ulps)
+            && S::ulps_eq(&self.k3, &other.k3, epsilon, max_ulps)
+            && S::ulps_eq(&self.p1, &other.p1, epsilon, max_ulps)
+            && S::ulps_eq(&self.p2, &other.p2, epsilon, max_ulps)
+    }
+}
+
+
+/// The intrinsic parameters of a pinhole camera, with optional lens distortion.
+///
+/// The camera intrinsics map points in camera space to pixel coordinates
+/// in an image. They follow the computer vision conventions: the camera sits
+/// at the origin looking down the **positive z-axis**, with the **x-axis**
+/// pointing right and the **y-axis** pointing down the image. Pixel coordinates
+/// are continuous, with the origin at the top left corner of the image, so an
+/// image of size `width x height` covers `[0, width] x [0, height]`.
+///
+/// A point `(x, y, z)` in the camera space used by the projections in this
+/// crate, where the camera looks down the **negative z-axis** with the
+/// **y-axis** pointing up, is the point `(x, -y, -z)` in the camera space
+/// of the camera intrinsics.
+///
+/// Without lens distortion, a point `(x, y, z)` projects to the pixel
+/// ```text
+/// u = focal_x * (x / z) + skew * (y / z) + principal_x
+/// v = focal_y * (y / z) + principal_y
+/// ```
+/// which is the pixel given by the camera matrix
+/// ```text
+/// K = [ focal_x  skew     principal_x ]
+///     [ 0        focal_y  principal_y ]
+///     [ 0        0        1           ]
+/// ```
+/// With lens distortion, the distortion model is applied to the normalized
+/// image coordinates `(x / z, y / z)` before the camera matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraIntrinsics<S> {
+    /// The focal length along the horizontal image axis, in pixels.
+    focal_x: S,
+    /// The focal length along the vertical image axis, in pixels.
+    focal_y: S,
+    /// The horizontal position of the principal point, in pixels.
+    principal_x: S,
+    /// The vertical position of the principal point, in pixels.
+    principal_y: S,
+    /// The skew between the horizontal and vertical image axes.
+    skew: S,
+    /// The lens distortion model.
+    distortion: BrownConrady<S>,
+}
+
+impl<S> CameraIntrinsics<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct new camera intrinsics from the focal lengths, the principal
+    /// point, and the skew, with no lens distortion.
+    #[inline]
+    pub fn new(focal_x: S, focal_y: S, principal_x: S, principal_y: S, skew: S) -> CameraIntrinsics<S> {
+        CameraIntrinsics {
+            focal_x: focal_x,
+            focal_y: focal_y,
+            principal_x: principal_x,
+            principal_y: principal_y,
+            skew: skew,
+            distortion: BrownConrady::zero(),
+        }
+    }
+
+    /// Construct a copy of the camera intrinsics with a different lens
+    /// distortion model.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BrownConrady,
+    /// #     CameraIntrinsics,
+    /// # };
+    /// #
+    /// let distortion = BrownConrady::new(-0.1_f64, 0.01_f64, 0_f64, 0_f64, 0_f64);
+    /// let intrinsics = CameraIntrinsics::new(800_f64, 800_f64, 320_f64, 240_f64, 0_f64)
+    ///     .with_distortion(&distortion);
+    ///
+    /// assert_eq!(intrinsics.distortion(), &distortion);
+    /// ```
+    #[inline]
+    pub fn with_distortion(&self, distortion: &BrownConrady<S>) -> CameraIntrinsics<S> {
+        CameraIntrinsics {
+            distortion: *distortion,
+            ..*self
+        }
+    }
+
+    /// Construct camera intrinsics from a camera matrix.
+    ///
+    /// The camera matrix must have the upper triangular form
+    /// ```text
+    /// [ focal_x  skew     principal_x ]
+    /// [ 0        focal_y  principal_y ]
+    /// [ 0        0        w           ]
+    /// ```
+    /// where `w` is nonzero. The matrix is rescaled so that `w == 1`. The
+    /// function returns `None` if the matrix does not have this form.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     1_f64,   780_f64, 0_f64,
+    ///     320_f64, 240_f64, 1_f64
+    /// );
+    /// let intrinsics = CameraIntrinsics::from_matrix(&matrix).unwrap();
+    ///
+    /// assert_eq!(intrinsics.focal_x(), 800_f64);
+    /// assert_eq!(intrinsics.focal_y(), 780_f64);
+    /// assert_eq!(intrinsics.principal_x(), 320_f64);
+    /// assert_eq!(intrinsics.principal_y(), 240_f64);
+    /// assert_eq!(intrinsics.skew(), 1_f64);
+    /// assert_eq!(intrinsics.to_matrix(), matrix);
+    /// ```
+    pub fn from_matrix(matrix: &Matrix3x3<S>) -> Option<CameraIntrinsics<S>> {
+        let is_upper_triangular = matrix.c0r1.is_zero()
+            && matrix.c0r2.is_zero()
+            && matrix.c1r2.is_zero();
+        if !is_upper_triangular || matrix.c2r2.is_zero() {
+            return None;
+        }
+
+        let w = matrix.c2r2;
+
+        Some(CameraIntrinsics::new(
+            matrix.c0r0 / w,
+            matrix.c1r1 / w,
+            matrix.c2r0 / w,
+            matrix.c2r1 / w,
+            matrix.c1r0 / w
+        ))
+    }
+
+    /// Construct camera intrinsics from a perspective projection and the
+    /// size of the image in pixels.
+    ///
+    /// The resulting camera intrinsics project a point in camera space onto
+    /// the same location in the image as the perspective projection does in
+    /// normalized device coordinates, where the left side of the frustum maps
+    /// to `u == 0` and the top side of the frustum maps to `v == 0`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Perspective3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-1_f64, 1_f64, -0.75_f64, 0.75_f64, 1_f64, 100_f64);
+    /// let intrinsics = CameraIntrinsics::from_perspective(&perspective, 640_f64, 480_f64);
+    ///
+    /// assert!(relative_eq!(intrinsics.focal_x(), 320_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(intrinsics.focal_y(), 320_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(intrinsics.principal_x(), 320_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(intrinsics.principal_y(), 240_f64, epsilon = 1e-10));
+    /// ```
+    pub fn from_perspective(perspective: &Perspective3<S>, width: S, height: S) -> CameraIntrinsics<S> {
+        let near = perspective.near_z();
+        let frustum_width = perspective.right_x() - perspective.left_x();
+        let frustum_height = perspective.top_y() - perspective.bottom_y();
+        let focal_x = (width * near) / frustum_width;
+        let focal_y = (height * near) / frustum_height;
+        let principal_x = (-perspective.left_x() * width) / frustum_width;
+        let principal_y = (perspective.top_y() * height) / frustum_height;
+
+        CameraIntrinsics::new(focal_x, focal_y, principal_x, principal_y, S::zero())
+    }
+
+    /// Construct camera intrinsics from a symmetric perspective projection
+    /// and the size of the image in pixels. The principal point lies at the
+    /// center of the image.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Degrees,
+    /// #     PerspectiveFov3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = PerspectiveFov3::new(Degrees(90_f64), 4_f64 / 3_f64, 0.1_f64, 100_f64);
+    /// let intrinsics = CameraIntrinsics::from_perspective_fov(&perspective, 640_f64, 480_f64);
+    ///
+    /// assert!(relative_eq!(intrinsics.focal_x(), 240_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(intrinsics.focal_y(), 240_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(intrinsics.principal_x(), 320_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(intrinsics.principal_y(), 240_f64, epsilon = 1e-10));
+    /// ```
+    pub fn from_perspective_fov(perspective: &PerspectiveFov3<S>, width: S, height: S) -> CameraIntrinsics<S> {
+        let one = S::one();
+        let two = one + one;
+        let tan_half_vfov = (perspective.vfov() / two).tan();
+        let focal_y = height / (two * tan_half_vfov);
+        let focal_x = width / (two * tan_half_vfov * perspective.aspect());
+
+        CameraIntrinsics::new(focal_x, focal_y, width / two, height / two, S::zero())
+    }
+
+    /// Construct the perspective projection with the given near and far planes
+    /// whose viewing frustum covers an image of the given size in pixels.
+    ///
+    /// A perspective projection cannot represent skew or lens distortion, so
+    /// both are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Perspective3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(320_f64, 320_f64, 320_f64, 240_f64, 0_f64);
+    /// let result = intrinsics.to_perspective(640_f64, 480_f64, 1_f64, 100_f64);
+    /// let expected = Perspective3::new(-1_f64, 1_f64, -0.75_f64, 0.75_f64, 1_f64, 100_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn to_perspective(&self, width: S, height: S, near: S, far: S) -> Perspective3<S> {
+        let left = -(self.principal_x * near) / self.focal_x;
+        let right = ((width - self.principal_x) * near) / self.focal_x;
+        let bottom = -((height - self.principal_y) * near) / self.focal_y;
+        let top = (self.principal_y * near) / self.focal_y;
+
+        Perspective3::new(left, right, bottom, top, near, far)
+    }
+
+    /// Construct the symmetric perspective projection with the given near and
+    /// far planes whose viewing frustum covers an image of the given size in
+    /// pixels.
+    ///
+    /// A symmetric perspective projection assumes the principal point lies at
+    /// the center of the image, and it cannot represent skew or lens distortion,
+    /// so all three are ignored. Use [`CameraIntrinsics::to_perspective`] for
+    /// an off-center principal point.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Degrees,
+    /// #     PerspectiveFov3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(240_f64, 240_f64, 320_f64, 240_f64, 0_f64);
+    /// let result = intrinsics.to_perspective_fov(640_f64, 480_f64, 0.1_f64, 100_f64);
+    /// let expected = PerspectiveFov3::new(Degrees(90_f64), 4_f64 / 3_f64, 0.1_f64, 100_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn to_perspective_fov(&self, width: S, height: S, near: S, far: S) -> PerspectiveFov3<S> {
+        let one = S::one();
+        let two = one + one;
+        let vfov = Radians(two * (height / (two * self.focal_y)).atan());
+        let aspect = (width * self.focal_y) / (height * self.focal_x);
+
+        PerspectiveFov3::new(vfov, aspect, near, far)
+    }
+
+    /// Get the focal length along the horizontal image axis, in pixels.
+    #[inline]
+    pub fn focal_x(&self) -> S {
+        self.focal_x
+    }
+
+    /// Get the focal length along the vertical image axis, in pixels.
+    #[inline]
+    pub fn focal_y(&self) -> S {
+        self.focal_y
+    }
+
+    /// Get the horizontal position of the principal point, in pixels.
+    #[inline]
+    pub fn principal_x(&self) -> S {
+        self.principal_x
+    }
+
+    /// Get the vertical position of the principal point, in pixels.
+    #[inline]
+    pub fn principal_y(&self) -> S {
+        self.principal_y
+    }
+
+    /// Get the principal point, in pixels.
+    #[inline]
+    pub fn principal_point(&self) -> Point2<S> {
+        Point2::new(self.principal_x, self.principal_y)
+    }
+
+    /// Get the skew between the horizontal and vertical image axes.
+    #[inline]
+    pub fn skew(&self) -> S {
+        self.skew
+    }
+
+    /// Get the lens distortion model.
+    #[inline]
+    pub fn distortion(&self) -> &BrownConrady<S> {
+        &self.distortion
+    }
+
+    /// Get the camera matrix `K` of the camera intrinsics. The camera matrix
+    /// does not include the lens distortion.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(800_f64, 780_f64, 320_f64, 240_f64, 2_f64);
+    /// let expected = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     2_f64,   780_f64, 0_f64,
+    ///     320_f64, 240_f64, 1_f64
+    /// );
+    /// let result = intrinsics.to_matrix();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    pub fn to_matrix(&self) -> Matrix3x3<S> {
+        let zero = S::zero();
+        let one = S::one();
+
+        Matrix3x3::new(
+            self.focal_x,     zero,             zero,
+            self.skew,        self.focal_y,     zero,
+            self.principal_x, self.principal_y, one
+        )
+    }
+
+    /// Project a point in camera space to pixel coordinates.
+    ///
+    /// The function returns `None` if the point does not lie in front of the
+    /// camera, i.e. its z-coordinate is not positive.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CameraIntrinsics,
+    /// #     Point2,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(800_f64, 800_f64, 320_f64, 240_f64, 0_f64);
+    /// let point = Point3::new(1_f64, -0.5_f64, 4_f64);
+    /// let expected = Point2::new(520_f64, 140_f64);
+    /// let result = intrinsics.project_point(&point).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// assert!(intrinsics.project_point(&Point3::new(1_f64, 1_f64, -1_f64)).is_none());
+    /// ```
+    pub fn project_point(&self, point: &Point3<S>) -> Option<Point2<S>> {
+        if point.z <= S::zero() {
+            return None;
+        }
+
+        let normalized = Point2::new(point.x / point.z, point.y / point.z);
+        let distorted = self.distortion.distort(&normalized);
+        let u = self.focal_x * distorted.x + self.skew * distorted.y + self.principal_x;
+        let v = self.focal_y * distorted.y + self.principal_y;
+
+        Some(Point2::new(u, v))
+    }
+
+    /// Compute the normalized image coordinates of a pixel, removing the lens
+    /// distortion.
+    ///
+    /// The normalized image coordinates of a pixel are the coordinates `(x, y)`
+    /// of the point `(x, y, 1)` in camera space that projects to the pixel. The
+    /// function returns `None` if the lens distortion cannot be inverted at
+    /// the pixel.
+    pub fn unproject_pixel_normalized(&self, pixel: &Point2<S>) -> Option<Point2<S>> {
+        let distorted_y = (pixel.y - self.principal_y) / self.focal_y;
+        let distorted_x = (pixel.x - self.principal_x - self.skew * distorted_y) / self.focal_x;
+        let distorted = Point2::new(distorted_x, distorted_y);
+
+        if self.distortion.is_zero() {
+            Some(distorted)
+        } else {
+            self.distortion.undistort(&distorted)
+        }
+    }
+
+    /// Back-project a pixel to the direction of the ray from the camera
+    /// center through the pixel in camera space.
+    ///
+    /// The function returns `None` if the lens distortion cannot be inverted
+    /// at the pixel.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BrownConrady,
+    /// #     CameraIntrinsics,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let distortion = BrownConrady::new(-0.15_f64, 0.02_f64, 0_f64, 0.001_f64, 0.0005_f64);
+    /// let intrinsics = CameraIntrinsics::new(800_f64, 800_f64, 320_f64, 240_f64, 0_f64)
+    ///     .with_distortion(&distortion);
+    /// let point = Point3::new(1_f64, -0.5_f64, 4_f64);
+    /// let pixel = intrinsics.project_point(&point).unwrap();
+    /// let expected = Unit::from_value(point.to_vector());
+    /// let result = intrinsics.unproject_pixel(&pixel).unwrap();
+    ///
+    /// assert!(relative_eq!(result.into_inner(), expected.into_inner(), epsilon = 1e-10));
+    /// ```
+    pub fn unproject_pixel(&self, pixel: &Point2<S>) -> Option<Unit<Vector3<S>>> {
+        self.unproject_pixel_normalized(pixel).map(|normalized| {
+            Unit::from_value(Vector3::new(normalized.x, normalized.y, S::one()))
+        })
+    }
+}
+
+impl<S> fmt::Display for CameraIntrinsics<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "CameraIntrinsics [focal=({}, {}), principal=({}, {}), skew={}, distortion={}]",
+            self.focal_x, self.focal_y, self.principal_x, self.principal_y, self.skew, self.distortion
+        )
+    }
+}
+
+impl<S> approx::AbsDiffEq for CameraIntrinsics<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.focal_x, &other.focal_x, epsilon)
+            && S::abs_diff_eq(&self.focal_y, &other.focal_y, epsilon)
+            && S::abs_diff_eq(&self.principal_x, &other.principal_x, epsilon)
+            && S::abs_diff_eq(&self.principal_y, &other.principal_y, epsilon)
+            && S::abs_diff_eq(&self.skew, &other.skew, epsilon)
+            && BrownConrady::abs_diff_eq(&self.distortion, &other.distortion, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for CameraIntrinsics<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.focal_x, &other.focal_x, epsilon, max_relative)
+            && S::relative_eq(&self.focal_y, &other.focal_y, epsilon, max_relative)
+            && S::relative_eq(&self.principal_x, &other.principal_x, epsilon, max_relative)
+            && S::relative_eq(&self.principal_y, &other.principal_y, epsilon, max_relative)
+            && S::relative_eq(&self.skew, &other.skew, epsilon, max_relative)
+            && BrownConrady::relative_eq(&self.distortion, &other.distortion, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for CameraIntrinsics<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.focal_x, &other.focal_x, epsilon, max_ulps)
+            && S::ulps_eq(&self.focal_y, &other.focal_y, epsilon, max_ulps)
+            && S::ulps_eq(&self.principal_x, &other.principal_x, epsilon, max_ulps)
+            && S::ulps_eq(&self.principal_y, &other.principal_y, epsilon, max_ulps)
+            && S::ulps_eq(&self.skew, &other.skew, epsilon, max_ulps)
+            && BrownConrady::ulps_eq(&self.distortion, &other.distortion, epsilon, max_ulps)
+    }
+}
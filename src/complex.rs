@@ -0,0 +1,1924 @@
+use crate::base::{
+    Magnitude,
+    Scalar,
+    ScalarSigned,
+    ScalarFloat,
+    fast_rsqrt,
+    use_fast_math,
+    Unit,
+};
+use crate::angle::{
+    Angle,
+    Radians,
+};
+use crate::matrix::{
+    Matrix2x2,
+    Matrix3x3,
+};
+use crate::point::{
+    Point2,
+};
+use crate::vector::{
+    Vector2,
+};
+use crate::normal::{
+    Normal2,
+};
+use crate::rotation::{
+    Rotation2,
+};
+use crate::translation::{
+    Translation2,
+};
+use crate::isometry::{
+    Isometry2,
+};
+use crate::similarity::{
+    Similarity2,
+};
+
+use num_traits::NumCast;
+use core::fmt;
+use core::ops;
+
+
+/// A complex number in Cartesian form.
+///
+/// A complex number `z` can be written as
+/// ```text
+/// z := re + i * im
+/// ```
+/// where `re` is the real part, `im` is the imaginary part, and `i` is the
+/// imaginary unit satisfying `i * i == -1`. Complex numbers are the
+/// two-dimensional analogue of quaternions. In particular, a complex number
+/// can be written in polar form as
+/// ```text
+/// z := |z| * exp(i * theta) := |z| * (cos(theta) + i * sin(theta))
+/// ```
+/// where `|z|` is the modulus of the complex number and `theta` is the argument.
+/// Unit complex numbers encode rotations in the plane in the same way that 
+/// unit quaternions encode rotations in three dimensions.
+///
+/// Complex numbers are stored in [re, im] storage order.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Complex<S> {
+    /// The real part of a complex number.
+    pub re: S,
+    /// The imaginary part of a complex number.
+    pub im: S,
+}
+
+/// A unit complex number representing a rotation in the **xy-plane**.
+pub type UnitComplex<S> = Unit<Complex<S>>;
+
+impl<S> Complex<S> {
+    /// Construct a new complex number from its real part and its imaginary
+    /// part.
+    #[inline]
+    pub const fn new(re: S, im: S) -> Complex<S> {
+        Complex { 
+            re: re, 
+            im: im,
+        }
+    }
+}
+
+impl<S> Complex<S> 
+where 
+    S: Copy 
+{
+    /// Construct a new complex number from a fill value. 
+    ///
+    /// Both components of the resulting complex number will have the same 
+    /// value supplied by the `value` argument.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex, 
+    /// # };
+    /// #
+    /// let result = Complex::from_fill(1_f64);
+    /// let expected = Complex::new(1_f64, 1_f64);
+    /// 
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn from_fill(value: S) -> Complex<S> {
+        Complex::new(value, value)
+    }
+
+    /// The length of the the underlying array storing the complex number 
+    /// components.
+    #[allow(clippy::len_without_is_empty)]
+    #[inline]
+    pub fn len(&self) -> usize {
+        2
+    }
+
+    /// The shape of the underlying array storing the complex number components.
+    ///
+    /// The shape is the equivalent number of columns and rows of the 
+    /// array as though it represents a matrix. The order of the descriptions 
+    /// of the shape of the array is **(rows, columns)**.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (2, 1)
+    }
+
+    /// Get a pointer to the underlying array.
+    #[inline]
+    pub fn as_ptr(&self) -> *const S {
+        &self.re
+    }
+
+    /// Get a mutable pointer to the underlying array.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S {
+        &mut self.re
+    }
+
+    /// Get a slice of the underlying elements of the data type.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        <Self as AsRef<[S; 2]>>::as_ref(self)
+    }
+
+    /// Map an operation on that acts on the components of a complex number, 
+    /// returning a complex number whose components are of the new scalar type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,  
+    /// # };
+    /// #
+    /// let z: Complex<f32> = Complex::new(1_f32, 2_f32);
+    /// let expected: Complex<f64> = Complex::new(-2_f64, -3_f64);
+    /// let result: Complex<f64> = z.map(|comp| -(comp + 1_f32) as f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn map<T, F>(self, mut op: F) -> Complex<T> 
+    where 
+        F: FnMut(S) -> T 
+    {
+        Complex::new(op(self.re), op(self.im))
+    }
+}
+
+impl<S> Complex<S> 
+where 
+    S: NumCast + Copy 
+{
+    /// Cast a complex number from one type of scalars to another type of 
+    /// scalars.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,   
+    /// # };
+    /// #
+    /// let z: Complex<u32> = Complex::new(1_u32, 2_u32);
+    /// let expected: Option<Complex<i32>> = Some(Complex::new(1_i32, 2_i32));
+    /// let result = z.cast::<i32>();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Complex<T>> {
+        let re = num_traits::cast(self.re)?;
+        let im = num_traits::cast(self.im)?;
+
+        Some(Complex::new(re, im))
+    }
+}
+
+impl<S> Complex<S> 
+where 
+    S: Scalar 
+{
+    /// Returns the unit real complex number.
+    ///
+    /// A real complex number is a complex number with zero imaginary part.
+    #[inline]
+    pub fn unit_re() -> Complex<S> {
+        Complex::new(S::one(), S::zero())
+    }
+
+    /// Returns the unit imaginary complex number.
+    ///
+    /// A pure imaginary complex number is a complex number with zero real 
+    /// part.
+    #[inline]
+    pub fn unit_im() -> Complex<S> {
+        Complex::new(S::zero(), S::one())
+    }
+
+    /// Construct a zero complex number.
+    ///
+    /// A zero complex number is a complex number `z` such that for every 
+    /// complex number `w`, `z * w == 0`.
+    #[inline]
+    pub fn zero() -> Complex<S> {
+        Complex::new(S::zero(), S::zero())
+    }
+    
+    /// Determine whether a complex number is the zero complex number.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    /// Construct the multiplicative identity complex number.
+    ///
+    /// The multiplicative identity complex number is the complex number `1` 
+    /// satisfying `1 * z == z * 1 == z` for every complex number `z`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// #
+    /// let z = Complex::new(2_f64, 3_f64);
+    /// let identity = Complex::identity();
+    ///
+    /// assert_eq!(z * identity, z);
+    /// assert_eq!(identity * z, z);
+    /// ```
+    #[inline]
+    pub fn identity() -> Complex<S> {
+        Complex::new(S::one(), S::zero())
+    }
+
+    /// Determine whether a complex number is equal to the identity complex 
+    /// number.
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.re.is_one() && self.im.is_zero()
+    }
+
+    /// Determine whether a complex number is a real number.
+    #[inline]
+    pub fn is_real(&self) -> bool {
+        self.im.is_zero()
+    }
+
+    /// Determine whether a complex number is a pure imaginary number.
+    #[inline]
+    pub fn is_imaginary(&self) -> bool {
+        self.re.is_zero()
+    }
+
+    /// Construct a real complex number from a scalar value.
+    #[inline]
+    pub fn from_real(value: S) -> Complex<S> {
+        Complex::new(value, S::zero())
+    }
+
+    /// Construct a pure imaginary complex number from a scalar value.
+    #[inline]
+    pub fn from_imaginary(value: S) -> Complex<S> {
+        Complex::new(S::zero(), value)
+    }
+
+    /// Compute the dot product of two complex numbers, treating them as
+    /// two-dimensional vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// #
+    /// let z1 = Complex::new(1_f64, 2_f64);
+    /// let z2 = Complex::new(3_f64, 4_f64);
+    ///
+    /// assert_eq!(z1.dot(&z2), 11_f64);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Complex<S>) -> S {
+        self.re * other.re + self.im * other.im
+    }
+}
+
+impl<S> Complex<S> 
+where 
+    S: ScalarSigned 
+{
+    /// Compute the conjugate of a complex number.
+    ///
+    /// Given a complex number `z := re + i * im`, the conjugate of `z` is the 
+    /// complex number `z* := re - i * im`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// #
+    /// let z = Complex::new(1_f64, 2_f64);
+    /// let expected = Complex::new(1_f64, -2_f64);
+    /// let result = z.conjugate();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn conjugate(&self) -> Complex<S> {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl<S> Complex<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a complex number from its polar form.
+    ///
+    /// The polar form of a complex number `z` is given by
+    /// ```text
+    /// z := modulus * (cos(angle) + i * sin(angle))
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let expected = Complex::new(0_f64, 2_f64);
+    /// let result = Complex::from_polar_decomposition(2_f64, Degrees(90_f64));
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_polar_decomposition<A: Into<Radians<S>>>(modulus: S, angle: A) -> Complex<S> {
+        let (sin_angle, cos_angle) = Radians::sin_cos(angle.into());
+
+        Complex::new(modulus * cos_angle, modulus * sin_angle)
+    }
+
+    /// Compute the polar decomposition of a complex number.
+    ///
+    /// The function returns the modulus of the complex number, followed by 
+    /// its principal argument.
+    #[inline]
+    pub fn polar_decomposition(&self) -> (S, Radians<S>) {
+        (self.magnitude(), Radians(self.arg()))
+    }
+
+    /// Compute the principal argument of a complex number.
+    ///
+    /// The principal argument of a complex number `z` is the angle `theta` 
+    /// in the interval `(-pi, pi]` satisfying 
+    /// ```text
+    /// z == |z| * (cos(theta) + i * sin(theta))
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let z = Complex::new(1_f64, 1_f64);
+    /// let expected = f64::consts::FRAC_PI_4;
+    /// let result = z.arg();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn arg(&self) -> S {
+        self.im.atan2(self.re)
+    }
+
+    /// Compute the multiplicative inverse of a complex number.
+    ///
+    /// If the complex number `self` has zero magnitude, it does not have an 
+    /// inverse. In this case the function return `None`. Otherwise it returns 
+    /// the inverse of `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// #
+    /// let z = Complex::new(1_f64, 1_f64);
+    /// let expected = Some(Complex::new(1_f64 / 2_f64, -1_f64 / 2_f64));
+    /// let result = z.inverse();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Complex<S>> {
+        let magnitude_squared = self.magnitude_squared();
+        if magnitude_squared <= S::default_epsilon() * S::default_epsilon() {
+            None
+        } else {
+            Some(self.conjugate() / magnitude_squared)
+        }
+    }
+
+    /// Determine whether a complex number is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.magnitude_squared() >= S::default_epsilon() * S::default_epsilon()
+    }
+
+    /// Calculate the exponential of a complex number.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let z = Complex::new(0_f64, f64::consts::PI);
+    /// let expected = Complex::new(-1_f64, 0_f64);
+    /// let result = z.exp();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn exp(&self) -> Complex<S> {
+        let exp_re = self.re.exp();
+        let (sin_im, cos_im) = self.im.sin_cos();
+
+        Complex::new(exp_re * cos_im, exp_re * sin_im)
+    }
+
+    /// Calculate the principal value of the natural logarithm of a complex 
+    /// number.
+    ///
+    /// The principal value of the natural logarithm of a complex number `z` is
+    /// ```text
+    /// Ln(z) := log(|z|, e) + i * Arg(z)
+    /// ```
+    /// where `Arg(z)` is the principal argument of `z`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// # use core::f64;
+    /// #
+    /// let z = Complex::new(0_f64, 2_f64);
+    /// let expected = Complex::new(f64::ln(2_f64), f64::consts::FRAC_PI_2);
+    /// let result = z.ln();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn ln(&self) -> Complex<S> {
+        Complex::new(self.magnitude().ln(), self.arg())
+    }
+
+    /// Calculate the power of a complex number where the exponent is a real 
+    /// number.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let z = Complex::new(1_f64, 1_f64);
+    /// let expected = Complex::new(0_f64, 2_f64);
+    /// let result = z.powf(2_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn powf(&self, exponent: S) -> Complex<S> {
+        if self.is_zero() {
+            Complex::zero()
+        } else {
+            (self.ln() * exponent).exp()
+        }
+    }
+
+    /// Calculate the principal value of the square root of a complex number.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let z = Complex::new(-4_f64, 0_f64);
+    /// let expected = Complex::new(0_f64, 2_f64);
+    /// let result = z.sqrt();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> Complex<S> {
+        let one_half = num_traits::cast(0.5_f64).unwrap();
+
+        self.powf(one_half)
+    }
+
+    /// Linearly interpolate between two complex numbers.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Complex,  
+    /// # };
+    /// #
+    /// let z0 = Complex::new(0_f64, 0_f64);
+    /// let z1 = Complex::new(10_f64, 20_f64);
+    /// let amount = 0.7;
+    /// let expected = Complex::new(7_f64, 14_f64);
+    /// let result = z0.lerp(&z1, amount);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Complex<S>, amount: S) -> Complex<S> {
+        self + (other - self) * amount
+    }
+
+    /// Compute the normalized linear interpolation between two complex 
+    /// numbers.
+    #[inline]
+    pub fn nlerp(&self, other: &Complex<S>, amount: S) -> Complex<S> {
+        (self * (S::one() - amount) + other * amount).normalize()
+    }
+
+    /// Returns `true` if the elements of a complex number are all finite. 
+    /// Otherwise, it returns `false`. 
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+}
+
+impl<S> Unit<Complex<S>> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a unit complex number that rotates vectors in the 
+    /// **xy-plane** by an angle `angle`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     UnitComplex,
+    /// #     Degrees,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation = UnitComplex::from_angle(Degrees(90_f64));
+    /// let vector = Vector2::unit_x();
+    /// let expected = Vector2::unit_y();
+    /// let result = rotation.rotate_vector(&vector);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_angle<A: Into<Radians<S>>>(angle: A) -> Unit<Complex<S>> {
+        let (sin_angle, cos_angle) = Radians::sin_cos(angle.into());
+
+        Unit::from_value_unchecked(Complex::new(cos_angle, sin_angle))
+    }
+
+    /// Construct a unit complex number from a two-dimensional rotation.
+    #[inline]
+    pub fn from_rotation(rotation: &Rotation2<S>) -> Unit<Complex<S>> {
+        let matrix = rotation.matrix();
+        let complex = Complex::new(matrix.c0r0, matrix.c0r1);
+
+        Unit::from_value(complex)
+    }
+
+    /// Construct a unit complex number that rotates the shortest angular 
+    /// distance between two unit vectors.
+    #[inline]
+    pub fn rotation_between_axis(a: &Unit<Vector2<S>>, b: &Unit<Vector2<S>>) -> Unit<Complex<S>> {
+        let unit_a = a.as_ref();
+        let unit_b = b.as_ref();
+        let cos_angle = unit_a.dot(unit_b);
+        let sin_angle = unit_a.x * unit_b.y - unit_a.y * unit_b.x;
+
+        Unit::from_value(Complex::new(cos_angle, sin_angle))
+    }
+
+    /// Get the rotation angle of a unit complex number.
+    ///
+    /// The angle lies in the interval `(-pi, pi]`.
+    #[inline]
+    pub fn angle(&self) -> Radians<S> {
+        Radians(self.arg())
+    }
+
+    /// Get the cosine of the rotation angle of a unit complex number.
+    #[inline]
+    pub fn cos_angle(&self) -> S {
+        self.re
+    }
+
+    /// Get the sine of the rotation angle of a unit complex number.
+    #[inline]
+    pub fn sin_angle(&self) -> S {
+        self.im
+    }
+
+    /// Construct the inverse rotation of a unit complex number.
+    ///
+    /// For unit complex numbers, the inverse is the conjugate.
+    #[inline]
+    pub fn inverse(&self) -> Unit<Complex<S>> {
+        Unit::from_value_unchecked(self.conjugate())
+    }
+
+    /// Rotate a two-dimensional vector by a unit complex number.
+    #[inline]
+    pub fn rotate_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        Vector2::new(
+            self.re * vector.x - self.im * vector.y,
+            self.im * vector.x + self.re * vector.y
+        )
+    }
+
+    /// Rotate a two-dimensional point by a unit complex number.
+    #[inline]
+    pub fn rotate_point(&self, point: &Point2<S>) -> Point2<S> {
+        Point2::from_vector(self.rotate_vector(&point.to_vector()))
+    }
+
+    /// Rotate a two-dimensional vector by the inverse of a unit complex 
+    /// number.
+    #[inline]
+    pub fn inverse_rotate_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        self.inverse().rotate_vector(vector)
+    }
+
+    /// Rotate a two-dimensional point by the inverse of a unit complex 
+    /// number.
+    #[inline]
+    pub fn inverse_rotate_point(&self, point: &Point2<S>) -> Point2<S> {
+        self.inverse().rotate_point(point)
+    }
+
+    /// Rotate a surface normal by a unit complex number.
+    ///
+    /// Rotations preserve angles, so normals rotate like ordinary vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     UnitComplex,
+    /// #     Degrees,
+    /// #     Normal2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation = UnitComplex::from_angle(Degrees(90_f64));
+    /// let normal = Normal2::unit_x();
+    /// let expected = Normal2::unit_y();
+    /// let result = rotation.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        Normal2::from_vector_unchecked(self.rotate_vector(normal.as_vector()))
+    }
+
+    /// Spherically linearly interpolate between two unit complex numbers.
+    ///
+    /// The interpolation proceeds at constant angular speed along the shortest 
+    /// arc between the two rotations.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     UnitComplex,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation1 = UnitComplex::from_angle(Degrees(30_f64));
+    /// let rotation2 = UnitComplex::from_angle(Degrees(150_f64));
+    /// let expected = UnitComplex::from_angle(Degrees(90_f64));
+    /// let result = rotation1.slerp(&rotation2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn slerp(&self, other: &Unit<Complex<S>>, amount: S) -> Unit<Complex<S>> {
+        let difference = self.inverse() * other;
+        let angle = difference.angle();
+
+        self * Self::from_angle(angle * amount)
+    }
+
+    /// Convert a unit complex number into the equivalent two-dimensional 
+    /// rotation matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     UnitComplex,
+    /// #     Degrees,
+    /// #     Matrix2x2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let angle = Degrees(60_f64);
+    /// let rotation = UnitComplex::from_angle(angle);
+    /// let expected = Matrix2x2::from_angle(angle);
+    /// let result = rotation.to_matrix();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix2x2<S> {
+        Matrix2x2::new(
+             self.re, self.im,
+            -self.im, self.re
+        )
+    }
+
+    /// Convert a unit complex number into the equivalent two-dimensional 
+    /// affine transformation matrix.
+    #[inline]
+    pub fn to_affine_matrix(&self) -> Matrix3x3<S> {
+        Matrix3x3::from(&self.to_matrix())
+    }
+
+    /// Convert a unit complex number into the equivalent two-dimensional 
+    /// rotation.
+    #[inline]
+    pub fn to_rotation(&self) -> Rotation2<S> {
+        Rotation2::from_unit_complex(self)
+    }
+}
+
+impl<S> AsRef<[S; 2]> for Complex<S> {
+    #[inline]
+    fn as_ref(&self) -> &[S; 2] {
+        unsafe { 
+            &*(self as *const Complex<S> as *const [S; 2])
+        }
+    }
+}
+
+impl<S> AsRef<(S, S)> for Complex<S> {
+    #[inline]
+    fn as_ref(&self) -> &(S, S) {
+        unsafe { 
+            &*(self as *const Complex<S> as *const (S, S))
+        }
+    }
+}
+
+impl<S> AsMut<[S; 2]> for Complex<S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [S; 2] {
+        unsafe { 
+            &mut *(self as *mut Complex<S> as *mut [S; 2])
+        }
+    }
+}
+
+impl<S> From<[S; 2]> for Complex<S> 
+where 
+    S: Copy 
+{
+    #[inline]
+    fn from(v: [S; 2]) -> Complex<S> {
+        Complex::new(v[0], v[1])
+    }
+}
+
+impl<S> From<(S, S)> for Complex<S> 
+where 
+    S: Copy 
+{
+    #[inline]
+    fn from(v: (S, S)) -> Complex<S> {
+        Complex::new(v.0, v.1)
+    }
+}
+
+impl<S> From<Rotation2<S>> for Unit<Complex<S>> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(rotation: Rotation2<S>) -> Unit<Complex<S>> {
+        Unit::from_rotation(&rotation)
+    }
+}
+
+impl<S> From<Unit<Complex<S>>> for Rotation2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(unit_complex: Unit<Complex<S>>) -> Rotation2<S> {
+        Rotation2::from_unit_complex(&unit_complex)
+    }
+}
+
+impl<S> From<Unit<Complex<S>>> for Matrix2x2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(unit_complex: Unit<Complex<S>>) -> Matrix2x2<S> {
+        unit_complex.to_matrix()
+    }
+}
+
+impl<S> From<Unit<Complex<S>>> for Matrix3x3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(unit_complex: Unit<Complex<S>>) -> Matrix3x3<S> {
+        unit_complex.to_affine_matrix()
+    }
+}
+
+impl<S> ops::Index<usize> for Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = S;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        let v: &[S; 2] = self.as_ref();
+        &v[index]
+    }
+}
+
+impl<S> ops::IndexMut<usize> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut S {
+        let v: &mut [S; 2] = self.as_mut();
+        &mut v[index]
+    }
+}
+
+impl<S> fmt::Display for Complex<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter, 
+            "Complex [re: {}, im: {}]", 
+            self.re, self.im
+        )
+    }
+}
+
+impl<S> ops::Neg for Complex<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<S> ops::Neg for &Complex<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+
+impl<S> ops::Add<Complex<S>> for Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn add(self, other: Complex<S>) -> Self::Output {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<S> ops::Add<&Complex<S>> for Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn add(self, other: &Complex<S>) -> Self::Output {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<S> ops::Add<Complex<S>> for &Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn add(self, other: Complex<S>) -> Self::Output {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<S> ops::Add<&Complex<S>> for &Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn add(self, other: &Complex<S>) -> Self::Output {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<S> ops::Sub<Complex<S>> for Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn sub(self, other: Complex<S>) -> Self::Output {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<S> ops::Sub<&Complex<S>> for Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn sub(self, other: &Complex<S>) -> Self::Output {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<S> ops::Sub<Complex<S>> for &Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn sub(self, other: Complex<S>) -> Self::Output {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<S> ops::Sub<&Complex<S>> for &Complex<S>
+where
+    S: Scalar
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn sub(self, other: &Complex<S>) -> Self::Output {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<S> ops::Mul<Complex<S>> for Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: Complex<S>) -> Self::Output {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re
+        )
+    }
+}
+
+impl<S> ops::Mul<&Complex<S>> for Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: &Complex<S>) -> Self::Output {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re
+        )
+    }
+}
+
+impl<S> ops::Mul<Complex<S>> for &Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: Complex<S>) -> Self::Output {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re
+        )
+    }
+}
+
+impl<S> ops::Mul<&Complex<S>> for &Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: &Complex<S>) -> Self::Output {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re
+        )
+    }
+}
+
+impl<S> ops::Div<Complex<S>> for Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: Complex<S>) -> Self::Output {
+        let denominator = other.re * other.re + other.im * other.im;
+
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / denominator,
+            (self.im * other.re - self.re * other.im) / denominator
+        )
+    }
+}
+
+impl<S> ops::Div<&Complex<S>> for Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: &Complex<S>) -> Self::Output {
+        let denominator = other.re * other.re + other.im * other.im;
+
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / denominator,
+            (self.im * other.re - self.re * other.im) / denominator
+        )
+    }
+}
+
+impl<S> ops::Div<Complex<S>> for &Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: Complex<S>) -> Self::Output {
+        let denominator = other.re * other.re + other.im * other.im;
+
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / denominator,
+            (self.im * other.re - self.re * other.im) / denominator
+        )
+    }
+}
+
+impl<S> ops::Div<&Complex<S>> for &Complex<S>
+where
+    S: ScalarSigned
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: &Complex<S>) -> Self::Output {
+        let denominator = other.re * other.re + other.im * other.im;
+
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / denominator,
+            (self.im * other.re - self.re * other.im) / denominator
+        )
+    }
+}
+
+impl<S> ops::Mul<S> for Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Complex::new(self.re * other, self.im * other)
+    }
+}
+
+impl<S> ops::Mul<S> for &Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        Complex::new(self.re * other, self.im * other)
+    }
+}
+
+impl<S> ops::Div<S> for Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Complex::new(self.re / other, self.im / other)
+    }
+}
+
+impl<S> ops::Div<S> for &Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn div(self, other: S) -> Self::Output {
+        Complex::new(self.re / other, self.im / other)
+    }
+}
+
+impl<S> ops::Rem<S> for Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn rem(self, other: S) -> Self::Output {
+        Complex::new(self.re % other, self.im % other)
+    }
+}
+
+impl<S> ops::Rem<S> for &Complex<S> 
+where 
+    S: Scalar 
+{
+    type Output = Complex<S>;
+
+    #[inline]
+    fn rem(self, other: S) -> Self::Output {
+        Complex::new(self.re % other, self.im % other)
+    }
+}
+
+macro_rules! impl_scalar_complex_mul_ops {
+    ($Lhs:ty) => {
+        impl ops::Mul<Complex<$Lhs>> for $Lhs {
+            type Output = Complex<$Lhs>;
+
+            #[inline]
+            fn mul(self, other: Complex<$Lhs>) -> Self::Output {
+                Self::Output::new(self * other.re, self * other.im)
+            }
+        }
+
+        impl ops::Mul<&Complex<$Lhs>> for $Lhs {
+            type Output = Complex<$Lhs>;
+
+            #[inline]
+            fn mul(self, other: &Complex<$Lhs>) -> Self::Output {
+                Self::Output::new(self * other.re, self * other.im)
+            }
+        }
+
+        impl ops::Mul<Complex<$Lhs>> for &$Lhs {
+            type Output = Complex<$Lhs>;
+
+            #[inline]
+            fn mul(self, other: Complex<$Lhs>) -> Self::Output {
+                Self::Output::new(self * other.re, self * other.im)
+            }
+        }
+
+        impl ops::Mul<&Complex<$Lhs>> for &$Lhs {
+            type Output = Complex<$Lhs>;
+
+            #[inline]
+            fn mul(self, other: &Complex<$Lhs>) -> Self::Output {
+                Self::Output::new(self * other.re, self * other.im)
+            }
+        }
+    }
+}
+
+impl_scalar_complex_mul_ops!(u8);
+impl_scalar_complex_mul_ops!(u16);
+impl_scalar_complex_mul_ops!(u32);
+impl_scalar_complex_mul_ops!(u64);
+impl_scalar_complex_mul_ops!(u128);
+impl_scalar_complex_mul_ops!(usize);
+impl_scalar_complex_mul_ops!(i8);
+impl_scalar_complex_mul_ops!(i16);
+impl_scalar_complex_mul_ops!(i32);
+impl_scalar_complex_mul_ops!(i64);
+impl_scalar_complex_mul_ops!(i128);
+impl_scalar_complex_mul_ops!(isize);
+impl_scalar_complex_mul_ops!(f32);
+impl_scalar_complex_mul_ops!(f64);
+
+
+impl<S> ops::AddAssign<Complex<S>> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn add_assign(&mut self, other: Complex<S>) {
+        self.re += other.re;
+        self.im += other.im;
+    }
+}
+
+impl<S> ops::AddAssign<&Complex<S>> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn add_assign(&mut self, other: &Complex<S>) {
+        self.re += other.re;
+        self.im += other.im;
+    }
+}
+
+impl<S> ops::SubAssign<Complex<S>> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Complex<S>) {
+        self.re -= other.re;
+        self.im -= other.im;
+    }
+}
+
+impl<S> ops::SubAssign<&Complex<S>> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn sub_assign(&mut self, other: &Complex<S>) {
+        self.re -= other.re;
+        self.im -= other.im;
+    }
+}
+
+impl<S> ops::MulAssign<S> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn mul_assign(&mut self, other: S) {
+        self.re *= other;
+        self.im *= other;
+    }
+}
+
+impl<S> ops::DivAssign<S> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn div_assign(&mut self, other: S) {
+        self.re /= other;
+        self.im /= other;
+    }
+}
+
+impl<S> ops::RemAssign<S> for Complex<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn rem_assign(&mut self, other: S) {
+        self.re %= other;
+        self.im %= other;
+    }
+}
+
+impl<S> Magnitude for Complex<S> 
+where 
+    S: ScalarFloat 
+{
+    type Output = S;
+
+    #[inline]
+    fn magnitude_squared(&self) -> Self::Output {
+        self.dot(self)
+    }
+
+    #[inline]
+    fn magnitude(&self) -> Self::Output {
+        self.re.hypot(self.im)
+    }
+
+    #[inline]
+    fn normalize(&self) -> Self {
+        if use_fast_math::<S>() {
+            self * fast_rsqrt(self.magnitude_squared())
+        } else {
+            self / self.magnitude()
+        }
+    }
+
+    #[inline]
+    fn normalize_to(&self, magnitude: Self::Output) -> Self {
+        self * (magnitude / self.magnitude())
+    }
+
+    #[inline]
+    fn try_normalize(&self, threshold: Self::Output) -> Option<Self> {
+        let magnitude = self.magnitude();
+
+        if magnitude <= threshold {
+            None
+        } else {
+            Some(self.normalize())
+        }
+    }
+
+    #[inline]
+    fn distance_squared(&self, other: &Complex<S>) -> S {
+        (self - other).magnitude_squared()
+    }
+
+    #[inline]
+    fn distance(&self, other: &Self) -> Self::Output {
+        self.distance_squared(other).sqrt()
+    }
+}
+
+impl<S> approx::AbsDiffEq for Complex<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.re, &other.re, epsilon) &&
+        S::abs_diff_eq(&self.im, &other.im, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Complex<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.re, &other.re, epsilon, max_relative) &&
+        S::relative_eq(&self.im, &other.im, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Complex<S> 
+where 
+    S: ScalarFloat
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.re, &other.re, epsilon, max_ulps) &&
+        S::ulps_eq(&self.im, &other.im, epsilon, max_ulps)
+    }
+}
+
+impl<S> approx::AbsDiffEq for Unit<Complex<S>> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Complex::abs_diff_eq(self.as_ref(), other.as_ref(), epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Unit<Complex<S>> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Complex::relative_eq(self.as_ref(), other.as_ref(), epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Unit<Complex<S>> 
+where 
+    S: ScalarFloat
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Complex::ulps_eq(self.as_ref(), other.as_ref(), epsilon, max_ulps)
+    }
+}
+
+
+impl<S> ops::Mul<Unit<Complex<S>>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Unit<Complex<S>>;
+
+    #[inline]
+    fn mul(self, other: Unit<Complex<S>>) -> Self::Output {
+        let product: Complex<S> = self.as_ref() * other.as_ref();
+
+        Unit::from_value_unchecked(product)
+    }
+}
+
+impl<S> ops::Mul<&Unit<Complex<S>>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Unit<Complex<S>>;
+
+    #[inline]
+    fn mul(self, other: &Unit<Complex<S>>) -> Self::Output {
+        let product: Complex<S> = self.as_ref() * other.as_ref();
+
+        Unit::from_value_unchecked(product)
+    }
+}
+
+impl<S> ops::Mul<Unit<Complex<S>>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Unit<Complex<S>>;
+
+    #[inline]
+    fn mul(self, other: Unit<Complex<S>>) -> Self::Output {
+        let product: Complex<S> = self.as_ref() * other.as_ref();
+
+        Unit::from_value_unchecked(product)
+    }
+}
+
+impl<S> ops::Mul<&Unit<Complex<S>>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Unit<Complex<S>>;
+
+    #[inline]
+    fn mul(self, other: &Unit<Complex<S>>) -> Self::Output {
+        let product: Complex<S> = self.as_ref() * other.as_ref();
+
+        Unit::from_value_unchecked(product)
+    }
+}
+
+impl<S> ops::Mul<Vector2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: Vector2<S>) -> Self::Output {
+        self.rotate_vector(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector2<S>) -> Self::Output {
+        self.rotate_vector(other)
+    }
+}
+
+impl<S> ops::Mul<Vector2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: Vector2<S>) -> Self::Output {
+        self.rotate_vector(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector2<S>) -> Self::Output {
+        self.rotate_vector(other)
+    }
+}
+
+impl<S> ops::Mul<Point2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: Point2<S>) -> Self::Output {
+        self.rotate_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: &Point2<S>) -> Self::Output {
+        self.rotate_point(other)
+    }
+}
+
+impl<S> ops::Mul<Point2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: Point2<S>) -> Self::Output {
+        self.rotate_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: &Point2<S>) -> Self::Output {
+        self.rotate_point(other)
+    }
+}
+
+impl<S> ops::Mul<Isometry2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: Isometry2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(&self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Isometry2::from_parts(&translation, &rotation)
+    }
+}
+
+impl<S> ops::Mul<&Isometry2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: &Isometry2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(&self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Isometry2::from_parts(&translation, &rotation)
+    }
+}
+
+impl<S> ops::Mul<Isometry2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: Isometry2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Isometry2::from_parts(&translation, &rotation)
+    }
+}
+
+impl<S> ops::Mul<&Isometry2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: &Isometry2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Isometry2::from_parts(&translation, &rotation)
+    }
+}
+
+impl<S> ops::Mul<Unit<Complex<S>>> for Isometry2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Isometry2::from_parts(self.translation(), &(self.rotation() * rotation))
+    }
+}
+
+impl<S> ops::Mul<&Unit<Complex<S>>> for Isometry2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: &Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Isometry2::from_parts(self.translation(), &(self.rotation() * rotation))
+    }
+}
+
+impl<S> ops::Mul<Unit<Complex<S>>> for &Isometry2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Isometry2::from_parts(self.translation(), &(self.rotation() * rotation))
+    }
+}
+
+impl<S> ops::Mul<&Unit<Complex<S>>> for &Isometry2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Isometry2<S>;
+
+    #[inline]
+    fn mul(self, other: &Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Isometry2::from_parts(self.translation(), &(self.rotation() * rotation))
+    }
+}
+
+impl<S> ops::Mul<Similarity2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: Similarity2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(&self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Similarity2::from_parts(&translation, &rotation, other.scale())
+    }
+}
+
+impl<S> ops::Mul<&Similarity2<S>> for Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: &Similarity2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(&self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Similarity2::from_parts(&translation, &rotation, other.scale())
+    }
+}
+
+impl<S> ops::Mul<Similarity2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: Similarity2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Similarity2::from_parts(&translation, &rotation, other.scale())
+    }
+}
+
+impl<S> ops::Mul<&Similarity2<S>> for &Unit<Complex<S>>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: &Similarity2<S>) -> Self::Output {
+        let rotation = Rotation2::from_unit_complex(self) * other.rotation();
+        let translation = Translation2::from_vector(&self.rotate_vector(&other.translation().vector));
+
+        Similarity2::from_parts(&translation, &rotation, other.scale())
+    }
+}
+
+impl<S> ops::Mul<Unit<Complex<S>>> for Similarity2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Similarity2::from_parts(self.translation(), &(self.rotation() * rotation), self.scale())
+    }
+}
+
+impl<S> ops::Mul<&Unit<Complex<S>>> for Similarity2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: &Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Similarity2::from_parts(self.translation(), &(self.rotation() * rotation), self.scale())
+    }
+}
+
+impl<S> ops::Mul<Unit<Complex<S>>> for &Similarity2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Similarity2::from_parts(self.translation(), &(self.rotation() * rotation), self.scale())
+    }
+}
+
+impl<S> ops::Mul<&Unit<Complex<S>>> for &Similarity2<S>
+where
+    S: ScalarFloat
+{
+    type Output = Similarity2<S>;
+
+    #[inline]
+    fn mul(self, other: &Unit<Complex<S>>) -> Self::Output {
+        let rotation = other.to_rotation();
+
+        Similarity2::from_parts(self.translation(), &(self.rotation() * rotation), self.scale())
+    }
+}
@@ -0,0 +1,425 @@
+use crate::base::{
+    ScalarFloat,
+};
+use crate::angle::{
+    Radians,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::quaternion::{
+    Quaternion,
+};
+use crate::affine::{
+    Affine2,
+    Affine3,
+};
+use crate::isometry::{
+    Isometry2,
+    Isometry3,
+};
+use crate::projection::{
+    Orthographic3,
+    OrthographicFov3,
+    Perspective3,
+    PerspectiveFov3,
+};
+use crate::projective::{
+    Projective2,
+    Projective3,
+};
+use crate::reflection::{
+    Reflection2,
+    Reflection3,
+};
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+use crate::scale::{
+    Scale2,
+    Scale3,
+};
+use crate::shear::{
+    Shear2,
+    Shear3,
+};
+use crate::similarity::{
+    Similarity2,
+    Similarity3,
+};
+use crate::transform::{
+    Transform2,
+    Transform3,
+};
+use crate::translation::{
+    Translation2,
+    Translation3,
+};
+
+use core::ops;
+
+
+// Composition operators between different transformation types. The product 
+// `lhs * rhs` is the transformation that applies `rhs` first, followed by `lhs`. 
+// Each product returns the most specific transformation type that can 
+// represent the composite: rotations and translations compose into 
+// isometries, isometries and similarities compose into similarities, any 
+// structured transformation composes with an affine transformation into an 
+// affine transformation, any composite involving a projection or a 
+// projective transformation is a projective transformation, and any other 
+// composite involving a scale, shear, reflection, or generic transformation 
+// falls back to a generic transformation. The products already defined 
+// alongside the types themselves, such as the product of two transformations of 
+// the same type, are not repeated here.
+macro_rules! impl_transform_composition_ops {
+    ($Lhs:ident, $Rhs:ident, $Output:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl<S> ops::Mul<$Rhs<S>> for $Lhs<S> 
+        where 
+            S: ScalarFloat 
+        {
+            type Output = $Output<S>;
+
+            #[inline]
+            fn mul(self, other: $Rhs<S>) -> Self::Output {
+                let $lhs = &self;
+                let $rhs = &other;
+
+                $body
+            }
+        }
+
+        impl<S> ops::Mul<&$Rhs<S>> for $Lhs<S> 
+        where 
+            S: ScalarFloat 
+        {
+            type Output = $Output<S>;
+
+            #[inline]
+            fn mul(self, other: &$Rhs<S>) -> Self::Output {
+                let $lhs = &self;
+                let $rhs = other;
+
+                $body
+            }
+        }
+
+        impl<S> ops::Mul<$Rhs<S>> for &$Lhs<S> 
+        where 
+            S: ScalarFloat 
+        {
+            type Output = $Output<S>;
+
+            #[inline]
+            fn mul(self, other: $Rhs<S>) -> Self::Output {
+                let $lhs = self;
+                let $rhs = &other;
+
+                $body
+            }
+        }
+
+        impl<S> ops::Mul<&$Rhs<S>> for &$Lhs<S> 
+        where 
+            S: ScalarFloat 
+        {
+            type Output = $Output<S>;
+
+            #[inline]
+            fn mul(self, other: &$Rhs<S>) -> Self::Output {
+                let $lhs = self;
+                let $rhs = other;
+
+                $body
+            }
+        }
+    }
+}
+
+
+// Compositions of two-dimensional transformations.
+
+impl_transform_composition_ops!(Translation2, Rotation2, Isometry2, |lhs, rhs| Isometry2::from_parts(lhs, rhs));
+impl_transform_composition_ops!(Translation2, Scale2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Translation2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Translation2, Reflection2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Translation2, Isometry2, Isometry2, |lhs, rhs| Isometry2::from_translation(lhs) * rhs);
+impl_transform_composition_ops!(Translation2, Similarity2, Similarity2, |lhs, rhs| Similarity2::from_translation(lhs) * rhs);
+impl_transform_composition_ops!(Translation2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Rotation2, Translation2, Isometry2, |lhs, rhs| Isometry2::from_rotation(lhs) * Isometry2::from_translation(rhs));
+impl_transform_composition_ops!(Rotation2, Scale2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Rotation2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Rotation2, Reflection2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Rotation2, Isometry2, Isometry2, |lhs, rhs| Isometry2::from_rotation(lhs) * rhs);
+impl_transform_composition_ops!(Rotation2, Similarity2, Similarity2, |lhs, rhs| Similarity2::from_rotation(lhs) * rhs);
+impl_transform_composition_ops!(Rotation2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Scale2, Translation2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale2, Rotation2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale2, Reflection2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale2, Isometry2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale2, Similarity2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Shear2, Translation2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Rotation2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Scale2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Reflection2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Isometry2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Similarity2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Reflection2, Translation2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection2, Rotation2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection2, Scale2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection2, Reflection2, Isometry2, |lhs, rhs| compose_reflections2(lhs, rhs));
+impl_transform_composition_ops!(Reflection2, Isometry2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection2, Similarity2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Isometry2, Translation2, Isometry2, |lhs, rhs| lhs * Isometry2::from_translation(rhs));
+impl_transform_composition_ops!(Isometry2, Rotation2, Isometry2, |lhs, rhs| lhs * Isometry2::from_rotation(rhs));
+impl_transform_composition_ops!(Isometry2, Scale2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Isometry2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Isometry2, Reflection2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Isometry2, Similarity2, Similarity2, |lhs, rhs| Similarity2::from_isometry(*lhs) * rhs);
+impl_transform_composition_ops!(Isometry2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Similarity2, Translation2, Similarity2, |lhs, rhs| lhs * Similarity2::from_translation(rhs));
+impl_transform_composition_ops!(Similarity2, Rotation2, Similarity2, |lhs, rhs| lhs * Similarity2::from_rotation(rhs));
+impl_transform_composition_ops!(Similarity2, Scale2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Similarity2, Shear2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Similarity2, Reflection2, Transform2, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Similarity2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Transform2, Translation2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform2, Rotation2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform2, Scale2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform2, Shear2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform2, Reflection2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform2, Isometry2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform2, Similarity2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+
+impl_transform_composition_ops!(Affine2, Translation2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Translation2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Rotation2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Rotation2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Scale2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Scale2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Shear2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Shear2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Reflection2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Reflection2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Isometry2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Isometry2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Similarity2, Affine2, |lhs, rhs| lhs * Affine2::from(rhs));
+impl_transform_composition_ops!(Similarity2, Affine2, Affine2, |lhs, rhs| Affine2::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine2, Transform2, Transform2, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Transform2, Affine2, Transform2, |lhs, rhs| lhs * rhs.to_transform());
+
+impl_transform_composition_ops!(Projective2, Translation2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Translation2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Rotation2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Rotation2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Scale2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Scale2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Shear2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Shear2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Reflection2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Reflection2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Isometry2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Isometry2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Similarity2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Similarity2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Affine2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Affine2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective2, Transform2, Projective2, |lhs, rhs| lhs * Projective2::from(rhs));
+impl_transform_composition_ops!(Transform2, Projective2, Projective2, |lhs, rhs| Projective2::from(lhs) * rhs);
+
+
+// Compositions of three-dimensional transformations.
+
+impl_transform_composition_ops!(Translation3, Rotation3, Isometry3, |lhs, rhs| Isometry3::from_parts(lhs, rhs));
+impl_transform_composition_ops!(Translation3, Scale3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Translation3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Translation3, Reflection3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Translation3, Isometry3, Isometry3, |lhs, rhs| Isometry3::from_translation(lhs) * rhs);
+impl_transform_composition_ops!(Translation3, Similarity3, Similarity3, |lhs, rhs| Similarity3::from_translation(lhs) * rhs);
+impl_transform_composition_ops!(Translation3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Rotation3, Translation3, Isometry3, |lhs, rhs| Isometry3::from_rotation(lhs) * Isometry3::from_translation(rhs));
+impl_transform_composition_ops!(Rotation3, Scale3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Rotation3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Rotation3, Reflection3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Rotation3, Isometry3, Isometry3, |lhs, rhs| Isometry3::from_rotation(lhs) * rhs);
+impl_transform_composition_ops!(Rotation3, Similarity3, Similarity3, |lhs, rhs| Similarity3::from_rotation(lhs) * rhs);
+impl_transform_composition_ops!(Rotation3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Scale3, Translation3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale3, Rotation3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale3, Reflection3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale3, Isometry3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale3, Similarity3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Scale3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Shear3, Translation3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Rotation3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Scale3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Reflection3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Isometry3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Similarity3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Shear3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Reflection3, Translation3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection3, Rotation3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection3, Scale3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection3, Reflection3, Isometry3, |lhs, rhs| compose_reflections3(lhs, rhs));
+impl_transform_composition_ops!(Reflection3, Isometry3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection3, Similarity3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Reflection3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Isometry3, Translation3, Isometry3, |lhs, rhs| lhs * Isometry3::from_translation(rhs));
+impl_transform_composition_ops!(Isometry3, Rotation3, Isometry3, |lhs, rhs| lhs * Isometry3::from_rotation(rhs));
+impl_transform_composition_ops!(Isometry3, Scale3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Isometry3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Isometry3, Reflection3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Isometry3, Similarity3, Similarity3, |lhs, rhs| Similarity3::from_isometry(lhs) * rhs);
+impl_transform_composition_ops!(Isometry3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Similarity3, Translation3, Similarity3, |lhs, rhs| lhs * Similarity3::from_translation(rhs));
+impl_transform_composition_ops!(Similarity3, Rotation3, Similarity3, |lhs, rhs| lhs * Similarity3::from_rotation(rhs));
+impl_transform_composition_ops!(Similarity3, Scale3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Similarity3, Shear3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Similarity3, Reflection3, Transform3, |lhs, rhs| lhs.to_transform() * rhs.to_transform());
+impl_transform_composition_ops!(Similarity3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Transform3, Translation3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform3, Rotation3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform3, Scale3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform3, Shear3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform3, Reflection3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform3, Isometry3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+impl_transform_composition_ops!(Transform3, Similarity3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+
+impl_transform_composition_ops!(Affine3, Translation3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Translation3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Rotation3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Rotation3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Scale3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Scale3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Shear3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Shear3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Reflection3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Reflection3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Isometry3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Isometry3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Similarity3, Affine3, |lhs, rhs| lhs * Affine3::from(rhs));
+impl_transform_composition_ops!(Similarity3, Affine3, Affine3, |lhs, rhs| Affine3::from(lhs) * rhs);
+impl_transform_composition_ops!(Affine3, Transform3, Transform3, |lhs, rhs| lhs.to_transform() * rhs);
+impl_transform_composition_ops!(Transform3, Affine3, Transform3, |lhs, rhs| lhs * rhs.to_transform());
+
+impl_transform_composition_ops!(Projective3, Translation3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Translation3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Rotation3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Rotation3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Scale3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Scale3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Shear3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Shear3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Reflection3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Reflection3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Isometry3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Isometry3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Similarity3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Similarity3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Affine3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Affine3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Transform3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Transform3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Perspective3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, PerspectiveFov3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, Orthographic3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Projective3, OrthographicFov3, Projective3, |lhs, rhs| lhs * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Projective3, Projective3, |lhs, rhs| Projective3::from(lhs) * rhs);
+impl_transform_composition_ops!(Perspective3, Translation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Rotation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Scale3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Shear3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Reflection3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Isometry3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Similarity3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Affine3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Perspective3, Transform3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Translation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Rotation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Scale3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Shear3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Reflection3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Isometry3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Similarity3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Affine3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(PerspectiveFov3, Transform3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Translation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Rotation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Scale3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Shear3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Reflection3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Isometry3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Similarity3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Affine3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(Orthographic3, Transform3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Translation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Rotation3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Scale3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Shear3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Reflection3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Isometry3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Similarity3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Affine3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+impl_transform_composition_ops!(OrthographicFov3, Transform3, Projective3, |lhs, rhs| Projective3::from(lhs) * Projective3::from(rhs));
+
+
+/// Compose two reflections in two dimensions.
+///
+/// The composite of two reflections about lines with unit normals `n1` and 
+/// `n2` is a rotation by twice the angle from `n2` to `n1`, about the 
+/// intersection point of the two lines. When the lines are parallel, the 
+/// composite is a translation.
+#[inline]
+fn compose_reflections2<S>(lhs: &Reflection2<S>, rhs: &Reflection2<S>) -> Isometry2<S> 
+where 
+    S: ScalarFloat 
+{
+    let two = S::one() + S::one();
+    let normal_lhs = lhs.normal();
+    let normal_rhs = rhs.normal();
+    let cos_angle = normal_rhs.dot(&normal_lhs);
+    let sin_angle = normal_rhs.x * normal_lhs.y - normal_rhs.y * normal_lhs.x;
+    let rotation = Rotation2::from_angle(Radians(two * sin_angle.atan2(cos_angle)));
+    let origin = Point2::origin();
+    let translation = Translation2::from_vector(
+        &lhs.reflect_point(&rhs.reflect_point(&origin)).to_vector()
+    );
+
+    Isometry2::from_parts(&translation, &rotation)
+}
+
+/// Compose two reflections in three dimensions.
+///
+/// The composite of two reflections about planes with unit normals `n1` and 
+/// `n2` is a rotation about the line of intersection of the two planes by twice 
+/// the angle from `n2` to `n1`. When the planes are parallel, the composite 
+/// is a translation. The rotation part is the unit quaternion `n1 * n2`, 
+/// where the normals are treated as pure quaternions.
+#[inline]
+fn compose_reflections3<S>(lhs: &Reflection3<S>, rhs: &Reflection3<S>) -> Isometry3<S> 
+where 
+    S: ScalarFloat 
+{
+    let normal_lhs = Quaternion::from_pure(lhs.normal());
+    let normal_rhs = Quaternion::from_pure(rhs.normal());
+    let rotation = Rotation3::from_quaternion(&(normal_lhs * normal_rhs));
+    let origin = Point3::origin();
+    let translation = Translation3::from_vector(
+        &lhs.reflect_point(&rhs.reflect_point(&origin)).to_vector()
+    );
+
+    Isometry3::from_parts(&translation, &rotation)
+}
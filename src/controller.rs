@@ -0,0 +1,609 @@
+use crate::base::{
+    Magnitude,
+    ScalarFloat,
+    Unit,
+};
+use crate::angle::{
+    Angle,
+    Radians,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::quaternion::{
+    Quaternion,
+};
+use crate::isometry::{
+    Isometry3,
+};
+use crate::rotation::{
+    Rotation3,
+};
+use crate::translation::{
+    Translation3,
+};
+
+use core::fmt;
+
+
+/// The largest magnitude of a pitch angle that the camera controllers allow.
+///
+/// The limit lies slightly inside a quarter turn, so that the viewing
+/// direction never becomes parallel to the up direction of the world.
+fn pitch_limit<S>() -> Radians<S> 
+where 
+    S: ScalarFloat 
+{
+    let margin: S = num_traits::cast(0.001).unwrap();
+
+    Radians::full_turn_div_4() - Radians(margin)
+}
+
+/// Clamp a pitch angle into `[-pitch_limit, pitch_limit]`.
+fn clamp_pitch<S>(pitch: Radians<S>) -> Radians<S> 
+where 
+    S: ScalarFloat 
+{
+    let limit = pitch_limit();
+    if pitch > limit {
+        limit
+    } else if pitch < -limit {
+        -limit
+    } else {
+        pitch
+    }
+}
+
+/// Construct the view transformation of a camera at `eye` whose orientation
+/// rotates camera space into world space.
+fn view_from_pose<S>(eye: &Point3<S>, orientation: &Quaternion<S>) -> Isometry3<S> 
+where 
+    S: ScalarFloat 
+{
+    let translation = Translation3::from_vector(&eye.to_vector());
+    let rotation = Rotation3::from_quaternion(orientation);
+
+    Isometry3::from_parts(&translation, &rotation).inverse()
+}
+
+
+/// An orbit camera, also known as a turntable camera.
+///
+/// An orbit camera circles a target point at a fixed distance, always looking
+/// at the target. The **y-axis** points up in world space. The yaw angle turns
+/// the camera about the vertical axis through the target, and the pitch angle
+/// raises the camera above the horizontal plane through the target, so that
+/// a positive pitch looks down at the target. With zero yaw and zero pitch,
+/// the camera sits on the **positive z-axis** side of the target and looks
+/// down the **negative z-axis**. The pitch stays strictly between `-90` and
+/// `90` degrees, so the camera never flips over the poles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OrbitCamera<S> {
+    target: Point3<S>,
+    distance: S,
+    yaw: Radians<S>,
+    pitch: Radians<S>,
+}
+
+impl<S> OrbitCamera<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a new orbit camera.
+    ///
+    /// The pitch angle is clamped to lie strictly between `-90` and `90`
+    /// degrees.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     OrbitCamera,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let target = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let camera = OrbitCamera::new(target, 5_f64, Degrees(90_f64), Degrees(0_f64));
+    ///
+    /// assert!(relative_eq!(camera.eye(), Point3::new(6_f64, 2_f64, 3_f64), epsilon = 1e-10));
+    /// assert!(relative_eq!(camera.view().transform_point(&target), Point3::new(0_f64, 0_f64, -5_f64), epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn new<A: Into<Radians<S>>>(target: Point3<S>, distance: S, yaw: A, pitch: A) -> OrbitCamera<S> {
+        OrbitCamera {
+            target: target,
+            distance: distance,
+            yaw: yaw.into(),
+            pitch: clamp_pitch(pitch.into()),
+        }
+    }
+
+    /// Get the point the camera looks at.
+    #[inline]
+    pub fn target(&self) -> Point3<S> {
+        self.target
+    }
+
+    /// Get the distance from the camera to the target.
+    #[inline]
+    pub fn distance(&self) -> S {
+        self.distance
+    }
+
+    /// Get the yaw angle of the camera about the vertical axis.
+    #[inline]
+    pub fn yaw(&self) -> Radians<S> {
+        self.yaw
+    }
+
+    /// Get the pitch angle of the camera above the horizontal plane.
+    #[inline]
+    pub fn pitch(&self) -> Radians<S> {
+        self.pitch
+    }
+
+    /// Move the point the camera looks at, keeping the camera angles and the
+    /// distance to the target.
+    #[inline]
+    pub fn set_target(&mut self, target: Point3<S>) {
+        self.target = target;
+    }
+
+    /// Set the distance from the camera to the target.
+    #[inline]
+    pub fn set_distance(&mut self, distance: S) {
+        self.distance = distance;
+    }
+
+    /// Turn the camera around the target. The pitch angle stays clamped to lie
+    /// strictly between `-90` and `90` degrees.
+    #[inline]
+    pub fn rotate<A: Into<Radians<S>>>(&mut self, delta_yaw: A, delta_pitch: A) {
+        self.yaw += delta_yaw.into();
+        self.pitch = clamp_pitch(self.pitch + delta_pitch.into());
+    }
+
+    /// Scale the distance from the camera to the target. A factor less than
+    /// one moves the camera toward the target.
+    #[inline]
+    pub fn zoom(&mut self, factor: S) {
+        self.distance *= factor;
+    }
+
+    /// Move the camera and the target together in the plane of the image. The
+    /// offsets are measured along the right and up directions of the camera.
+    #[inline]
+    pub fn pan(&mut self, right: S, up: S) {
+        let orientation = Rotation3::from_quaternion(&self.orientation());
+        let offset = orientation.rotate_vector(&Vector3::new(right, up, S::zero()));
+        self.target += offset;
+    }
+
+    /// Get the position of the camera.
+    #[inline]
+    pub fn eye(&self) -> Point3<S> {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+        let offset = Vector3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw);
+
+        self.target + offset * self.distance
+    }
+
+    /// Get the orientation of the camera, which rotates camera space into
+    /// world space.
+    #[inline]
+    pub fn orientation(&self) -> Quaternion<S> {
+        let axis_x = Unit::from_value(Vector3::unit_x());
+        let axis_y = Unit::from_value(Vector3::unit_y());
+
+        Quaternion::from_axis_angle(&axis_y, self.yaw) * Quaternion::from_axis_angle(&axis_x, -self.pitch)
+    }
+
+    /// Construct the view transformation of the camera, which maps world
+    /// space into camera space.
+    #[inline]
+    pub fn view(&self) -> Isometry3<S> {
+        Isometry3::look_at_rh(&self.eye(), &self.target, &Vector3::unit_y())
+    }
+}
+
+impl<S> fmt::Display for OrbitCamera<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "OrbitCamera [target={}, distance={}, yaw={}, pitch={}]",
+            self.target, self.distance, self.yaw, self.pitch
+        )
+    }
+}
+
+
+/// A first person fly camera.
+///
+/// A fly camera sits at a position in world space and looks in the direction
+/// given by its yaw and pitch angles. The **y-axis** points up in world
+/// space. The yaw angle turns the camera to the left about the vertical axis,
+/// and the pitch angle tilts the camera up. With zero yaw and zero pitch, the
+/// camera looks down the **negative z-axis**. The pitch stays strictly between
+/// `-90` and `90` degrees, so the camera never looks straight up or down.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FlyCamera<S> {
+    position: Point3<S>,
+    yaw: Radians<S>,
+    pitch: Radians<S>,
+}
+
+impl<S> FlyCamera<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a new fly camera.
+    ///
+    /// The pitch angle is clamped to lie strictly between `-90` and `90`
+    /// degrees.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     FlyCamera,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let mut camera = FlyCamera::new(Point3::new(0_f64, 1_f64, 0_f64), Degrees(90_f64), Degrees(0_f64));
+    ///
+    /// assert!(relative_eq!(camera.forward(), -Vector3::unit_x(), epsilon = 1e-10));
+    ///
+    /// camera.move_forward(2_f64);
+    ///
+    /// assert!(relative_eq!(camera.position(), Point3::new(-2_f64, 1_f64, 0_f64), epsilon = 1e-10));
+    ///
+    /// camera.rotate(Degrees(0_f64), Degrees(120_f64));
+    ///
+    /// assert!(camera.pitch() < Degrees(90_f64).into());
+    /// ```
+    #[inline]
+    pub fn new<A: Into<Radians<S>>>(position: Point3<S>, yaw: A, pitch: A) -> FlyCamera<S> {
+        FlyCamera {
+            position: position,
+            yaw: yaw.into(),
+            pitch: clamp_pitch(pitch.into()),
+        }
+    }
+
+    /// Get the position of the camera.
+    #[inline]
+    pub fn position(&self) -> Point3<S> {
+        self.position
+    }
+
+    /// Get the yaw angle of the camera about the vertical axis.
+    #[inline]
+    pub fn yaw(&self) -> Radians<S> {
+        self.yaw
+    }
+
+    /// Get the pitch angle of the camera above the horizontal plane.
+    #[inline]
+    pub fn pitch(&self) -> Radians<S> {
+        self.pitch
+    }
+
+    /// Move the camera to a new position, keeping its viewing direction.
+    #[inline]
+    pub fn set_position(&mut self, position: Point3<S>) {
+        self.position = position;
+    }
+
+    /// Turn the camera. The pitch angle stays clamped to lie strictly between
+    /// `-90` and `90` degrees.
+    #[inline]
+    pub fn rotate<A: Into<Radians<S>>>(&mut self, delta_yaw: A, delta_pitch: A) {
+        self.yaw += delta_yaw.into();
+        self.pitch = clamp_pitch(self.pitch + delta_pitch.into());
+    }
+
+    /// Get the unit direction the camera looks in.
+    #[inline]
+    pub fn forward(&self) -> Vector3<S> {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+
+        Vector3::new(-sin_yaw * cos_pitch, sin_pitch, -cos_yaw * cos_pitch)
+    }
+
+    /// Get the unit direction to the right of the camera. The direction
+    /// always lies in the horizontal plane.
+    #[inline]
+    pub fn right(&self) -> Vector3<S> {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+
+        Vector3::new(cos_yaw, S::zero(), -sin_yaw)
+    }
+
+    /// Get the unit up direction of the camera.
+    #[inline]
+    pub fn up(&self) -> Vector3<S> {
+        self.right().cross(&self.forward())
+    }
+
+    /// Move the camera along its viewing direction.
+    #[inline]
+    pub fn move_forward(&mut self, distance: S) {
+        self.position += self.forward() * distance;
+    }
+
+    /// Move the camera along its right direction.
+    #[inline]
+    pub fn move_right(&mut self, distance: S) {
+        self.position += self.right() * distance;
+    }
+
+    /// Move the camera along the up direction of the world.
+    #[inline]
+    pub fn move_up(&mut self, distance: S) {
+        self.position += Vector3::unit_y() * distance;
+    }
+
+    /// Get the orientation of the camera, which rotates camera space into
+    /// world space.
+    #[inline]
+    pub fn orientation(&self) -> Quaternion<S> {
+        let axis_x = Unit::from_value(Vector3::unit_x());
+        let axis_y = Unit::from_value(Vector3::unit_y());
+
+        Quaternion::from_axis_angle(&axis_y, self.yaw) * Quaternion::from_axis_angle(&axis_x, self.pitch)
+    }
+
+    /// Construct the view transformation of the camera, which maps world
+    /// space into camera space.
+    #[inline]
+    pub fn view(&self) -> Isometry3<S> {
+        let target = self.position + self.forward();
+
+        Isometry3::look_at_rh(&self.position, &target, &Vector3::unit_y())
+    }
+}
+
+impl<S> fmt::Display for FlyCamera<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "FlyCamera [position={}, yaw={}, pitch={}]",
+            self.position, self.yaw, self.pitch
+        )
+    }
+}
+
+
+/// A virtual trackball camera following Ken Shoemake's arcball.
+///
+/// An arcball camera circles a target point at a fixed distance. Dragging the
+/// mouse across the window rolls a virtual ball centered in the window, and the
+/// scene turns with the ball. The ball is the largest circle that fits in the
+/// window. Mouse positions are measured in pixels, with the origin at the top
+/// left corner of the window and the **y-axis** pointing down, as in most
+/// windowing systems. Drags outside the ball rotate about the viewing
+/// direction.
+///
+/// As in Shoemake's original construction, a drag between two points on the
+/// ball rotates the scene by twice the angle between them. This makes the
+/// rotations independent of the path of the mouse: the rotation of a drag
+/// depends only on where it starts and ends.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ArcballCamera<S> {
+    target: Point3<S>,
+    distance: S,
+    orientation: Quaternion<S>,
+    width: S,
+    height: S,
+}
+
+impl<S> ArcballCamera<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a new arcball camera looking down the **negative z-axis**
+    /// at the target, in a window of size `width x height`.
+    #[inline]
+    pub fn new(target: Point3<S>, distance: S, width: S, height: S) -> ArcballCamera<S> {
+        ArcballCamera {
+            target: target,
+            distance: distance,
+            orientation: Quaternion::identity(),
+            width: width,
+            height: height,
+        }
+    }
+
+    /// Get the point the camera looks at.
+    #[inline]
+    pub fn target(&self) -> Point3<S> {
+        self.target
+    }
+
+    /// Get the distance from the camera to the target.
+    #[inline]
+    pub fn distance(&self) -> S {
+        self.distance
+    }
+
+    /// Get the orientation of the camera, which rotates camera space into
+    /// world space.
+    #[inline]
+    pub fn orientation(&self) -> Quaternion<S> {
+        self.orientation
+    }
+
+    /// Move the point the camera looks at, keeping the orientation and the
+    /// distance to the target.
+    #[inline]
+    pub fn set_target(&mut self, target: Point3<S>) {
+        self.target = target;
+    }
+
+    /// Set the distance from the camera to the target.
+    #[inline]
+    pub fn set_distance(&mut self, distance: S) {
+        self.distance = distance;
+    }
+
+    /// Set the orientation of the camera. The orientation is normalized.
+    #[inline]
+    pub fn set_orientation(&mut self, orientation: &Quaternion<S>) {
+        self.orientation = orientation.normalize();
+    }
+
+    /// Set the size of the window that the mouse moves in.
+    #[inline]
+    pub fn set_window_size(&mut self, width: S, height: S) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Scale the distance from the camera to the target. A factor less than
+    /// one moves the camera toward the target.
+    #[inline]
+    pub fn zoom(&mut self, factor: S) {
+        self.distance *= factor;
+    }
+
+    /// Map a mouse position in the window to a unit vector on the arcball in
+    /// camera space.
+    ///
+    /// Positions inside the ball map onto its front hemisphere. Positions
+    /// outside the ball map onto its silhouette, the circle facing the camera.
+    pub fn project_to_ball(&self, mouse: &Point2<S>) -> Vector3<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let radius = self.width.min(self.height) * one_half;
+        let x = (mouse.x - self.width * one_half) / radius;
+        let y = (self.height * one_half - mouse.y) / radius;
+        let radius_squared = x * x + y * y;
+        if radius_squared <= one {
+            Vector3::new(x, y, (one - radius_squared).sqrt())
+        } else {
+            let length = radius_squared.sqrt();
+
+            Vector3::new(x / length, y / length, zero)
+        }
+    }
+
+    /// Compute the rotation in camera space of a mouse drag from `from` to `to`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     ArcballCamera,
+    /// #     Point2,
+    /// #     Point3,
+    /// #     Rotation3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let camera = ArcballCamera::new(Point3::origin(), 5_f64, 800_f64, 600_f64);
+    /// // Drag from the center of the window to the right edge of the ball.
+    /// let rotation = camera.drag_rotation(&Point2::new(400_f64, 300_f64), &Point2::new(700_f64, 300_f64));
+    /// let rotation = Rotation3::from_quaternion(&rotation);
+    ///
+    /// // The front of the ball rolls to the back: twice the quarter turn
+    /// // between the endpoints of the drag.
+    /// assert!(relative_eq!(rotation.rotate_vector(&Vector3::unit_z()), -Vector3::unit_z(), epsilon = 1e-10));
+    /// ```
+    pub fn drag_rotation(&self, from: &Point2<S>, to: &Point2<S>) -> Quaternion<S> {
+        let start = self.project_to_ball(from);
+        let end = self.project_to_ball(to);
+
+        Quaternion::from_parts(start.dot(&end), start.cross(&end))
+    }
+
+    /// Turn the scene by a mouse drag from `from` to `to`.
+    ///
+    /// The camera orbits the target in the opposite direction, so that the
+    /// scene appears to follow the mouse.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     ArcballCamera,
+    /// #     Point2,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let mut camera = ArcballCamera::new(Point3::origin(), 5_f64, 600_f64, 600_f64);
+    /// let (from, to) = (Point2::new(300_f64, 300_f64), Point2::new(300_f64 + 150_f64 * f64::sqrt(2_f64), 300_f64));
+    /// // Dragging by an eighth of a turn on the ball turns the scene a quarter turn to
+    /// // the right, so the camera moves to the left of the target.
+    /// camera.drag(&from, &to);
+    ///
+    /// assert!(relative_eq!(camera.eye(), Point3::new(-5_f64, 0_f64, 0_f64), epsilon = 1e-10));
+    ///
+    /// // Dragging back undoes the rotation.
+    /// camera.drag(&to, &from);
+    ///
+    /// assert!(relative_eq!(camera.eye(), Point3::new(0_f64, 0_f64, 5_f64), epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn drag(&mut self, from: &Point2<S>, to: &Point2<S>) {
+        let rotation = self.drag_rotation(from, to);
+        self.orientation = (self.orientation * rotation.conjugate()).normalize();
+    }
+
+    /// Get the position of the camera.
+    #[inline]
+    pub fn eye(&self) -> Point3<S> {
+        let orientation = Rotation3::from_quaternion(&self.orientation);
+        let offset = orientation.rotate_vector(&Vector3::unit_z());
+
+        self.target + offset * self.distance
+    }
+
+    /// Construct the view transformation of the camera, which maps world
+    /// space into camera space.
+    #[inline]
+    pub fn view(&self) -> Isometry3<S> {
+        view_from_pose(&self.eye(), &self.orientation)
+    }
+}
+
+impl<S> fmt::Display for ArcballCamera<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "ArcballCamera [target={}, distance={}, orientation={}]",
+            self.target, self.distance, self.orientation
+        )
+    }
+}
+
@@ -0,0 +1,332 @@
+use crate::base::{
+    ScalarFloat,
+};
+use crate::angle::{
+    Radians,
+};
+use crate::matrix::{
+    Matrix3x3,
+    Matrix4x4,
+};
+use crate::point::{
+    Point3,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::quaternion::{
+    Quaternion,
+};
+use crate::euler::{
+    EulerAngles,
+};
+use crate::isometry::{
+    Isometry3,
+};
+use crate::rotation::{
+    Rotation3,
+};
+use crate::translation::{
+    Translation3,
+};
+use crate::projection::{
+    Handedness,
+};
+
+use core::fmt;
+
+
+/// A named convention for the axes of a three dimensional coordinate system.
+///
+/// A convention fixes the handedness of the coordinate system and the world
+/// space directions of the right, up, and forward axes of a model. The
+/// forward direction is the direction the front of a model faces, and the right
+/// direction is the right hand side of the model from its own point of view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CoordinateSystem {
+    /// A right-handed coordinate system with the **positive y-axis** up and
+    /// models facing the **positive z-axis**, so that their right hand side
+    /// points along the **negative x-axis**. This is the convention of glTF,
+    /// OpenGL, Maya, and three.js.
+    YUpRightHanded,
+    /// A right-handed coordinate system with the **positive z-axis** up and
+    /// models facing the **negative y-axis**, so that their right hand side
+    /// points along the **negative x-axis**. This is the convention of Blender
+    /// and of Z-up USD stages.
+    ZUpRightHanded,
+    /// A left-handed coordinate system with the **positive y-axis** up and
+    /// models facing the **positive z-axis**, so that their right hand side
+    /// points along the **positive x-axis**. This is the convention of Unity
+    /// and Direct3D.
+    YUpLeftHanded,
+    /// A left-handed coordinate system with the **positive z-axis** up and
+    /// models facing the **positive x-axis**, so that their right hand side
+    /// points along the **positive y-axis**. This is the convention of Unreal
+    /// Engine.
+    ZUpLeftHanded,
+}
+
+impl CoordinateSystem {
+    /// Get the direction of the right hand side of a model.
+    #[inline]
+    pub fn right<S>(self) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        match self {
+            CoordinateSystem::YUpRightHanded => -Vector3::unit_x(),
+            CoordinateSystem::ZUpRightHanded => -Vector3::unit_x(),
+            CoordinateSystem::YUpLeftHanded  =>  Vector3::unit_x(),
+            CoordinateSystem::ZUpLeftHanded  =>  Vector3::unit_y(),
+        }
+    }
+
+    /// Get the up direction.
+    #[inline]
+    pub fn up<S>(self) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        match self {
+            CoordinateSystem::YUpRightHanded => Vector3::unit_y(),
+            CoordinateSystem::ZUpRightHanded => Vector3::unit_z(),
+            CoordinateSystem::YUpLeftHanded  => Vector3::unit_y(),
+            CoordinateSystem::ZUpLeftHanded  => Vector3::unit_z(),
+        }
+    }
+
+    /// Get the direction the front of a model faces.
+    #[inline]
+    pub fn forward<S>(self) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        match self {
+            CoordinateSystem::YUpRightHanded =>  Vector3::unit_z(),
+            CoordinateSystem::ZUpRightHanded => -Vector3::unit_y(),
+            CoordinateSystem::YUpLeftHanded  =>  Vector3::unit_z(),
+            CoordinateSystem::ZUpLeftHanded  =>  Vector3::unit_x(),
+        }
+    }
+
+    /// Get the handedness of the coordinate system.
+    #[inline]
+    pub fn handedness(self) -> Handedness {
+        match self {
+            CoordinateSystem::YUpRightHanded => Handedness::RightHanded,
+            CoordinateSystem::ZUpRightHanded => Handedness::RightHanded,
+            CoordinateSystem::YUpLeftHanded  => Handedness::LeftHanded,
+            CoordinateSystem::ZUpLeftHanded  => Handedness::LeftHanded,
+        }
+    }
+
+    /// Get the matrix whose columns are the right, up, and forward
+    /// directions.
+    #[inline]
+    fn basis<S>(self) -> Matrix3x3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        Matrix3x3::from_columns(self.right(), self.up(), self.forward())
+    }
+}
+
+
+/// A change of basis between two coordinate system conventions.
+///
+/// A basis change maps the coordinates of geometry in one convention to the
+/// coordinates of the same geometry in another convention, so that the right,
+/// up, and forward directions of a model match. The underlying matrix is a
+/// signed permutation matrix. When the two conventions have opposite
+/// handedness, the matrix has determinant `-1`, and the conversions of
+/// rotations account for the reflection: a rotation converts to the rotation
+/// by the same angle about the converted axis in the opposite sense, so that
+/// it stays a proper rotation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BasisChange<S> {
+    /// The matrix mapping source coordinates to target coordinates.
+    matrix: Matrix3x3<S>,
+}
+
+impl<S> BasisChange<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct the change of basis from the convention `source` to the
+    /// convention `target`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BasisChange,
+    /// #     CoordinateSystem,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// // Exporting from Blender to glTF maps `(x, y, z)` to `(x, z, -y)`.
+    /// let blender_to_gltf = BasisChange::new(CoordinateSystem::ZUpRightHanded, CoordinateSystem::YUpRightHanded);
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert_eq!(blender_to_gltf.convert_point(&point), Point3::new(1_f64, 3_f64, -2_f64));
+    /// assert_eq!(blender_to_gltf.determinant(), 1_f64);
+    ///
+    /// // Importing glTF into Unity negates the x-coordinate.
+    /// let gltf_to_unity = BasisChange::new(CoordinateSystem::YUpRightHanded, CoordinateSystem::YUpLeftHanded);
+    ///
+    /// assert_eq!(gltf_to_unity.convert_point(&point), Point3::new(-1_f64, 2_f64, 3_f64));
+    /// assert_eq!(gltf_to_unity.determinant(), -1_f64);
+    /// ```
+    #[inline]
+    pub fn new(source: CoordinateSystem, target: CoordinateSystem) -> BasisChange<S> {
+        // The source basis is orthogonal, so its inverse is its transpose.
+        let matrix = target.basis() * source.basis::<S>().transpose();
+
+        BasisChange {
+            matrix: matrix,
+        }
+    }
+
+    /// Get the matrix that maps source coordinates to target coordinates.
+    #[inline]
+    pub fn matrix(&self) -> &Matrix3x3<S> {
+        &self.matrix
+    }
+
+    /// Get the determinant of the change of basis. The determinant is `1`
+    /// when the conventions have the same handedness, and `-1` otherwise.
+    #[inline]
+    pub fn determinant(&self) -> S {
+        self.matrix.determinant()
+    }
+
+    /// Determine whether the change of basis reverses handedness.
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        self.determinant() < S::zero()
+    }
+
+    /// Construct the change of basis in the opposite direction.
+    #[inline]
+    pub fn inverse(&self) -> BasisChange<S> {
+        BasisChange {
+            matrix: self.matrix.transpose(),
+        }
+    }
+
+    /// Convert a point from the source convention to the target convention.
+    #[inline]
+    pub fn convert_point(&self, point: &Point3<S>) -> Point3<S> {
+        Point3::from_vector(self.matrix * point.to_vector())
+    }
+
+    /// Convert a vector from the source convention to the target convention.
+    #[inline]
+    pub fn convert_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        self.matrix * vector
+    }
+
+    /// Convert a unit quaternion rotation from the source convention to the
+    /// target convention.
+    ///
+    /// The converted quaternion `q'` satisfies `B * R(q) * B^-1 == R(q')`,
+    /// where `B` is the change of basis matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     BasisChange,
+    /// #     CoordinateSystem,
+    /// #     Degrees,
+    /// #     Quaternion,
+    /// #     Rotation3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let change = BasisChange::new(CoordinateSystem::YUpRightHanded, CoordinateSystem::YUpLeftHanded);
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+    /// let quaternion = Quaternion::from_axis_angle(&axis, Degrees(50_f64));
+    /// let converted = change.convert_quaternion(&quaternion);
+    /// let vector = Vector3::new(-1_f64, 4_f64, 2_f64);
+    /// let expected = change.convert_vector(&Rotation3::from_quaternion(&quaternion).rotate_vector(&vector));
+    /// let result = Rotation3::from_quaternion(&converted).rotate_vector(&change.convert_vector(&vector));
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn convert_quaternion(&self, quaternion: &Quaternion<S>) -> Quaternion<S> {
+        // A reflection reverses the sense of rotation about the reflected axis.
+        let vector = self.convert_vector(&quaternion.v) * self.determinant();
+
+        Quaternion::from_parts(quaternion.s, vector)
+    }
+
+    /// Convert a rotation from the source convention to the target convention.
+    #[inline]
+    pub fn convert_rotation(&self, rotation: &Rotation3<S>) -> Rotation3<S> {
+        let quaternion = Quaternion::from(*rotation);
+
+        Rotation3::from_quaternion(&self.convert_quaternion(&quaternion))
+    }
+
+    /// Convert an isometry from the source convention to the target convention.
+    ///
+    /// The converted isometry maps converted points to the converted images
+    /// of the points.
+    #[inline]
+    pub fn convert_isometry(&self, isometry: &Isometry3<S>) -> Isometry3<S> {
+        let rotation = self.convert_rotation(isometry.rotation());
+        let translation = Translation3::from_vector(&self.convert_vector(&isometry.translation().vector));
+
+        Isometry3::from_parts(&translation, &rotation)
+    }
+
+    /// Convert a homogeneous transformation matrix from the source convention
+    /// to the target convention.
+    ///
+    /// The converted matrix is `B * M * B^-1`, where `B` is the change of
+    /// basis in homogeneous coordinates. This works for any affine or
+    /// projective transformation of the source space.
+    #[rustfmt::skip]
+    pub fn convert_matrix(&self, matrix: &Matrix4x4<S>) -> Matrix4x4<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let basis = &self.matrix;
+        let basis4x4 = Matrix4x4::new(
+            basis.c0r0, basis.c0r1, basis.c0r2, zero,
+            basis.c1r0, basis.c1r1, basis.c1r2, zero,
+            basis.c2r0, basis.c2r1, basis.c2r2, zero,
+            zero,       zero,       zero,       one
+        );
+
+        basis4x4 * matrix * basis4x4.transpose()
+    }
+
+    /// Convert a set of Euler angles from the source convention to the
+    /// target convention.
+    ///
+    /// The Euler angles of the result describe the converted rotation in the
+    /// same axis order as the input, with respect to the axes of the target
+    /// convention.
+    #[inline]
+    pub fn convert_euler_angles(&self, euler_angles: &EulerAngles<Radians<S>>) -> EulerAngles<Radians<S>> {
+        let rotation = self.matrix * euler_angles.to_matrix() * self.matrix.transpose();
+
+        EulerAngles::from_matrix(&rotation)
+    }
+}
+
+impl<S> fmt::Display for BasisChange<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "BasisChange [{}]", self.matrix)
+    }
+}
+
@@ -0,0 +1,377 @@
+use crate::base::{
+    ScalarFloat,
+};
+use crate::angle::{
+    Degrees,
+};
+use crate::matrix::{
+    Matrix4x4,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::isometry::{
+    Isometry3,
+};
+use crate::projection::{
+    PerspectiveFov3,
+};
+
+use core::fmt;
+
+
+/// The orientation conventions of the faces of a cube map.
+///
+/// Both conventions select faces and texture coordinates from a direction
+/// in the same way, but they orient the cameras that render the faces
+/// differently.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CubeMapConvention {
+    /// The OpenGL convention. The face cameras use right-handed view
+    /// transformations that pair with a right-handed 90 degree perspective
+    /// projection with the depth range `[-1, 1]`, and render into a
+    /// framebuffer whose origin lies at the bottom left corner.
+    OpenGl,
+    /// The Direct3D convention. The face cameras use left-handed view
+    /// transformations for a left-handed world space that pair with a
+    /// left-handed 90 degree perspective projection with the depth range
+    /// `[0, 1]`, and render into a framebuffer whose origin lies at the top
+    /// left corner. [`CubeMapFace::projection`] constructs the projections of
+    /// both conventions.
+    Direct3D,
+}
+
+
+/// A face of a cube map.
+///
+/// The faces are numbered in the order `+x`, `-x`, `+y`, `-y`, `+z`, `-z`,
+/// which is the layer order of cube map textures in both OpenGL and Direct3D.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CubeMapFace {
+    /// The face in the direction of the **positive x-axis**.
+    PositiveX,
+    /// The face in the direction of the **negative x-axis**.
+    NegativeX,
+    /// The face in the direction of the **positive y-axis**.
+    PositiveY,
+    /// The face in the direction of the **negative y-axis**.
+    NegativeY,
+    /// The face in the direction of the **positive z-axis**.
+    PositiveZ,
+    /// The face in the direction of the **negative z-axis**.
+    NegativeZ,
+}
+
+impl CubeMapFace {
+    /// The six faces of a cube map in layer order.
+    pub const ALL: [CubeMapFace; 6] = [
+        CubeMapFace::PositiveX,
+        CubeMapFace::NegativeX,
+        CubeMapFace::PositiveY,
+        CubeMapFace::NegativeY,
+        CubeMapFace::PositiveZ,
+        CubeMapFace::NegativeZ,
+    ];
+
+    /// Get the layer index of the face.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CubeMapFace,
+    /// # };
+    /// #
+    /// for (index, face) in CubeMapFace::ALL.iter().enumerate() {
+    ///     assert_eq!(face.index(), index);
+    ///     assert_eq!(CubeMapFace::from_index(index), Some(*face));
+    /// }
+    /// assert_eq!(CubeMapFace::from_index(6), None);
+    /// ```
+    #[inline]
+    pub fn index(self) -> usize {
+        match self {
+            CubeMapFace::PositiveX => 0,
+            CubeMapFace::NegativeX => 1,
+            CubeMapFace::PositiveY => 2,
+            CubeMapFace::NegativeY => 3,
+            CubeMapFace::PositiveZ => 4,
+            CubeMapFace::NegativeZ => 5,
+        }
+    }
+
+    /// Get the face with a given layer index. The function returns `None`
+    /// if the index is not less than six.
+    #[inline]
+    pub fn from_index(index: usize) -> Option<CubeMapFace> {
+        CubeMapFace::ALL.get(index).copied()
+    }
+
+    /// Get the direction from the center of the cube map toward the center
+    /// of the face.
+    #[inline]
+    pub fn forward<S>(self) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        match self {
+            CubeMapFace::PositiveX =>  Vector3::unit_x(),
+            CubeMapFace::NegativeX => -Vector3::unit_x(),
+            CubeMapFace::PositiveY =>  Vector3::unit_y(),
+            CubeMapFace::NegativeY => -Vector3::unit_y(),
+            CubeMapFace::PositiveZ =>  Vector3::unit_z(),
+            CubeMapFace::NegativeZ => -Vector3::unit_z(),
+        }
+    }
+
+    /// Get the up direction of the camera that renders the face.
+    #[inline]
+    pub fn up<S>(self, convention: CubeMapConvention) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        match convention {
+            CubeMapConvention::OpenGl => match self {
+                CubeMapFace::PositiveY =>  Vector3::unit_z(),
+                CubeMapFace::NegativeY => -Vector3::unit_z(),
+                _ => -Vector3::unit_y(),
+            },
+            CubeMapConvention::Direct3D => match self {
+                CubeMapFace::PositiveY => -Vector3::unit_z(),
+                CubeMapFace::NegativeY =>  Vector3::unit_z(),
+                _ => Vector3::unit_y(),
+            },
+        }
+    }
+
+    /// Construct the view transformation of the camera at `center` that
+    /// renders the face.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CubeMapConvention,
+    /// #     CubeMapFace,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let center = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let view = CubeMapFace::NegativeZ.view(&center, CubeMapConvention::OpenGl);
+    /// let point = center + Vector3::new(0_f64, 0_f64, -5_f64);
+    ///
+    /// // The center of the face lies straight ahead of the camera.
+    /// assert!(relative_eq!(view.transform_point(&point), Point3::new(0_f64, 0_f64, -5_f64), epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn view<S>(self, center: &Point3<S>, convention: CubeMapConvention) -> Isometry3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        let target = center + self.forward::<S>();
+        let up = self.up(convention);
+        match convention {
+            CubeMapConvention::OpenGl => Isometry3::look_at_rh(center, &target, &up),
+            CubeMapConvention::Direct3D => Isometry3::look_at_lh(center, &target, &up),
+        }
+    }
+
+    /// Get the matrix of the view transformation of the camera at `center`
+    /// that renders the face.
+    #[inline]
+    pub fn view_matrix<S>(self, center: &Point3<S>, convention: CubeMapConvention) -> Matrix4x4<S> 
+    where 
+        S: ScalarFloat 
+    {
+        self.view(center, convention).to_affine_matrix()
+    }
+
+    /// Construct the view transformations of the cameras at `center` that
+    /// render the six faces, in layer order.
+    #[inline]
+    pub fn views<S>(center: &Point3<S>, convention: CubeMapConvention) -> [Isometry3<S>; 6] 
+    where 
+        S: ScalarFloat 
+    {
+        CubeMapFace::ALL.map(|face| face.view(center, convention))
+    }
+
+    /// Construct the matrix of the 90 degree perspective projection with a
+    /// square aspect ratio that renders a face with the view transformations
+    /// of the convention `convention`.
+    ///
+    /// For the OpenGL convention, this is the matrix of the right-handed
+    /// [`PerspectiveFov3`] projection with the depth range `[-1, 1]`. For the
+    /// Direct3D convention, this is the matrix of the left-handed projection
+    /// with the depth range `[0, 1]`, which looks down the **positive z-axis**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CubeMapConvention,
+    /// #     CubeMapFace,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let center = Point3::new(0_f64, 1_f64, 0_f64);
+    /// let convention = CubeMapConvention::OpenGl;
+    /// let projection = CubeMapFace::projection(0.1_f64, 100_f64, convention);
+    /// let view = CubeMapFace::PositiveX.view(&center, convention);
+    /// // The corner of the face in the directions `+y` and `+z` projects to
+    /// // the corner of the image at texture coordinates `(0, 0)`.
+    /// let point = center + Vector3::new(1_f64, 1_f64, 1_f64);
+    /// let clip = projection * view.transform_point(&point).to_homogeneous();
+    /// let projected = Point3::from_homogeneous(clip).unwrap();
+    ///
+    /// assert!(relative_eq!(projected.x, -1_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(projected.y, -1_f64, epsilon = 1e-10));
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn projection<S>(near: S, far: S, convention: CubeMapConvention) -> Matrix4x4<S> 
+    where 
+        S: ScalarFloat 
+    {
+        match convention {
+            CubeMapConvention::OpenGl => {
+                let ninety: S = num_traits::cast(90).unwrap();
+
+                *PerspectiveFov3::new(Degrees(ninety), S::one(), near, far).matrix()
+            }
+            CubeMapConvention::Direct3D => {
+                let zero = S::zero();
+                let one = S::one();
+                let sz = far / (far - near);
+                let pz = -(far * near) / (far - near);
+
+                Matrix4x4::new(
+                    one,   zero,  zero,  zero,
+                    zero,  one,   zero,  zero,
+                    zero,  zero,  sz,    one,
+                    zero,  zero,  pz,    zero
+                )
+            }
+        }
+    }
+
+    /// Find the face that a direction from the center of the cube map points
+    /// at, together with the texture coordinates of the point on the face.
+    ///
+    /// The texture coordinates range over `[0, 1] x [0, 1]`, with `(0, 0)` at
+    /// the first texel of the face in memory. The face is the one along the
+    /// component of the direction with the largest magnitude, with ties
+    /// broken in the order `x`, `y`, `z`. The function returns `None` for the
+    /// zero vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     CubeMapFace,
+    /// #     Point2,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let direction = Vector3::new(0.5_f64, -0.25_f64, -2_f64);
+    /// let (face, uv) = CubeMapFace::from_direction(&direction).unwrap();
+    ///
+    /// assert_eq!(face, CubeMapFace::NegativeZ);
+    /// assert!(relative_eq!(uv, Point2::new(0.375_f64, 0.5625_f64), epsilon = 1e-10));
+    /// assert!(relative_eq!(face.to_direction(&uv), direction / 2_f64, epsilon = 1e-10));
+    /// ```
+    pub fn from_direction<S>(direction: &Vector3<S>) -> Option<(CubeMapFace, Point2<S>)> 
+    where 
+        S: ScalarFloat 
+    {
+        let abs_x = direction.x.abs();
+        let abs_y = direction.y.abs();
+        let abs_z = direction.z.abs();
+        let zero = S::zero();
+        // Select the face along the major axis, and the coordinates `(sc, tc)`
+        // across the face, following the cube map tables of OpenGL and Direct3D.
+        let (face, sc, tc, major) = if abs_x >= abs_y && abs_x >= abs_z {
+            if direction.x >= zero {
+                (CubeMapFace::PositiveX, -direction.z, -direction.y, abs_x)
+            } else {
+                (CubeMapFace::NegativeX, direction.z, -direction.y, abs_x)
+            }
+        } else if abs_y >= abs_z {
+            if direction.y >= zero {
+                (CubeMapFace::PositiveY, direction.x, direction.z, abs_y)
+            } else {
+                (CubeMapFace::NegativeY, direction.x, -direction.z, abs_y)
+            }
+        } else if direction.z >= zero {
+            (CubeMapFace::PositiveZ, direction.x, -direction.y, abs_z)
+        } else {
+            (CubeMapFace::NegativeZ, -direction.x, -direction.y, abs_z)
+        };
+
+        if major.is_zero() {
+            return None;
+        }
+
+        let one = S::one();
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let u = (sc / major + one) * one_half;
+        let v = (tc / major + one) * one_half;
+
+        Some((face, Point2::new(u, v)))
+    }
+
+    /// Compute the direction from the center of the cube map toward the point
+    /// on the face with the texture coordinates `uv`. This is the inverse of
+    /// [`CubeMapFace::from_direction`].
+    ///
+    /// The direction is not normalized. Its component along the major axis of
+    /// the face has unit magnitude.
+    pub fn to_direction<S>(self, uv: &Point2<S>) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        let one = S::one();
+        let two = one + one;
+        let sc = two * uv.x - one;
+        let tc = two * uv.y - one;
+        match self {
+            CubeMapFace::PositiveX => Vector3::new( one, -tc, -sc),
+            CubeMapFace::NegativeX => Vector3::new(-one, -tc,  sc),
+            CubeMapFace::PositiveY => Vector3::new( sc,  one,  tc),
+            CubeMapFace::NegativeY => Vector3::new( sc, -one, -tc),
+            CubeMapFace::PositiveZ => Vector3::new( sc, -tc,  one),
+            CubeMapFace::NegativeZ => Vector3::new(-sc, -tc, -one),
+        }
+    }
+}
+
+impl fmt::Display for CubeMapFace {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            CubeMapFace::PositiveX => "+x",
+            CubeMapFace::NegativeX => "-x",
+            CubeMapFace::PositiveY => "+y",
+            CubeMapFace::NegativeY => "-y",
+            CubeMapFace::PositiveZ => "+z",
+            CubeMapFace::NegativeZ => "-z",
+        };
+
+        write!(formatter, "CubeMapFace [{}]", name)
+    }
+}
+
@@ -0,0 +1,719 @@
+use crate::base::{
+    Magnitude,
+    ScalarFloat,
+};
+use crate::angle::{
+    Angle,
+    Radians,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::vector::{
+    Vector2,
+    Vector3,
+};
+
+use core::fmt;
+
+
+/// A point in two dimensions in polar coordinates.
+///
+/// The polar coordinates of a point are its distance `radius` from the origin
+/// and the angle `angle` from the **positive x-axis** toward the
+/// **positive y-axis**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Polar2<S> {
+    radius: S,
+    angle: Radians<S>,
+}
+
+impl<S> Polar2<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a point in polar coordinates.
+    #[inline]
+    pub fn new<A: Into<Radians<S>>>(radius: S, angle: A) -> Polar2<S> {
+        Polar2 {
+            radius: radius,
+            angle: angle.into(),
+        }
+    }
+
+    /// Get the distance of the point from the origin.
+    #[inline]
+    pub fn radius(&self) -> S {
+        self.radius
+    }
+
+    /// Get the angle of the point from the **positive x-axis**.
+    #[inline]
+    pub fn angle(&self) -> Radians<S> {
+        self.angle
+    }
+
+    /// Compute the polar coordinates of a point in Cartesian coordinates.
+    ///
+    /// The angle of the result lies in the range `(-pi, pi]`. The origin has
+    /// angle zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Point2,
+    /// #     Polar2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let point = Point2::new(0_f64, -2_f64);
+    /// let polar = Polar2::from_point(&point);
+    ///
+    /// assert!(relative_eq!(polar, Polar2::new(2_f64, Degrees(-90_f64)), epsilon = 1e-10));
+    /// assert!(relative_eq!(polar.to_point(), point, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_point(point: &Point2<S>) -> Polar2<S> {
+        Polar2::from_vector(&point.to_vector())
+    }
+
+    /// Compute the polar coordinates of the tip of a vector.
+    #[inline]
+    pub fn from_vector(vector: &Vector2<S>) -> Polar2<S> {
+        let radius = vector.magnitude();
+        let angle = Radians::atan2(vector.y, vector.x);
+
+        Polar2::new(radius, angle)
+    }
+
+    /// Compute the Cartesian coordinates of the point.
+    #[inline]
+    pub fn to_point(&self) -> Point2<S> {
+        Point2::from_vector(self.to_vector())
+    }
+
+    /// Compute the position vector of the point.
+    #[inline]
+    pub fn to_vector(&self) -> Vector2<S> {
+        let (sin_angle, cos_angle) = self.angle.sin_cos();
+
+        Vector2::new(self.radius * cos_angle, self.radius * sin_angle)
+    }
+
+    /// Linearly interpolate between two points in polar coordinates.
+    ///
+    /// The radius is interpolated linearly, and the angle is interpolated
+    /// along the shorter arc between the two angles.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Polar2,
+    /// #     Radians,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let polar1 = Polar2::new(1_f64, Degrees(170_f64));
+    /// let polar2 = Polar2::new(3_f64, Degrees(-170_f64));
+    /// let result = polar1.lerp(&polar2, 0.5_f64);
+    /// let expected_angle: Radians<f64> = Degrees(180_f64).into();
+    ///
+    /// assert!(relative_eq!(result.radius(), 2_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(result.angle(), expected_angle, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Polar2<S>, amount: S) -> Polar2<S> {
+        let radius = self.radius + (other.radius - self.radius) * amount;
+        let angle = self.angle.lerp_shortest(other.angle, amount);
+
+        Polar2::new(radius, angle)
+    }
+}
+
+impl<S> From<Point2<S>> for Polar2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(point: Point2<S>) -> Polar2<S> {
+        Polar2::from_point(&point)
+    }
+}
+
+impl<S> From<Polar2<S>> for Point2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(polar: Polar2<S>) -> Point2<S> {
+        polar.to_point()
+    }
+}
+
+impl<S> fmt::Display for Polar2<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Polar2 [radius={}, angle={}]", self.radius, self.angle)
+    }
+}
+
+impl<S> approx::AbsDiffEq for Polar2<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.radius, &other.radius, epsilon)
+            && Radians::abs_diff_eq(&self.angle, &other.angle, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Polar2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+            && Radians::relative_eq(&self.angle, &other.angle, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Polar2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+            && Radians::ulps_eq(&self.angle, &other.angle, epsilon, max_ulps)
+    }
+}
+
+
+/// A point in three dimensions in spherical coordinates.
+///
+/// The spherical coordinates of a point follow the ISO 80000-2 convention used
+/// in physics. They are the distance `radius` from the origin, the polar
+/// angle `polar` (also known as the inclination, or colatitude) from the
+/// **positive z-axis**, and the azimuthal angle `azimuth` in the **xy-plane**
+/// from the **positive x-axis** toward the **positive y-axis**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spherical3<S> {
+    radius: S,
+    polar: Radians<S>,
+    azimuth: Radians<S>,
+}
+
+impl<S> Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a point in spherical coordinates.
+    #[inline]
+    pub fn new<A: Into<Radians<S>>>(radius: S, polar: A, azimuth: A) -> Spherical3<S> {
+        Spherical3 {
+            radius: radius,
+            polar: polar.into(),
+            azimuth: azimuth.into(),
+        }
+    }
+
+    /// Get the distance of the point from the origin.
+    #[inline]
+    pub fn radius(&self) -> S {
+        self.radius
+    }
+
+    /// Get the polar angle of the point from the **positive z-axis**.
+    #[inline]
+    pub fn polar(&self) -> Radians<S> {
+        self.polar
+    }
+
+    /// Get the azimuthal angle of the point from the **positive x-axis**.
+    #[inline]
+    pub fn azimuth(&self) -> Radians<S> {
+        self.azimuth
+    }
+
+    /// Compute the spherical coordinates of a point in Cartesian coordinates.
+    ///
+    /// The polar angle of the result lies in the range `[0, pi]`, and the
+    /// azimuthal angle lies in the range `(-pi, pi]`. Points on the
+    /// **z-axis** have azimuthal angle zero, and the origin has polar angle
+    /// zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Point3,
+    /// #     Spherical3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let point = Point3::new(0_f64, 2_f64, 2_f64);
+    /// let spherical = Spherical3::from_point(&point);
+    /// let expected = Spherical3::new(2_f64 * f64::sqrt(2_f64), Degrees(45_f64), Degrees(90_f64));
+    ///
+    /// assert!(relative_eq!(spherical, expected, epsilon = 1e-10));
+    /// assert!(relative_eq!(spherical.to_point(), point, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_point(point: &Point3<S>) -> Spherical3<S> {
+        Spherical3::from_vector(&point.to_vector())
+    }
+
+    /// Compute the spherical coordinates of the tip of a vector.
+    #[inline]
+    pub fn from_vector(vector: &Vector3<S>) -> Spherical3<S> {
+        let radius = vector.magnitude();
+        let radius_xy = vector.x.hypot(vector.y);
+        let polar = Radians::atan2(radius_xy, vector.z);
+        let azimuth = Radians::atan2(vector.y, vector.x);
+
+        Spherical3::new(radius, polar, azimuth)
+    }
+
+    /// Compute the Cartesian coordinates of the point.
+    #[inline]
+    pub fn to_point(&self) -> Point3<S> {
+        Point3::from_vector(self.to_vector())
+    }
+
+    /// Compute the position vector of the point.
+    #[inline]
+    pub fn to_vector(&self) -> Vector3<S> {
+        self.direction() * self.radius
+    }
+
+    /// Compute the unit vector pointing from the origin in the direction of
+    /// the point.
+    #[inline]
+    pub fn direction(&self) -> Vector3<S> {
+        let (sin_polar, cos_polar) = self.polar.sin_cos();
+        let (sin_azimuth, cos_azimuth) = self.azimuth.sin_cos();
+
+        Vector3::new(sin_polar * cos_azimuth, sin_polar * sin_azimuth, cos_polar)
+    }
+
+    /// Interpolate between two points in spherical coordinates.
+    ///
+    /// The radius is interpolated linearly, and the direction is interpolated
+    /// at constant angular speed along the shorter great circle arc between the
+    /// two directions. Unlike interpolating the angles separately, this
+    /// takes the short way around over the poles. When the two directions are
+    /// opposite, the interpolation follows one of the many shortest arcs.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Spherical3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// // Two points near the north pole on opposite sides of the z-axis.
+    /// let spherical1 = Spherical3::new(1_f64, Degrees(10_f64), Degrees(0_f64));
+    /// let spherical2 = Spherical3::new(3_f64, Degrees(10_f64), Degrees(180_f64));
+    /// let result = spherical1.lerp(&spherical2, 0.5_f64);
+    ///
+    /// // The shortest path passes straight over the pole.
+    /// assert!(relative_eq!(result.to_vector(), Vector3::new(0_f64, 0_f64, 2_f64), epsilon = 1e-10));
+    /// ```
+    pub fn lerp(&self, other: &Spherical3<S>, amount: S) -> Spherical3<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let radius = self.radius + (other.radius - self.radius) * amount;
+        let start = self.direction();
+        let end = other.direction();
+        let cos_angle = start.dot(&end);
+        let normal = start.cross(&end);
+        let sin_angle = normal.magnitude();
+        let axis = if sin_angle > S::epsilon() {
+            normal / sin_angle
+        } else if cos_angle > zero {
+            // The directions coincide.
+            return Spherical3::new(radius, self.polar, self.azimuth);
+        } else {
+            // The directions are opposite, so any axis perpendicular to the
+            // starting direction yields a shortest arc.
+            let other_axis = if start.x.abs() < one / (one + one) { Vector3::unit_x() } else { Vector3::unit_y() };
+
+            start.cross(&other_axis).normalize()
+        };
+        let angle = Radians::atan2(sin_angle, cos_angle) * amount;
+        let (sin_step, cos_step) = angle.sin_cos();
+        let direction = start * cos_step + axis.cross(&start) * sin_step;
+        let mut result = Spherical3::from_vector(&direction);
+        result.radius = radius;
+
+        result
+    }
+}
+
+impl<S> From<Point3<S>> for Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(point: Point3<S>) -> Spherical3<S> {
+        Spherical3::from_point(&point)
+    }
+}
+
+impl<S> From<Spherical3<S>> for Point3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(spherical: Spherical3<S>) -> Point3<S> {
+        spherical.to_point()
+    }
+}
+
+impl<S> From<Vector3<S>> for Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(vector: Vector3<S>) -> Spherical3<S> {
+        Spherical3::from_vector(&vector)
+    }
+}
+
+impl<S> From<Spherical3<S>> for Vector3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(spherical: Spherical3<S>) -> Vector3<S> {
+        spherical.to_vector()
+    }
+}
+
+impl<S> fmt::Display for Spherical3<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Spherical3 [radius={}, polar={}, azimuth={}]",
+            self.radius, self.polar, self.azimuth
+        )
+    }
+}
+
+impl<S> approx::AbsDiffEq for Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.radius, &other.radius, epsilon)
+            && Radians::abs_diff_eq(&self.polar, &other.polar, epsilon)
+            && Radians::abs_diff_eq(&self.azimuth, &other.azimuth, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+            && Radians::relative_eq(&self.polar, &other.polar, epsilon, max_relative)
+            && Radians::relative_eq(&self.azimuth, &other.azimuth, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+            && Radians::ulps_eq(&self.polar, &other.polar, epsilon, max_ulps)
+            && Radians::ulps_eq(&self.azimuth, &other.azimuth, epsilon, max_ulps)
+    }
+}
+
+
+/// A point in three dimensions in cylindrical coordinates.
+///
+/// The cylindrical coordinates of a point are the distance `radius` from the
+/// **z-axis**, the azimuthal angle `azimuth` in the **xy-plane** from the
+/// **positive x-axis** toward the **positive y-axis**, and the height `z`
+/// above the **xy-plane**.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cylindrical3<S> {
+    radius: S,
+    azimuth: Radians<S>,
+    z: S,
+}
+
+impl<S> Cylindrical3<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a point in cylindrical coordinates.
+    #[inline]
+    pub fn new<A: Into<Radians<S>>>(radius: S, azimuth: A, z: S) -> Cylindrical3<S> {
+        Cylindrical3 {
+            radius: radius,
+            azimuth: azimuth.into(),
+            z: z,
+        }
+    }
+
+    /// Get the distance of the point from the **z-axis**.
+    #[inline]
+    pub fn radius(&self) -> S {
+        self.radius
+    }
+
+    /// Get the azimuthal angle of the point from the **positive x-axis**.
+    #[inline]
+    pub fn azimuth(&self) -> Radians<S> {
+        self.azimuth
+    }
+
+    /// Get the height of the point above the **xy-plane**.
+    #[inline]
+    pub fn z(&self) -> S {
+        self.z
+    }
+
+    /// Compute the cylindrical coordinates of a point in Cartesian
+    /// coordinates.
+    ///
+    /// The azimuthal angle of the result lies in the range `(-pi, pi]`. Points
+    /// on the **z-axis** have azimuthal angle zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Cylindrical3,
+    /// #     Degrees,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let point = Point3::new(-3_f64, 0_f64, 5_f64);
+    /// let cylindrical = Cylindrical3::from_point(&point);
+    ///
+    /// assert!(relative_eq!(cylindrical, Cylindrical3::new(3_f64, Degrees(180_f64), 5_f64), epsilon = 1e-10));
+    /// assert!(relative_eq!(cylindrical.to_point(), point, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_point(point: &Point3<S>) -> Cylindrical3<S> {
+        Cylindrical3::from_vector(&point.to_vector())
+    }
+
+    /// Compute the cylindrical coordinates of the tip of a vector.
+    #[inline]
+    pub fn from_vector(vector: &Vector3<S>) -> Cylindrical3<S> {
+        let radius = vector.x.hypot(vector.y);
+        let azimuth = Radians::atan2(vector.y, vector.x);
+
+        Cylindrical3::new(radius, azimuth, vector.z)
+    }
+
+    /// Compute the Cartesian coordinates of the point.
+    #[inline]
+    pub fn to_point(&self) -> Point3<S> {
+        Point3::from_vector(self.to_vector())
+    }
+
+    /// Compute the position vector of the point.
+    #[inline]
+    pub fn to_vector(&self) -> Vector3<S> {
+        let (sin_azimuth, cos_azimuth) = self.azimuth.sin_cos();
+
+        Vector3::new(self.radius * cos_azimuth, self.radius * sin_azimuth, self.z)
+    }
+
+    /// Linearly interpolate between two points in cylindrical coordinates.
+    ///
+    /// The radius and the height are interpolated linearly, and the azimuthal
+    /// angle is interpolated along the shorter arc between the two angles.
+    #[inline]
+    pub fn lerp(&self, other: &Cylindrical3<S>, amount: S) -> Cylindrical3<S> {
+        let radius = self.radius + (other.radius - self.radius) * amount;
+        let azimuth = self.azimuth.lerp_shortest(other.azimuth, amount);
+        let z = self.z + (other.z - self.z) * amount;
+
+        Cylindrical3::new(radius, azimuth, z)
+    }
+}
+
+impl<S> From<Point3<S>> for Cylindrical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(point: Point3<S>) -> Cylindrical3<S> {
+        Cylindrical3::from_point(&point)
+    }
+}
+
+impl<S> From<Cylindrical3<S>> for Point3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(cylindrical: Cylindrical3<S>) -> Point3<S> {
+        cylindrical.to_point()
+    }
+}
+
+impl<S> From<Spherical3<S>> for Cylindrical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(spherical: Spherical3<S>) -> Cylindrical3<S> {
+        let (sin_polar, cos_polar) = spherical.polar.sin_cos();
+
+        Cylindrical3::new(spherical.radius * sin_polar, spherical.azimuth, spherical.radius * cos_polar)
+    }
+}
+
+impl<S> From<Cylindrical3<S>> for Spherical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn from(cylindrical: Cylindrical3<S>) -> Spherical3<S> {
+        let radius = cylindrical.radius.hypot(cylindrical.z);
+        let polar = Radians::atan2(cylindrical.radius, cylindrical.z);
+
+        Spherical3::new(radius, polar, cylindrical.azimuth)
+    }
+}
+
+impl<S> fmt::Display for Cylindrical3<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Cylindrical3 [radius={}, azimuth={}, z={}]",
+            self.radius, self.azimuth, self.z
+        )
+    }
+}
+
+impl<S> approx::AbsDiffEq for Cylindrical3<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.radius, &other.radius, epsilon)
+            && Radians::abs_diff_eq(&self.azimuth, &other.azimuth, epsilon)
+            && S::abs_diff_eq(&self.z, &other.z, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Cylindrical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+            && Radians::relative_eq(&self.azimuth, &other.azimuth, epsilon, max_relative)
+            && S::relative_eq(&self.z, &other.z, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Cylindrical3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+            && Radians::ulps_eq(&self.azimuth, &other.azimuth, epsilon, max_ulps)
+            && S::ulps_eq(&self.z, &other.z, epsilon, max_ulps)
+    }
+}
+
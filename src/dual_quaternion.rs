@@ -0,0 +1,1051 @@
+use crate::base::{
+    Magnitude,
+    ScalarFloat,
+};
+use crate::isometry::{
+    Isometry3,
+};
+use crate::matrix::{
+    Matrix4x4,
+};
+use crate::point::{
+    Point3,
+};
+use crate::quaternion::{
+    Quaternion,
+};
+use crate::rotation::{
+    Rotation3,
+};
+use crate::translation::{
+    Translation3,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::normal::{
+    Normal3,
+};
+
+use core::fmt;
+use core::ops;
+
+
+/// A dual quaternion is a quaternion whose components are dual numbers.
+///
+/// A dual quaternion `q` can be written as
+/// ```text
+/// q := q_real + epsilon * q_dual
+/// ```
+/// where `q_real` and `q_dual` are quaternions, and `epsilon` is the dual
+/// unit satisfying `epsilon * epsilon == 0`. Unit dual quaternions represent
+/// rigid body transformations in three dimensions in the same way that unit
+/// quaternions represent rotations. The real part of a unit dual quaternion
+/// is the unit quaternion of the rotation, and the dual part encodes the
+/// translation. In particular, the rigid body transformation that rotates by
+/// the unit quaternion `r` followed by a translation `t` is represented by
+/// ```text
+/// q := r + epsilon * (1 / 2) * t * r
+/// ```
+/// where `t` is the translation written as a pure quaternion.
+///
+/// Interpolating and blending unit dual quaternions follows screw motions,
+/// which makes them well suited for skinning, since blending dual quaternions
+/// does not produce the volume loss artifacts of blending transformation
+/// matrices.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualQuaternion<S> {
+    /// The real part of a dual quaternion.
+    pub real: Quaternion<S>,
+    /// The dual part of a dual quaternion.
+    pub dual: Quaternion<S>,
+}
+
+impl<S> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real part and its dual part.
+    #[inline]
+    pub const fn from_parts(real: Quaternion<S>, dual: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion {
+            real: real,
+            dual: dual,
+        }
+    }
+}
+
+impl<S> DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    /// Construct the identity dual quaternion.
+    ///
+    /// The identity dual quaternion represents the identity rigid body
+    /// transformation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Point3,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::identity();
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    ///
+    /// assert_eq!(dual_quaternion.transform_point(&point), point);
+    /// ```
+    #[inline]
+    pub fn identity() -> DualQuaternion<S> {
+        DualQuaternion::from_parts(Quaternion::identity(), Quaternion::zero())
+    }
+
+    /// Construct the zero dual quaternion.
+    #[inline]
+    pub fn zero() -> DualQuaternion<S> {
+        DualQuaternion::from_parts(Quaternion::zero(), Quaternion::zero())
+    }
+
+    /// Construct a unit dual quaternion from a rotation followed by
+    /// a translation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Point3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation = Rotation3::from_angle_z(Degrees(90_f64));
+    /// let translation = Translation3::new(1_f64, 2_f64, 3_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(
+    ///     &rotation, &translation
+    /// );
+    /// let point = Point3::new(1_f64, 0_f64, 0_f64);
+    /// let expected = Point3::new(1_f64, 3_f64, 3_f64);
+    /// let result = dual_quaternion.transform_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_rotation_translation(
+        rotation: &Rotation3<S>, translation: &Translation3<S>) -> DualQuaternion<S> {
+
+        let real = Quaternion::from(*rotation);
+
+        Self::from_real_translation(real, translation.vector)
+    }
+
+    /// Construct a unit dual quaternion from a translation.
+    #[inline]
+    pub fn from_translation(translation: &Translation3<S>) -> DualQuaternion<S> {
+        Self::from_real_translation(Quaternion::identity(), translation.vector)
+    }
+
+    /// Construct a unit dual quaternion from a rotation.
+    #[inline]
+    pub fn from_rotation(rotation: &Rotation3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_parts(Quaternion::from(*rotation), Quaternion::zero())
+    }
+
+    /// Construct a unit dual quaternion from an isometry.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let distance = Vector3::new(4_f64, 5_f64, 6_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(60_f64), &distance);
+    /// let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = isometry.transform_point(&point);
+    /// let result = dual_quaternion.transform_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_isometry(isometry: &Isometry3<S>) -> DualQuaternion<S> {
+        Self::from_rotation_translation(&isometry.rotation, &isometry.translation)
+    }
+
+    #[inline]
+    fn from_real_translation(real: Quaternion<S>, translation: Vector3<S>) -> DualQuaternion<S> {
+        let one_half = num_traits::cast(0.5_f64).unwrap();
+        let dual = Quaternion::from_pure(translation * one_half) * real;
+
+        DualQuaternion::from_parts(real, dual)
+    }
+
+    /// Get the rotation part of a unit dual quaternion.
+    #[inline]
+    pub fn rotation(&self) -> Rotation3<S> {
+        Rotation3::from_quaternion(&self.real)
+    }
+
+    /// Get the translation part of a unit dual quaternion.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation = Rotation3::from_angle_x(Degrees(30_f64));
+    /// let translation = Translation3::new(1_f64, 2_f64, 3_f64);
+    /// let dual_quaternion = DualQuaternion::from_rotation_translation(
+    ///     &rotation, &translation
+    /// );
+    /// let result = dual_quaternion.translation();
+    ///
+    /// assert!(relative_eq!(result, translation, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn translation(&self) -> Translation3<S> {
+        let two = S::one() + S::one();
+        let translation = (self.dual * self.real.conjugate()) * two;
+
+        Translation3::from_vector(&translation.v)
+    }
+
+    /// Convert a unit dual quaternion into the equivalent isometry.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_y());
+    /// let distance = Vector3::new(-1_f64, 3_f64, 2_f64);
+    /// let expected = Isometry3::from_axis_angle_translation(&axis, Degrees(135_f64), &distance);
+    /// let dual_quaternion = DualQuaternion::from_isometry(&expected);
+    /// let result = dual_quaternion.to_isometry();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn to_isometry(&self) -> Isometry3<S> {
+        Isometry3::from_parts(&self.translation(), &self.rotation())
+    }
+
+    /// Convert a unit dual quaternion to an equivalent affine transformation
+    /// matrix.
+    #[inline]
+    pub fn to_affine_matrix(&self) -> Matrix4x4<S> {
+        self.to_isometry().to_affine_matrix()
+    }
+
+    /// Compute the quaternion conjugate of a dual quaternion.
+    ///
+    /// Given a dual quaternion `q := q_real + epsilon * q_dual`, the
+    /// quaternion conjugate of `q` is the dual quaternion
+    /// ```text
+    /// q* := q_real* + epsilon * q_dual*
+    /// ```
+    /// For unit dual quaternions, the conjugate is the inverse.
+    #[inline]
+    pub fn conjugate(&self) -> DualQuaternion<S> {
+        DualQuaternion::from_parts(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Compute the inverse of a dual quaternion.
+    ///
+    /// A dual quaternion is invertible if and only if its real part is
+    /// invertible. If the dual quaternion is not invertible, the function
+    /// returns `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let distance = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(45_f64), &distance);
+    /// let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+    /// let dual_quaternion_inv = dual_quaternion.inverse().unwrap();
+    /// let expected = DualQuaternion::identity();
+    /// let result = dual_quaternion * dual_quaternion_inv;
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<DualQuaternion<S>> {
+        self.real.inverse().map(|real_inv| {
+            let dual_inv = -(real_inv * self.dual * real_inv);
+
+            DualQuaternion::from_parts(real_inv, dual_inv)
+        })
+    }
+
+    /// Determine whether a dual quaternion is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.real.is_invertible()
+    }
+
+    /// Normalize a dual quaternion into a unit dual quaternion.
+    ///
+    /// A unit dual quaternion has a unit real part, and a dual part
+    /// orthogonal to the real part. The function scales the dual quaternion
+    /// so that its real part is a unit quaternion, and then removes the
+    /// component of the dual part parallel to the real part. If the real part
+    /// is too small to normalize, the function returns `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Quaternion,
+    /// #     Magnitude,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let dual_quaternion = DualQuaternion::from_parts(
+    ///     Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64),
+    ///     Quaternion::new(5_f64, 6_f64, 7_f64, 8_f64),
+    /// );
+    /// let result = dual_quaternion.try_normalize().unwrap();
+    ///
+    /// assert!(relative_eq!(result.real.magnitude(), 1_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(result.real.dot(&result.dual), 0_f64, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn try_normalize(&self) -> Option<DualQuaternion<S>> {
+        let magnitude_real = self.real.magnitude();
+        if magnitude_real <= S::default_epsilon() {
+            None
+        } else {
+            let real = self.real / magnitude_real;
+            let dual = self.dual / magnitude_real;
+            let dual_orthogonal = dual - real * real.dot(&dual);
+
+            Some(DualQuaternion::from_parts(real, dual_orthogonal))
+        }
+    }
+
+    /// Compute the dot product of the real parts of two dual quaternions.
+    #[inline]
+    pub fn dot_real(&self, other: &DualQuaternion<S>) -> S {
+        self.real.dot(&other.real)
+    }
+
+    /// Transform a point with a unit dual quaternion.
+    ///
+    /// The dual quaternion applies the rotation followed by the translation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_x());
+    /// let distance = Vector3::new(1_f64, 1_f64, 1_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(90_f64), &distance);
+    /// let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+    /// let point = Point3::new(0_f64, 1_f64, 0_f64);
+    /// let expected = Point3::new(1_f64, 1_f64, 2_f64);
+    /// let result = dual_quaternion.transform_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        let rotated = self.transform_vector(&point.to_vector());
+        let translation = self.translation();
+
+        Point3::from_vector(rotated + translation.vector)
+    }
+
+    /// Transform a vector with a unit dual quaternion.
+    ///
+    /// The dual quaternion applies only the rotation to vectors.
+    #[inline]
+    pub fn transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        let rotated = self.real * Quaternion::from_pure(*vector) * self.real.conjugate();
+
+        rotated.v
+    }
+
+    /// Transform a point with the inverse of a unit dual quaternion.
+    #[inline]
+    pub fn inverse_transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        self.conjugate().transform_point(point)
+    }
+
+    /// Transform a vector with the inverse of a unit dual quaternion.
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        self.conjugate().transform_vector(vector)
+    }
+
+    /// Transform a surface normal with a unit dual quaternion.
+    ///
+    /// Rigid body motions preserve angles and translations do not act on 
+    /// directions, so normals are rotated by the rotation part only.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Normal3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_x());
+    /// let distance = Vector3::new(1_f64, 1_f64, 1_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(90_f64), &distance);
+    /// let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+    /// let normal = Normal3::unit_y();
+    /// let expected = Normal3::unit_z();
+    /// let result = dual_quaternion.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        Normal3::from_vector_unchecked(self.transform_vector(normal.as_vector()))
+    }
+
+    /// Compute the power of a unit dual quaternion, where the exponent is
+    /// a real number.
+    ///
+    /// Raising a unit dual quaternion to a power scales the screw motion it
+    /// represents. That is, a rotation by an angle `angle` about a screw axis,
+    /// combined with a displacement `displacement` along the same axis, raised
+    /// to the power `exponent` is the rotation by `exponent * angle` combined
+    /// with the displacement `exponent * displacement` about the same screw
+    /// axis.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let distance = Vector3::new(0_f64, 0_f64, 4_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(90_f64), &distance);
+    /// let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+    /// let half_distance = Vector3::new(0_f64, 0_f64, 2_f64);
+    /// let expected = DualQuaternion::from_isometry(
+    ///     &Isometry3::from_axis_angle_translation(&axis, Degrees(45_f64), &half_distance)
+    /// );
+    /// let result = dual_quaternion.powf(0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn powf(&self, exponent: S) -> DualQuaternion<S> {
+        let one = S::one();
+        let cos_half_angle = S::min(S::max(self.real.s, -one), one);
+        let sin_half_angle = self.real.v.magnitude();
+        let threshold = num_traits::cast(1e-6_f64).unwrap();
+        if sin_half_angle < threshold {
+            // The rotation part is the identity, up to sign. The screw motion
+            // degenerates to a pure translation, so scaling the motion is
+            // scaling the translation.
+            let real = if self.real.s < S::zero() {
+                -Quaternion::identity()
+            } else {
+                Quaternion::identity()
+            };
+            let translation = self.translation().vector * exponent;
+
+            return Self::from_real_translation(real, translation);
+        }
+
+        // Compute the screw parameters of the dual quaternion: the rotation
+        // angle, the displacement along the screw axis, the direction of the
+        // screw axis, and the moment of the screw axis.
+        let half_angle = S::atan2(sin_half_angle, cos_half_angle);
+        let direction = self.real.v / sin_half_angle;
+        let half_displacement = -self.dual.s / sin_half_angle;
+        let moment = (self.dual.v - direction * (half_displacement * cos_half_angle)) / sin_half_angle;
+
+        let new_half_angle = half_angle * exponent;
+        let new_half_displacement = half_displacement * exponent;
+        let (sin_new_half_angle, cos_new_half_angle) = new_half_angle.sin_cos();
+        let real = Quaternion::from_parts(cos_new_half_angle, direction * sin_new_half_angle);
+        let dual = Quaternion::from_parts(
+            -new_half_displacement * sin_new_half_angle,
+            moment * sin_new_half_angle + direction * (new_half_displacement * cos_new_half_angle)
+        );
+
+        DualQuaternion::from_parts(real, dual)
+    }
+
+    /// Compute the screw linear interpolation (ScLERP) between two unit dual
+    /// quaternions.
+    ///
+    /// Screw linear interpolation is the dual quaternion analogue of
+    /// spherical linear interpolation. It moves along the screw motion
+    /// between the two rigid body transformations at constant linear and
+    /// angular speed. Like `slerp`, it takes the short way around.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let dual_quaternion0 = DualQuaternion::from_isometry(&Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(0_f64), &Vector3::new(0_f64, 0_f64, 0_f64)
+    /// ));
+    /// let dual_quaternion1 = DualQuaternion::from_isometry(&Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(90_f64), &Vector3::new(0_f64, 0_f64, 4_f64)
+    /// ));
+    /// let expected = DualQuaternion::from_isometry(&Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(45_f64), &Vector3::new(0_f64, 0_f64, 2_f64)
+    /// ));
+    /// let result = dual_quaternion0.sclerp(&dual_quaternion1, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn sclerp(&self, other: &DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        // Take the short way around by choosing the representative of `other`
+        // whose rotation lies in the same hemisphere as the rotation of `self`.
+        let other_aligned = if self.dot_real(other) < S::zero() {
+            -other
+        } else {
+            *other
+        };
+        let difference = self.conjugate() * other_aligned;
+
+        self * difference.powf(amount)
+    }
+
+    /// Compute the dual quaternion linear blend (DLB) of a collection of
+    /// unit dual quaternions.
+    ///
+    /// The blend is the normalized weighted sum of the dual quaternions. Each
+    /// dual quaternion is sign-corrected to lie in the same hemisphere as the
+    /// first one before summing, so the blend takes the short way around.
+    /// This is the blending operation used in dual quaternion skinning. The
+    /// function returns `None` if the slices have different lengths, or if
+    /// the weighted sum cannot be normalized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DualQuaternion,
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let distance = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let dual_quaternion0 = DualQuaternion::from_isometry(
+    ///     &Isometry3::from_axis_angle_translation(&axis, Degrees(0_f64), &distance)
+    /// );
+    /// let dual_quaternion1 = DualQuaternion::from_isometry(
+    ///     &Isometry3::from_axis_angle_translation(&axis, Degrees(90_f64), &distance)
+    /// );
+    /// let expected = DualQuaternion::from_isometry(
+    ///     &Isometry3::from_axis_angle_translation(&axis, Degrees(45_f64), &distance)
+    /// );
+    /// let result = DualQuaternion::dlb(
+    ///     &[dual_quaternion0, dual_quaternion1], &[0.5_f64, 0.5_f64]
+    /// ).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn dlb(dual_quaternions: &[DualQuaternion<S>], weights: &[S]) -> Option<DualQuaternion<S>> {
+        if dual_quaternions.len() != weights.len() || dual_quaternions.is_empty() {
+            return None;
+        }
+
+        let pivot = dual_quaternions[0];
+        let mut blend = DualQuaternion::zero();
+        for (dual_quaternion, weight) in dual_quaternions.iter().zip(weights.iter()) {
+            if pivot.dot_real(dual_quaternion) < S::zero() {
+                blend -= dual_quaternion * *weight;
+            } else {
+                blend += dual_quaternion * *weight;
+            }
+        }
+
+        blend.try_normalize()
+    }
+
+    /// Compute the dual quaternion linear blend between two unit dual
+    /// quaternions.
+    ///
+    /// This is a cheaper approximation to screw linear interpolation.
+    #[inline]
+    pub fn nlerp(&self, other: &DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        let weights = [S::one() - amount, amount];
+
+        Self::dlb(&[*self, *other], &weights).unwrap_or(*self)
+    }
+
+    /// Returns `true` if the elements of a dual quaternion are all finite.
+    /// Otherwise, it returns `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.real.is_finite() && self.dual.is_finite()
+    }
+}
+
+impl<S> fmt::Display for DualQuaternion<S>
+where
+    S: fmt::Display
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "DualQuaternion [real: [{}, {}, {}, {}], dual: [{}, {}, {}, {}]]",
+            self.real.s, self.real.v[0], self.real.v[1], self.real.v[2],
+            self.dual.s, self.dual.v[0], self.dual.v[1], self.dual.v[2]
+        )
+    }
+}
+
+impl<S> From<Isometry3<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn from(isometry: Isometry3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_isometry(&isometry)
+    }
+}
+
+impl<S> From<&Isometry3<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn from(isometry: &Isometry3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_isometry(isometry)
+    }
+}
+
+impl<S> From<DualQuaternion<S>> for Isometry3<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn from(dual_quaternion: DualQuaternion<S>) -> Isometry3<S> {
+        dual_quaternion.to_isometry()
+    }
+}
+
+impl<S> From<&DualQuaternion<S>> for Isometry3<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn from(dual_quaternion: &DualQuaternion<S>) -> Isometry3<S> {
+        dual_quaternion.to_isometry()
+    }
+}
+
+impl<S> approx::AbsDiffEq for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Quaternion::abs_diff_eq(&self.real, &other.real, epsilon) &&
+        Quaternion::abs_diff_eq(&self.dual, &other.dual, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Quaternion::relative_eq(&self.real, &other.real, epsilon, max_relative) &&
+        Quaternion::relative_eq(&self.dual, &other.dual, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Quaternion::ulps_eq(&self.real, &other.real, epsilon, max_ulps) &&
+        Quaternion::ulps_eq(&self.dual, &other.dual, epsilon, max_ulps)
+    }
+}
+
+impl<S> ops::Neg for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualQuaternion::from_parts(-self.real, -self.dual)
+    }
+}
+
+impl<S> ops::Neg for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualQuaternion::from_parts(-self.real, -self.dual)
+    }
+}
+
+impl<S> ops::Add<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Add<&DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Add<DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Add<&DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn add(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<S> ops::Sub<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Sub<&DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Sub<DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Sub<&DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn sub(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<S> ops::Mul<S> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        DualQuaternion::from_parts(self.real * other, self.dual * other)
+    }
+}
+
+impl<S> ops::Mul<S> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        DualQuaternion::from_parts(self.real * other, self.dual * other)
+    }
+}
+
+impl<S> ops::Mul<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real
+        )
+    }
+}
+
+impl<S> ops::Mul<&DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real
+        )
+    }
+}
+
+impl<S> ops::Mul<DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real
+        )
+    }
+}
+
+impl<S> ops::Mul<&DualQuaternion<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = DualQuaternion<S>;
+
+    #[inline]
+    fn mul(self, other: &DualQuaternion<S>) -> Self::Output {
+        DualQuaternion::from_parts(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real
+        )
+    }
+}
+
+impl<S> ops::Mul<Point3<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: Point3<S>) -> Self::Output {
+        self.transform_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point3<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: &Point3<S>) -> Self::Output {
+        self.transform_point(other)
+    }
+}
+
+impl<S> ops::Mul<Point3<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: Point3<S>) -> Self::Output {
+        self.transform_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point3<S>> for &DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, other: &Point3<S>) -> Self::Output {
+        self.transform_point(other)
+    }
+}
+
+impl<S> ops::AddAssign<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn add_assign(&mut self, other: DualQuaternion<S>) {
+        self.real += other.real;
+        self.dual += other.dual;
+    }
+}
+
+impl<S> ops::SubAssign<DualQuaternion<S>> for DualQuaternion<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn sub_assign(&mut self, other: DualQuaternion<S>) {
+        self.real -= other.real;
+        self.dual -= other.dual;
+    }
+}
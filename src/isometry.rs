@@ -30,6 +30,14 @@ use crate::transform::{
     Transform2,
     Transform3,
 };
+use crate::dual_quaternion::{
+    DualQuaternion,
+};
+use crate::registration;
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -202,6 +210,52 @@ where
         }
     }
 
+    /// Estimate the rigid body transformation that best maps the points 
+    /// `source` onto the corresponding points `target` in the least squares 
+    /// sense, using the Kabsch algorithm.
+    ///
+    /// The function returns the best fit isometry together with the root mean 
+    /// square distance between the transformed source points and the target 
+    /// points. It returns `None` if the point slices are empty or have 
+    /// different lengths. The rotation is unique only when the source points do 
+    /// not all lie on a line.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Isometry2,
+    /// #     Degrees,
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let isometry = Isometry2::from_angle_translation(Degrees(72_f64), &Vector2::new(1_f64, -2_f64));
+    /// let source = [
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(1_f64, 0_f64),
+    ///     Point2::new(0_f64, 2_f64),
+    ///     Point2::new(-3_f64, 1_f64),
+    /// ];
+    /// let target = source.map(|point| isometry.transform_point(&point));
+    /// let (result, residual) = Isometry2::kabsch(&source, &target).unwrap();
+    ///
+    /// assert!(relative_eq!(result, isometry, epsilon = 1e-10));
+    /// assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    /// ```
+    pub fn kabsch(source: &[Point2<S>], target: &[Point2<S>]) -> Option<(Isometry2<S>, S)> {
+        let alignment = registration::align_points2(source, target)?;
+        let rotated_centroid = alignment.rotation.rotate_point(&alignment.source_centroid);
+        let translation = Translation2::from_vector(&(alignment.target_centroid - rotated_centroid));
+        let isometry = Isometry2::from_parts(&translation, &alignment.rotation);
+        let residual = registration::rms_residual2(source, target, |point| isometry.transform_point(point));
+
+        Some((isometry, residual))
+    }
+
     /// Convert an isometry to a generic transformation.
     #[inline]
     pub fn to_transform(&self) -> Transform2<S> {
@@ -460,6 +514,36 @@ where
         self.rotation.inverse_rotate_vector(vector)
     }
 
+    /// Apply the isometry to a normal vector.
+    ///
+    /// The translation part of an isometry does not affect directions, so 
+    /// normals are rotated by the rotation part.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Isometry2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let isometry = Isometry2::from_angle_translation(Degrees(90_f64), &Vector2::new(1_f64, 2_f64));
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 0_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(0_f64, 1_f64));
+    /// let result = isometry.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        Normal2::from_vector_unchecked(self.rotation.rotate_vector(normal.as_vector()))
+    }
+
     /// Construct the identity isometry.
     ///
     /// ## Example
@@ -1087,6 +1171,56 @@ where
         Self::from_parts(&translation, &rotation)  
     }
 
+    /// Estimate the rigid body transformation that best maps the points 
+    /// `source` onto the corresponding points `target` in the least squares 
+    /// sense, using the Kabsch algorithm.
+    ///
+    /// The function returns the best fit isometry together with the root mean 
+    /// square distance between the transformed source points and the target 
+    /// points. It returns `None` if the point slices are empty or have 
+    /// different lengths. The rotation is unique only when the source points do 
+    /// not all lie on a line.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Isometry3,
+    /// #     Degrees,
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+    /// let distance = Vector3::new(1_f64, -2_f64, 3_f64);
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &distance);
+    /// let source = [
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(1_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, 2_f64, 0_f64),
+    ///     Point3::new(0_f64, 0_f64, 3_f64),
+    ///     Point3::new(-1_f64, 1_f64, 1_f64),
+    /// ];
+    /// let target = source.map(|point| isometry.transform_point(&point));
+    /// let (result, residual) = Isometry3::kabsch(&source, &target).unwrap();
+    ///
+    /// assert!(relative_eq!(result, isometry, epsilon = 1e-10));
+    /// assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    /// ```
+    pub fn kabsch(source: &[Point3<S>], target: &[Point3<S>]) -> Option<(Isometry3<S>, S)> {
+        let alignment = registration::align_points3(source, target)?;
+        let rotated_centroid = alignment.rotation.rotate_point(&alignment.source_centroid);
+        let translation = Translation3::from_vector(&(alignment.target_centroid - rotated_centroid));
+        let isometry = Isometry3::from_parts(&translation, &alignment.rotation);
+        let residual = registration::rms_residual3(source, target, |point| isometry.transform_point(point));
+
+        Some((isometry, residual))
+    }
+
     /// Convert an isometry into a generic transformation.
     #[inline]
     pub fn to_transform(&self) -> Transform3<S> {
@@ -1360,6 +1494,38 @@ where
         self.rotation.inverse_rotate_vector(vector)
     }
 
+    /// Apply the isometry to a normal vector.
+    ///
+    /// The translation part of an isometry does not affect directions, so 
+    /// normals are rotated by the rotation part.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Isometry3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// #     Degrees,
+    /// #     Unit,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(90_f64), &Vector3::new(1_f64, 2_f64, 3_f64));
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 0_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(0_f64, 1_f64, 0_f64));
+    /// let result = isometry.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        Normal3::from_vector_unchecked(self.rotation.rotate_vector(normal.as_vector()))
+    }
+
     /// Construct the identity isometry.
     ///
     /// ## Example
@@ -1385,6 +1551,92 @@ where
             translation: Translation3::identity()
         }
     }
+
+    /// Interpolate between two isometries by interpolating the rotation and 
+    /// the translation parts independently.
+    ///
+    /// The rotation parts are spherically linearly interpolated along the 
+    /// shortest arc, and the translation parts are linearly interpolated. 
+    /// Both parts proceed at constant speed, but the origin of the moving 
+    /// frame follows a straight line rather than a helix. For a screw motion, 
+    /// see [`Isometry3::sclerp`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Isometry3,
+    /// #     Degrees,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let isometry1 = Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(0_f64), &Vector3::new(0_f64, 0_f64, 0_f64)
+    /// );
+    /// let isometry2 = Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(90_f64), &Vector3::new(4_f64, 0_f64, 0_f64)
+    /// );
+    /// let expected = Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(45_f64), &Vector3::new(2_f64, 0_f64, 0_f64)
+    /// );
+    /// let result = isometry1.lerp_slerp(&isometry2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn lerp_slerp(&self, other: &Isometry3<S>, amount: S) -> Isometry3<S> {
+        let rotation = self.rotation.slerp(&other.rotation, amount);
+        let vector = self.translation.vector.lerp(&other.translation.vector, amount);
+        let translation = Translation3::from_vector(&vector);
+
+        Isometry3::from_parts(&translation, &rotation)
+    }
+
+    /// Interpolate between two isometries along a screw motion.
+    ///
+    /// Every rigid body motion is a rotation about an axis together with a 
+    /// translation along that axis. Screw linear interpolation moves along 
+    /// this helix at constant angular and linear speed, taking the shortest 
+    /// path between the two rotations. This is equivalent to 
+    /// [`DualQuaternion::sclerp`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Isometry3,
+    /// #     Degrees,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let isometry1 = Isometry3::identity();
+    /// let isometry2 = Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(90_f64), &Vector3::new(0_f64, 0_f64, 4_f64)
+    /// );
+    /// let expected = Isometry3::from_axis_angle_translation(
+    ///     &axis, Degrees(45_f64), &Vector3::new(0_f64, 0_f64, 2_f64)
+    /// );
+    /// let result = isometry1.sclerp(&isometry2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn sclerp(&self, other: &Isometry3<S>, amount: S) -> Isometry3<S> {
+        let dual_quaternion1 = DualQuaternion::from_isometry(self);
+        let dual_quaternion2 = DualQuaternion::from_isometry(other);
+
+        dual_quaternion1.sclerp(&dual_quaternion2, amount).to_isometry()
+    }
 }
 
 impl<S> fmt::Display for Isometry3<S> 
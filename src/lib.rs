@@ -109,8 +109,13 @@ mod vector;
 
 mod euler;
 mod projection;
+mod camera;
+mod viewport;
 mod point;
+mod normal;
 mod quaternion;
+mod dual_quaternion;
+mod complex;
 
 mod isometry;
 mod reflection;
@@ -119,7 +124,18 @@ mod scale;
 mod shear;
 mod translation;
 mod transform;
+mod affine;
+mod projective;
 mod similarity;
+mod composition;
+mod shadow;
+mod cubemap;
+mod controller;
+mod curvilinear;
+mod convention;
+mod space;
+mod registration;
+mod transformation;
 
 
 pub use base::*;
@@ -128,9 +144,14 @@ pub use angle::*;
 pub use euler::*;
 pub use matrix::*;
 pub use projection::*;
+pub use camera::*;
+pub use viewport::*;
 pub use quaternion::*;
+pub use dual_quaternion::*;
+pub use complex::*;
 pub use vector::*;
 pub use point::*;
+pub use normal::*;
 
 pub use isometry::*;
 pub use reflection::*;
@@ -139,5 +160,14 @@ pub use scale::*;
 pub use shear::*;
 pub use translation::*;
 pub use transform::*;
+pub use affine::*;
+pub use projective::*;
 pub use similarity::*;
+pub use shadow::*;
+pub use cubemap::*;
+pub use controller::*;
+pub use curvilinear::*;
+pub use convention::*;
+pub use space::*;
+pub use transformation::*;
 
@@ -0,0 +1,652 @@
+use crate::base::{
+    Magnitude,
+    ScalarSigned,
+    ScalarFloat,
+    Unit,
+};
+use crate::vector::{
+    Vector2,
+    Vector3,
+};
+use crate::isometry::{
+    Isometry2,
+    Isometry3,
+};
+use crate::reflection::{
+    Reflection2,
+    Reflection3,
+};
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+use crate::scale::{
+    Scale2,
+    Scale3,
+};
+use crate::shear::{
+    Shear2,
+    Shear3,
+};
+use crate::similarity::{
+    Similarity2,
+    Similarity3,
+};
+use crate::translation::{
+    Translation2,
+    Translation3,
+};
+
+use core::fmt;
+use core::ops;
+
+
+/// A unit surface normal in two dimensions.
+///
+/// A normal vector is a covector: it does not transform like an ordinary 
+/// vector. Under a transformation with linear part `M`, a tangent vector 
+/// transforms by `M`, but a normal vector transforms by the inverse transpose 
+/// `M^-T` of `M`. The two rules only agree when `M` is a rotation times a 
+/// uniform scale, so applying `transform_vector` to a normal gives wrong 
+/// results under non-uniform scales and shears. The normal type keeps normal 
+/// vectors apart from ordinary vectors, so they can only be transformed with 
+/// `transform_normal`. A normal is always unit normalized.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Normal2<S> {
+    /// The underlying unit vector.
+    vector: Vector2<S>,
+}
+
+impl<S> Normal2<S> {
+    /// Wrap a vector into a normal, assuming that it is normalized without 
+    /// checking.
+    #[inline]
+    pub(crate) fn from_vector_unchecked(vector: Vector2<S>) -> Normal2<S> {
+        Normal2 {
+            vector: vector,
+        }
+    }
+
+    /// Get a reference to the underlying unit vector.
+    #[inline]
+    pub fn as_vector(&self) -> &Vector2<S> {
+        &self.vector
+    }
+
+    /// Unwrap the underlying unit vector.
+    #[inline]
+    pub fn into_inner(self) -> Vector2<S> {
+        self.vector
+    }
+}
+
+impl<S> Normal2<S> 
+where 
+    S: Copy 
+{
+    /// Convert a normal into an ordinary vector.
+    #[inline]
+    pub fn to_vector(&self) -> Vector2<S> {
+        self.vector
+    }
+
+    /// Convert a normal into a unit vector.
+    #[inline]
+    pub fn to_unit_vector(&self) -> Unit<Vector2<S>> {
+        Unit::from_value_unchecked(self.vector)
+    }
+}
+
+impl<S> Normal2<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a normal from a vector, normalizing the input vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Normal2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let vector = Vector2::new(3_f64, 4_f64);
+    /// let expected = Vector2::new(3_f64 / 5_f64, 4_f64 / 5_f64);
+    /// let normal = Normal2::from_vector(&vector);
+    ///
+    /// assert!(relative_eq!(normal.as_vector(), &expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_vector(vector: &Vector2<S>) -> Normal2<S> {
+        Normal2 {
+            vector: vector.normalize(),
+        }
+    }
+
+    /// Construct a normal from a vector, provided that the magnitude of the 
+    /// vector is larger than `threshold`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Normal2,
+    /// #     Vector2,
+    /// # };
+    /// #
+    /// let vector: Vector2<f64> = Vector2::zero();
+    /// 
+    /// assert!(Normal2::try_from_vector(&vector, 1e-10).is_none());
+    /// ```
+    #[inline]
+    pub fn try_from_vector(vector: &Vector2<S>, threshold: S) -> Option<Normal2<S>> {
+        Unit::try_from_value(*vector, threshold).map(|unit| Normal2::from_unit_vector(&unit))
+    }
+
+    /// Construct a normal from a unit vector.
+    #[inline]
+    pub fn from_unit_vector(unit_vector: &Unit<Vector2<S>>) -> Normal2<S> {
+        Normal2 {
+            vector: *unit_vector.as_ref(),
+        }
+    }
+
+    /// Construct the unit normal along the **x-axis**.
+    #[inline]
+    pub fn unit_x() -> Normal2<S> {
+        Normal2 {
+            vector: Vector2::unit_x(),
+        }
+    }
+
+    /// Construct the unit normal along the **y-axis**.
+    #[inline]
+    pub fn unit_y() -> Normal2<S> {
+        Normal2 {
+            vector: Vector2::unit_y(),
+        }
+    }
+
+    /// Compute the dot product of a normal with a vector.
+    #[inline]
+    pub fn dot(&self, vector: &Vector2<S>) -> S {
+        self.vector.dot(vector)
+    }
+}
+
+impl<S> AsRef<Vector2<S>> for Normal2<S> {
+    #[inline]
+    fn as_ref(&self) -> &Vector2<S> {
+        &self.vector
+    }
+}
+
+impl<S> fmt::Display for Normal2<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Normal2 [{}, {}]",
+            self.vector[0], self.vector[1]
+        )
+    }
+}
+
+impl<S> From<Normal2<S>> for Vector2<S> {
+    #[inline]
+    fn from(normal: Normal2<S>) -> Vector2<S> {
+        normal.vector
+    }
+}
+
+impl<S> From<Normal2<S>> for Unit<Vector2<S>> {
+    #[inline]
+    fn from(normal: Normal2<S>) -> Unit<Vector2<S>> {
+        Unit::from_value_unchecked(normal.vector)
+    }
+}
+
+impl<S> From<Unit<Vector2<S>>> for Normal2<S> {
+    #[inline]
+    fn from(unit_vector: Unit<Vector2<S>>) -> Normal2<S> {
+        Normal2 {
+            vector: unit_vector.into_inner(),
+        }
+    }
+}
+
+impl<S> From<&Unit<Vector2<S>>> for Normal2<S> 
+where 
+    S: Copy 
+{
+    #[inline]
+    fn from(unit_vector: &Unit<Vector2<S>>) -> Normal2<S> {
+        Normal2 {
+            vector: *unit_vector.as_ref(),
+        }
+    }
+}
+
+impl<S> ops::Neg for Normal2<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Normal2<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Normal2 {
+            vector: -self.vector,
+        }
+    }
+}
+
+impl<S> ops::Neg for &Normal2<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Normal2<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Normal2 {
+            vector: -self.vector,
+        }
+    }
+}
+
+impl<S> approx::AbsDiffEq for Normal2<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Vector2::abs_diff_eq(&self.vector, &other.vector, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Normal2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector2::relative_eq(&self.vector, &other.vector, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Normal2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector2::ulps_eq(&self.vector, &other.vector, epsilon, max_ulps)
+    }
+}
+
+
+/// A unit surface normal in three dimensions.
+///
+/// A normal vector is a covector: it does not transform like an ordinary 
+/// vector. Under a transformation with linear part `M`, a tangent vector 
+/// transforms by `M`, but a normal vector transforms by the inverse transpose 
+/// `M^-T` of `M`. The two rules only agree when `M` is a rotation times a 
+/// uniform scale, so applying `transform_vector` to a normal gives wrong 
+/// results under non-uniform scales and shears. The normal type keeps normal 
+/// vectors apart from ordinary vectors, so they can only be transformed with 
+/// `transform_normal`. A normal is always unit normalized.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Normal3<S> {
+    /// The underlying unit vector.
+    vector: Vector3<S>,
+}
+
+impl<S> Normal3<S> {
+    /// Wrap a vector into a normal, assuming that it is normalized without 
+    /// checking.
+    #[inline]
+    pub(crate) fn from_vector_unchecked(vector: Vector3<S>) -> Normal3<S> {
+        Normal3 {
+            vector: vector,
+        }
+    }
+
+    /// Get a reference to the underlying unit vector.
+    #[inline]
+    pub fn as_vector(&self) -> &Vector3<S> {
+        &self.vector
+    }
+
+    /// Unwrap the underlying unit vector.
+    #[inline]
+    pub fn into_inner(self) -> Vector3<S> {
+        self.vector
+    }
+}
+
+impl<S> Normal3<S> 
+where 
+    S: Copy 
+{
+    /// Convert a normal into an ordinary vector.
+    #[inline]
+    pub fn to_vector(&self) -> Vector3<S> {
+        self.vector
+    }
+
+    /// Convert a normal into a unit vector.
+    #[inline]
+    pub fn to_unit_vector(&self) -> Unit<Vector3<S>> {
+        Unit::from_value_unchecked(self.vector)
+    }
+}
+
+impl<S> Normal3<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a normal from a vector, normalizing the input vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Normal3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let vector = Vector3::new(0_f64, 3_f64, 4_f64);
+    /// let expected = Vector3::new(0_f64, 3_f64 / 5_f64, 4_f64 / 5_f64);
+    /// let normal = Normal3::from_vector(&vector);
+    ///
+    /// assert!(relative_eq!(normal.as_vector(), &expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_vector(vector: &Vector3<S>) -> Normal3<S> {
+        Normal3 {
+            vector: vector.normalize(),
+        }
+    }
+
+    /// Construct a normal from a vector, provided that the magnitude of the 
+    /// vector is larger than `threshold`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Normal3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let vector: Vector3<f64> = Vector3::zero();
+    /// 
+    /// assert!(Normal3::try_from_vector(&vector, 1e-10).is_none());
+    /// ```
+    #[inline]
+    pub fn try_from_vector(vector: &Vector3<S>, threshold: S) -> Option<Normal3<S>> {
+        Unit::try_from_value(*vector, threshold).map(|unit| Normal3::from_unit_vector(&unit))
+    }
+
+    /// Construct a normal from a unit vector.
+    #[inline]
+    pub fn from_unit_vector(unit_vector: &Unit<Vector3<S>>) -> Normal3<S> {
+        Normal3 {
+            vector: *unit_vector.as_ref(),
+        }
+    }
+
+    /// Construct the unit normal along the **x-axis**.
+    #[inline]
+    pub fn unit_x() -> Normal3<S> {
+        Normal3 {
+            vector: Vector3::unit_x(),
+        }
+    }
+
+    /// Construct the unit normal along the **y-axis**.
+    #[inline]
+    pub fn unit_y() -> Normal3<S> {
+        Normal3 {
+            vector: Vector3::unit_y(),
+        }
+    }
+
+    /// Construct the unit normal along the **z-axis**.
+    #[inline]
+    pub fn unit_z() -> Normal3<S> {
+        Normal3 {
+            vector: Vector3::unit_z(),
+        }
+    }
+
+    /// Compute the dot product of a normal with a vector.
+    #[inline]
+    pub fn dot(&self, vector: &Vector3<S>) -> S {
+        self.vector.dot(vector)
+    }
+}
+
+impl<S> AsRef<Vector3<S>> for Normal3<S> {
+    #[inline]
+    fn as_ref(&self) -> &Vector3<S> {
+        &self.vector
+    }
+}
+
+impl<S> fmt::Display for Normal3<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Normal3 [{}, {}, {}]",
+            self.vector[0], self.vector[1], self.vector[2]
+        )
+    }
+}
+
+impl<S> From<Normal3<S>> for Vector3<S> {
+    #[inline]
+    fn from(normal: Normal3<S>) -> Vector3<S> {
+        normal.vector
+    }
+}
+
+impl<S> From<Normal3<S>> for Unit<Vector3<S>> {
+    #[inline]
+    fn from(normal: Normal3<S>) -> Unit<Vector3<S>> {
+        Unit::from_value_unchecked(normal.vector)
+    }
+}
+
+impl<S> From<Unit<Vector3<S>>> for Normal3<S> {
+    #[inline]
+    fn from(unit_vector: Unit<Vector3<S>>) -> Normal3<S> {
+        Normal3 {
+            vector: unit_vector.into_inner(),
+        }
+    }
+}
+
+impl<S> From<&Unit<Vector3<S>>> for Normal3<S> 
+where 
+    S: Copy 
+{
+    #[inline]
+    fn from(unit_vector: &Unit<Vector3<S>>) -> Normal3<S> {
+        Normal3 {
+            vector: *unit_vector.as_ref(),
+        }
+    }
+}
+
+impl<S> ops::Neg for Normal3<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Normal3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Normal3 {
+            vector: -self.vector,
+        }
+    }
+}
+
+impl<S> ops::Neg for &Normal3<S> 
+where 
+    S: ScalarSigned 
+{
+    type Output = Normal3<S>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Normal3 {
+            vector: -self.vector,
+        }
+    }
+}
+
+impl<S> approx::AbsDiffEq for Normal3<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.vector, &other.vector, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Normal3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.vector, &other.vector, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Normal3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.vector, &other.vector, epsilon, max_ulps)
+    }
+}
+
+
+macro_rules! impl_transform_normal_ops {
+    ($Normal:ident, $($Type:ident),* $(,)*) => {
+        $(
+            impl<S> ops::Mul<$Normal<S>> for $Type<S> 
+            where 
+                S: ScalarFloat 
+            {
+                type Output = $Normal<S>;
+
+                #[inline]
+                fn mul(self, other: $Normal<S>) -> Self::Output {
+                    self.transform_normal(&other)
+                }
+            }
+
+            impl<S> ops::Mul<&$Normal<S>> for $Type<S> 
+            where 
+                S: ScalarFloat 
+            {
+                type Output = $Normal<S>;
+
+                #[inline]
+                fn mul(self, other: &$Normal<S>) -> Self::Output {
+                    self.transform_normal(other)
+                }
+            }
+
+            impl<S> ops::Mul<$Normal<S>> for &$Type<S> 
+            where 
+                S: ScalarFloat 
+            {
+                type Output = $Normal<S>;
+
+                #[inline]
+                fn mul(self, other: $Normal<S>) -> Self::Output {
+                    self.transform_normal(&other)
+                }
+            }
+
+            impl<S> ops::Mul<&$Normal<S>> for &$Type<S> 
+            where 
+                S: ScalarFloat 
+            {
+                type Output = $Normal<S>;
+
+                #[inline]
+                fn mul(self, other: &$Normal<S>) -> Self::Output {
+                    self.transform_normal(other)
+                }
+            }
+        )*
+    }
+}
+
+impl_transform_normal_ops!(
+    Normal2, 
+    Translation2, Rotation2, Scale2, Shear2, Reflection2, Isometry2, Similarity2,
+);
+impl_transform_normal_ops!(
+    Normal3, 
+    Translation3, Rotation3, Scale3, Shear3, Reflection3, Isometry3, Similarity3,
+);
+
@@ -3,6 +3,8 @@ use crate::base::{
     Scalar,
     ScalarSigned,
     ScalarFloat,
+    fast_rsqrt,
+    use_fast_math,
 };
 use crate::vector::{
     Vector1,
@@ -1385,7 +1387,11 @@ macro_rules! impl_magnitude {
             
             #[inline]
             fn normalize(&self) -> Self {
-                self / self.magnitude()
+                if use_fast_math::<S>() {
+                    self * fast_rsqrt(self.magnitude_squared())
+                } else {
+                    self / self.magnitude()
+                }
             }
             
             #[inline]
@@ -12,13 +12,284 @@ use crate::point::{
     Point3,
 };
 use crate::vector::{
+    Vector2,
     Vector3,
+    Vector4,
 };
 
 use core::fmt;
 use core::ops;
 
 
+
+/// Compute the `index`-th element of the van der Corput sequence in base `base`.
+fn radical_inverse<S>(index: usize, base: usize) -> S 
+where 
+    S: ScalarFloat 
+{
+    let base_s: S = num_traits::cast(base).unwrap();
+    let inverse_base = S::one() / base_s;
+    let mut fraction = inverse_base;
+    let mut result = S::zero();
+    let mut i = index;
+    while i > 0 {
+        let digit: S = num_traits::cast(i % base).unwrap();
+        result += digit * fraction;
+        fraction *= inverse_base;
+        i /= base;
+    }
+
+    result
+}
+
+/// Compute the subpixel jitter offset for a frame of temporal anti-aliasing.
+///
+/// The jitter offsets follow the Halton (2, 3) sequence, shifted to lie
+/// in `[-0.5, 0.5) x [-0.5, 0.5)`. The units of the offset are pixels, with the
+/// **x-axis** pointing right and the **y-axis** pointing up, as in normalized
+/// device coordinates. The sequence starts at the first nonzero Halton point,
+/// so `index == 0` gives the offset `(0, -1/6)`. Renderers usually cycle the
+/// index through a short period, such as eight or sixteen frames.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     halton_jitter,
+/// #     Vector2,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// let jitter0: Vector2<f64> = halton_jitter(0);
+/// let jitter1: Vector2<f64> = halton_jitter(1);
+///
+/// assert!(relative_eq!(jitter0, Vector2::new(0_f64, -1_f64 / 6_f64), epsilon = 1e-10));
+/// assert!(relative_eq!(jitter1, Vector2::new(-0.25_f64, 1_f64 / 6_f64), epsilon = 1e-10));
+/// ```
+pub fn halton_jitter<S>(index: usize) -> Vector2<S> 
+where 
+    S: ScalarFloat 
+{
+    let one_half: S = num_traits::cast(0.5).unwrap();
+    let x = radical_inverse::<S>(index + 1, 2);
+    let y = radical_inverse::<S>(index + 1, 3);
+
+    Vector2::new(x - one_half, y - one_half)
+}
+
+
+/// Replace the near plane of a perspective projection matrix with an 
+/// arbitrary clipping plane, using the oblique frustum construction of 
+/// Eric Lengyel.
+///
+/// The clipping plane `clip_plane == (a, b, c, d)` lies in camera space and 
+/// contains the points `p` with `a * p.x + b * p.y + c * p.z + d == 0`. The 
+/// resulting projection keeps the points with `a * p.x + b * p.y + c * p.z + d >= 0` 
+/// and clips away the rest, so the camera must lie strictly on the negative 
+/// side of the plane, i.e. `d < 0`. Points on the plane map to depth `-1` in 
+/// normalized device coordinates, and the projected **x-axis** and **y-axis** 
+/// coordinates do not change. The new far plane passes through the corner of 
+/// the original far plane that lies farthest from the clipping plane, which 
+/// keeps the distortion of the far plane, and the resulting loss of depth 
+/// precision, as small as possible.
+///
+/// The projection matrix must map camera space into clip space with the 
+/// depth range `[-1, 1]` of normalized device coordinates, as every projection 
+/// in this crate does. The function returns `None` if the camera does not lie 
+/// on the negative side of the clipping plane, or if the projection matrix is 
+/// not invertible.
+///
+/// This is the usual way to clip away the geometry behind the mirror plane 
+/// of a planar reflection, or behind the opening of a portal, without 
+/// spending a user clipping plane.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     oblique_near_plane,
+/// #     Degrees,
+/// #     PerspectiveFov3,
+/// #     Point3,
+/// #     Vector4,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// let perspective = PerspectiveFov3::new(Degrees(90_f64), 1_f64, 0.1_f64, 100_f64);
+/// // The tilted plane `y - z - 4 == 0` lies in front of the camera.
+/// let clip_plane = Vector4::new(0_f64, 1_f64, -1_f64, -4_f64);
+/// let oblique = oblique_near_plane(perspective.matrix(), &clip_plane).unwrap();
+/// let point = Point3::new(0.5_f64, 1_f64, -3_f64);
+/// let projected = Point3::from_homogeneous(oblique * point.to_homogeneous()).unwrap();
+/// let expected = perspective.project_point(&point);
+///
+/// // Points on the clipping plane land on the near plane.
+/// assert!(relative_eq!(projected.z, -1_f64, epsilon = 1e-10));
+/// assert!(relative_eq!(projected.x, expected.x, epsilon = 1e-10));
+/// assert!(relative_eq!(projected.y, expected.y, epsilon = 1e-10));
+/// ```
+pub fn oblique_near_plane<S>(projection: &Matrix4x4<S>, clip_plane: &Vector4<S>) -> Option<Matrix4x4<S>> 
+where 
+    S: ScalarFloat 
+{
+    let zero = S::zero();
+    let one = S::one();
+    let two = one + one;
+    if clip_plane.w >= zero || clip_plane.w.is_nan() {
+        return None;
+    }
+
+    let projection_inverse = projection.inverse()?;
+    // Find the corner of the far plane opposite the clipping plane in clip space, 
+    // and bring it back into camera space.
+    let clip_plane_clip = projection_inverse.transpose() * clip_plane;
+    let corner_clip = Vector4::new(clip_plane_clip.x.signum(), clip_plane_clip.y.signum(), one, one);
+    let corner = projection_inverse * corner_clip;
+    let clip_plane_dot_corner = clip_plane.dot(&corner);
+    if clip_plane_dot_corner.abs() <= S::epsilon() {
+        return None;
+    }
+
+    // Replace the third row so that the near plane `row3 + row4 == 0` becomes 
+    // the clipping plane, and the far plane `row4 - row3 == 0` passes through 
+    // the corner.
+    let scaled_plane = clip_plane * (two / clip_plane_dot_corner);
+    let mut result = *projection;
+    result.c0r2 = scaled_plane.x - projection.c0r3;
+    result.c1r2 = scaled_plane.y - projection.c1r3;
+    result.c2r2 = scaled_plane.z - projection.c2r3;
+    result.c3r2 = scaled_plane.w - projection.c3r3;
+
+    Some(result)
+}
+
+
+/// The eye of a stereo camera rig that a projection is built for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StereoEye {
+    /// The left eye, displaced along the **negative x-axis**.
+    Left,
+    /// The right eye, displaced along the **positive x-axis**.
+    Right,
+}
+
+impl StereoEye {
+    /// Get the position of the eye relative to the center of the stereo rig
+    /// in camera space, given the interpupillary distance between the eyes.
+    ///
+    /// The view transformation of an eye is the view transformation of the
+    /// center of the rig followed by a translation by the negated offset.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     StereoEye,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let ipd = 0.064_f64;
+    ///
+    /// assert_eq!(StereoEye::Left.offset(ipd), Vector3::new(-0.032_f64, 0_f64, 0_f64));
+    /// assert_eq!(StereoEye::Right.offset(ipd), Vector3::new(0.032_f64, 0_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn offset<S>(self, interpupillary_distance: S) -> Vector3<S> 
+    where 
+        S: ScalarFloat 
+    {
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let half_ipd = interpupillary_distance * one_half;
+        let zero = S::zero();
+        match self {
+            StereoEye::Left => Vector3::new(-half_ipd, zero, zero),
+            StereoEye::Right => Vector3::new(half_ipd, zero, zero),
+        }
+    }
+}
+
+
+/// The handedness of the camera space that a projection matrix acts on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    /// A right-handed camera space, where the camera looks down the 
+    /// **negative z-axis**. This is the convention of OpenGL and of the 
+    /// projections in this crate.
+    RightHanded,
+    /// A left-handed camera space, where the camera looks down the 
+    /// **positive z-axis**. This is the convention of Direct3D.
+    LeftHanded,
+}
+
+
+/// The range of depths in normalized device coordinates that a projection 
+/// maps the near and far planes to.
+///
+/// The depth range of a projection matrix cannot be recovered from the matrix 
+/// alone. For example, the OpenGL perspective projection with `near == 1` and 
+/// `far == 3` is the same matrix as the projection with the depth range `[0, 1]`, 
+/// `near == 1.5`, and `far == 3`. The depth range is a property of the graphics 
+/// API that consumes the matrix, so it must be supplied when decomposing one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    /// The near plane maps to `-1` and the far plane maps to `1`. This is 
+    /// the convention of OpenGL and of the projections in this crate.
+    NegativeOneToOne,
+    /// The near plane maps to `0` and the far plane maps to `1`. This is the 
+    /// convention of Direct3D, Vulkan, and Metal.
+    ZeroToOne,
+    /// The near plane maps to `1` and the far plane maps to `0`. This is the 
+    /// reversed depth convention that improves the precision of floating 
+    /// point depth buffers.
+    ReversedZeroToOne,
+}
+
+impl DepthRange {
+    /// Get the depth in normalized device coordinates of the near plane.
+    #[inline]
+    pub fn near_depth<S>(self) -> S 
+    where 
+        S: ScalarFloat 
+    {
+        match self {
+            DepthRange::NegativeOneToOne => -S::one(),
+            DepthRange::ZeroToOne => S::zero(),
+            DepthRange::ReversedZeroToOne => S::one(),
+        }
+    }
+
+    /// Get the depth in normalized device coordinates of the far plane.
+    #[inline]
+    pub fn far_depth<S>(self) -> S 
+    where 
+        S: ScalarFloat 
+    {
+        match self {
+            DepthRange::NegativeOneToOne => S::one(),
+            DepthRange::ZeroToOne => S::one(),
+            DepthRange::ReversedZeroToOne => S::zero(),
+        }
+    }
+}
+
+/// Compute the tolerance below which an entry of a projection matrix 
+/// counts as zero, relative to the largest entry of the matrix.
+fn projection_tolerance<S>(matrix: &Matrix4x4<S>) -> S 
+where 
+    S: ScalarFloat 
+{
+    let max_entry = matrix.as_slice()
+        .iter()
+        .fold(S::zero(), |max_entry, entry| max_entry.max(entry.abs()));
+
+    max_entry * S::default_epsilon().sqrt()
+}
+
+
 /// A perspective projection transformation based on arbitrary `left`, `right`, 
 /// `bottom`, `top`, `near`, and `far` planes.
 ///
@@ -100,13 +371,13 @@ where
     /// Get the left plane along the **negative x-axis**.
     #[inline]
     pub fn left_x(&self)-> S {
-        self.near
+        self.left
     }
 
     /// Get the right plane along the **positive x-axis**.
     #[inline]
     pub fn right_x(&self) -> S {
-        self.far
+        self.right
     }
 
     /// Get the bottom plane along the **negative y-axis**.
@@ -188,7 +459,7 @@ where
         
         Point3::new(
             (self.matrix.c0r0 * point.x + self.matrix.c2r0 * point.z) * inverse_w,
-            (self.matrix.c1r1 * point.y + self.matrix.c3r1 * point.z) * inverse_w,
+            (self.matrix.c1r1 * point.y + self.matrix.c2r1 * point.z) * inverse_w,
             (self.matrix.c2r2 * point.z + self.matrix.c3r2) * inverse_w
         )
     }
@@ -370,6 +641,246 @@ where
             c3r2 * inverse_w,
         )
     }
+
+    /// Construct the off-axis perspective projection for one eye of a stereo 
+    /// camera rig.
+    ///
+    /// Both eyes share the vertical field of view `vfov`, the aspect ratio 
+    /// `aspect`, and the near and far planes. The eyes sit at 
+    /// `StereoEye::offset(interpupillary_distance)` from the center of the rig, 
+    /// and their viewing frustums are sheared horizontally so that both frustums 
+    /// cover the same window at the convergence distance along the **negative 
+    /// z-axis**. Objects at the convergence distance appear at zero parallax, 
+    /// i.e. at the same place in both images. The frustum of the left eye 
+    /// shifts right and the frustum of the right eye shifts left. Unlike 
+    /// rotating each eye toward the convergence point, this keeps the image 
+    /// planes of both eyes parallel and introduces no vertical parallax.
+    ///
+    /// The projection does not move the eye; the view transformation of each 
+    /// eye must still be translated by the negated eye offset.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Perspective3,
+    /// #     Point3,
+    /// #     StereoEye,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect = 16_f64 / 9_f64;
+    /// let (near, far) = (0.1_f64, 100_f64);
+    /// let ipd = 0.064_f64;
+    /// let convergence = 2_f64;
+    /// let left = Perspective3::from_stereo_eye(StereoEye::Left, vfov, aspect, near, far, ipd, convergence);
+    /// let right = Perspective3::from_stereo_eye(StereoEye::Right, vfov, aspect, near, far, ipd, convergence);
+    ///
+    /// // A point at the convergence distance in front of the rig projects to 
+    /// // the same location for both eyes.
+    /// let point = Point3::new(0.3_f64, -0.2_f64, -convergence);
+    /// let left_eye_point = point - StereoEye::Left.offset(ipd);
+    /// let right_eye_point = point - StereoEye::Right.offset(ipd);
+    /// let left_projected = left.project_point(&left_eye_point);
+    /// let right_projected = right.project_point(&right_eye_point);
+    ///
+    /// assert!(relative_eq!(left_projected, right_projected, epsilon = 1e-10));
+    /// ```
+    pub fn from_stereo_eye<A: Into<Radians<S>>>(
+        eye: StereoEye, 
+        vfov: A, 
+        aspect: S, 
+        near: S, 
+        far: S, 
+        interpupillary_distance: S, 
+        convergence_distance: S) -> Perspective3<S> 
+    {
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let top = near * (vfov.into() * one_half).tan();
+        let bottom = -top;
+        let half_width = aspect * top;
+        // The horizontal shift of the frustum on the near plane that moves the 
+        // center of the frustum of the eye onto the center of the rig at the 
+        // convergence distance.
+        let shift = -eye.offset(interpupillary_distance).x * (near / convergence_distance);
+        let left = -half_width + shift;
+        let right = half_width + shift;
+
+        Perspective3::new(left, right, bottom, top, near, far)
+    }
+
+    /// Construct a copy of the perspective projection whose image is offset 
+    /// by a subpixel jitter, for example for temporal anti-aliasing.
+    ///
+    /// The jitter is given in pixels of a viewport of size `width x height`, 
+    /// with the **x-axis** pointing right and the **y-axis** pointing up, as in 
+    /// normalized device coordinates. Every projected point moves by exactly 
+    /// `(2 * jitter.x / width, 2 * jitter.y / height)` in normalized device 
+    /// coordinates, while its depth stays the same.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Perspective3,
+    /// #     Point3,
+    /// #     Vector2,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-1_f64, 1_f64, -0.5_f64, 0.5_f64, 0.1_f64, 100_f64);
+    /// let jitter = Vector2::new(0.25_f64, -0.5_f64);
+    /// let (width, height) = (1920_f64, 1080_f64);
+    /// let jittered = perspective.jittered(&jitter, width, height);
+    /// let point = Point3::new(0.3_f64, 0.2_f64, -4_f64);
+    /// let expected = perspective.project_point(&point) 
+    ///     + Vector3::new(2_f64 * jitter.x / width, 2_f64 * jitter.y / height, 0_f64);
+    /// let result = jittered.project_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn jittered(&self, jitter: &Vector2<S>, width: S, height: S) -> Perspective3<S> {
+        let shift_x = jitter.x * (self.right - self.left) / width;
+        let shift_y = jitter.y * (self.top - self.bottom) / height;
+
+        Perspective3::new(
+            self.left - shift_x,
+            self.right - shift_x,
+            self.bottom - shift_y,
+            self.top - shift_y,
+            self.near,
+            self.far
+        )
+    }
+
+    /// Construct a copy of the perspective projection jittered by the 
+    /// `index`-th subpixel offset of the Halton (2, 3) sequence for a viewport 
+    /// of size `width x height`. See [`halton_jitter`] for the offsets.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     halton_jitter,
+    /// #     Perspective3,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-1_f64, 1_f64, -0.5_f64, 0.5_f64, 0.1_f64, 100_f64);
+    /// let index = 5;
+    /// let expected = perspective.jittered(&halton_jitter(index), 1280_f64, 720_f64);
+    /// let result = perspective.jittered_halton(index, 1280_f64, 720_f64);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn jittered_halton(&self, index: usize, width: S, height: S) -> Perspective3<S> {
+        self.jittered(&halton_jitter(index), width, height)
+    }
+
+    /// Construct the projection matrix of the perspective projection with its 
+    /// near plane replaced by the camera space clipping plane `clip_plane`. 
+    /// See [`oblique_near_plane`] for the details.
+    ///
+    /// The result is a raw matrix because an oblique frustum no longer has 
+    /// the form of a `Perspective3`.
+    #[inline]
+    pub fn oblique_near_plane(&self, clip_plane: &Vector4<S>) -> Option<Matrix4x4<S>> {
+        oblique_near_plane(&self.matrix, clip_plane)
+    }
+
+    /// Decompose a perspective projection matrix into the parameters of its 
+    /// viewing frustum.
+    ///
+    /// The matrix can come from any graphics API, as long as it maps the near 
+    /// and far planes onto the depth range `depth_range` in normalized device 
+    /// coordinates. The function detects whether the matrix acts on a 
+    /// right-handed or a left-handed camera space, and returns the perspective 
+    /// projection with the same viewing frustum together with the handedness. 
+    /// The matrix may carry an arbitrary positive homogeneous scale factor. A 
+    /// projection that flips the **y-axis**, as is common with Vulkan, yields a 
+    /// frustum with `bottom > top`.
+    ///
+    /// The function returns `None` if the matrix is not a perspective 
+    /// projection matrix with the given depth range, or if its far plane lies 
+    /// at infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DepthRange,
+    /// #     Handedness,
+    /// #     Matrix4x4,
+    /// #     Perspective3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// // A left-handed Direct3D style projection with depth range `[0, 1]`.
+    /// let (near, far) = (0.5_f64, 50_f64);
+    /// let matrix = Matrix4x4::new(
+    ///     1_f64, 0_f64, 0_f64,                       0_f64,
+    ///     0_f64, 2_f64, 0_f64,                       0_f64,
+    ///     0_f64, 0_f64, far / (far - near),          1_f64,
+    ///     0_f64, 0_f64, -near * far / (far - near),  0_f64
+    /// );
+    /// let (result, handedness) = Perspective3::decompose(&matrix, DepthRange::ZeroToOne).unwrap();
+    /// let expected = Perspective3::new(-0.5_f64, 0.5_f64, -0.25_f64, 0.25_f64, near, far);
+    ///
+    /// assert_eq!(handedness, Handedness::LeftHanded);
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    ///
+    /// // The same matrix is not a reversed depth projection.
+    /// assert!(Perspective3::decompose(&matrix, DepthRange::ReversedZeroToOne).is_none());
+    /// ```
+    pub fn decompose(matrix: &Matrix4x4<S>, depth_range: DepthRange) -> Option<(Perspective3<S>, Handedness)> {
+        let zero = S::zero();
+        let one = S::one();
+        let tolerance = projection_tolerance(matrix);
+        let is_zero = |value: S| value.abs() <= tolerance;
+        let has_perspective_form = is_zero(matrix.c0r1) && is_zero(matrix.c0r2) && is_zero(matrix.c0r3)
+            && is_zero(matrix.c1r0) && is_zero(matrix.c1r2) && is_zero(matrix.c1r3)
+            && is_zero(matrix.c3r0) && is_zero(matrix.c3r1) && is_zero(matrix.c3r3);
+        let is_degenerate = is_zero(matrix.c0r0) || is_zero(matrix.c1r1)
+            || is_zero(matrix.c2r3) || is_zero(matrix.c3r2);
+        if !has_perspective_form || is_degenerate {
+            return None;
+        }
+
+        // Remove the homogeneous scale factor, so that `w == -z` in a 
+        // right-handed camera space, and `w == z` in a left-handed one.
+        let scale = matrix.c2r3.abs();
+        let sign = matrix.c2r3 / scale;
+        let handedness = if sign < zero { Handedness::RightHanded } else { Handedness::LeftHanded };
+        // In terms of the distance `d > 0` in front of the camera, the depth in 
+        // normalized device coordinates is `depth == a + b / d`.
+        let a = sign * matrix.c2r2 / scale;
+        let b = matrix.c3r2 / scale;
+        let near = b / (depth_range.near_depth::<S>() - a);
+        let far = b / (depth_range.far_depth::<S>() - a);
+        if !(near > zero && far > near && far.is_finite()) {
+            return None;
+        }
+
+        let c0r0 = matrix.c0r0 / scale;
+        let c1r1 = matrix.c1r1 / scale;
+        let c2r0 = sign * matrix.c2r0 / scale;
+        let c2r1 = sign * matrix.c2r1 / scale;
+        let left = near * (-one - c2r0) / c0r0;
+        let right = near * (one - c2r0) / c0r0;
+        let bottom = near * (-one - c2r1) / c1r1;
+        let top = near * (one - c2r1) / c1r1;
+
+        Some((Perspective3::new(left, right, bottom, top, near, far), handedness))
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for Perspective3<S> {
@@ -849,6 +1360,118 @@ where
             c3r2 * inverse_w,
         )
     }
+
+    /// Construct the general perspective projection with the same viewing 
+    /// frustum.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     PerspectiveFov3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = PerspectiveFov3::new(Degrees(72_f64), 4_f64 / 3_f64, 0.1_f64, 100_f64);
+    /// let result = perspective.to_perspective();
+    ///
+    /// assert!(relative_eq!(result.matrix(), perspective.matrix(), epsilon = 1e-10));
+    /// ```
+    pub fn to_perspective(&self) -> Perspective3<S> {
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let top = self.near * (self.vfov * one_half).tan();
+        let right = self.aspect * top;
+
+        Perspective3::new(-right, right, -top, top, self.near, self.far)
+    }
+
+    /// Construct the off-axis perspective projection with the same field of 
+    /// view for one eye of a stereo camera rig. 
+    /// See [`Perspective3::from_stereo_eye`].
+    #[inline]
+    pub fn to_stereo_eye(&self, eye: StereoEye, interpupillary_distance: S, convergence_distance: S) -> Perspective3<S> {
+        Perspective3::from_stereo_eye(
+            eye, self.vfov, self.aspect, self.near, self.far, interpupillary_distance, convergence_distance
+        )
+    }
+
+    /// Construct the perspective projection with the same viewing frustum 
+    /// offset by a subpixel jitter. A jittered frustum is no longer symmetric, 
+    /// so the result is a general perspective projection.
+    /// See [`Perspective3::jittered`].
+    #[inline]
+    pub fn jittered(&self, jitter: &Vector2<S>, width: S, height: S) -> Perspective3<S> {
+        self.to_perspective().jittered(jitter, width, height)
+    }
+
+    /// Construct the perspective projection with the same viewing frustum 
+    /// jittered by the `index`-th subpixel offset of the Halton (2, 3) sequence.
+    /// See [`Perspective3::jittered_halton`].
+    #[inline]
+    pub fn jittered_halton(&self, index: usize, width: S, height: S) -> Perspective3<S> {
+        self.to_perspective().jittered_halton(index, width, height)
+    }
+
+    /// Construct the projection matrix of the perspective projection with its 
+    /// near plane replaced by the camera space clipping plane `clip_plane`. 
+    /// See [`oblique_near_plane`] for the details.
+    ///
+    /// The result is a raw matrix because an oblique frustum no longer has 
+    /// the form of a `PerspectiveFov3`.
+    #[inline]
+    pub fn oblique_near_plane(&self, clip_plane: &Vector4<S>) -> Option<Matrix4x4<S>> {
+        oblique_near_plane(&self.matrix, clip_plane)
+    }
+
+    /// Decompose a symmetric perspective projection matrix into its vertical 
+    /// field of view, aspect ratio, and near and far planes.
+    ///
+    /// The function returns `None` under the same conditions as 
+    /// [`Perspective3::decompose`], and when the viewing frustum of the 
+    /// matrix is off-center or flipped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     DepthRange,
+    /// #     Handedness,
+    /// #     PerspectiveFov3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = PerspectiveFov3::new(Degrees(72_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64);
+    /// let (result, handedness) = PerspectiveFov3::decompose(
+    ///     perspective.matrix(), DepthRange::NegativeOneToOne
+    /// ).unwrap();
+    ///
+    /// assert_eq!(handedness, Handedness::RightHanded);
+    /// assert!(relative_eq!(result, perspective, epsilon = 1e-10));
+    /// ```
+    pub fn decompose(matrix: &Matrix4x4<S>, depth_range: DepthRange) -> Option<(PerspectiveFov3<S>, Handedness)> {
+        let (perspective, handedness) = Perspective3::decompose(matrix, depth_range)?;
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let tolerance = S::default_epsilon().sqrt();
+        let width = perspective.right - perspective.left;
+        let height = perspective.top - perspective.bottom;
+        let is_centered = (perspective.right + perspective.left).abs() <= tolerance * width.abs()
+            && (perspective.top + perspective.bottom).abs() <= tolerance * height.abs();
+        if !is_centered || width <= zero || height <= zero {
+            return None;
+        }
+
+        let vfov = Radians(two * (height / (two * perspective.near)).atan());
+        let aspect = width / height;
+
+        Some((PerspectiveFov3::new(vfov, aspect, perspective.near, perspective.far), handedness))
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for PerspectiveFov3<S> {
@@ -1056,13 +1679,13 @@ where
     /// Get the left plane along the **negative x-axis**.
     #[inline]
     pub fn left_x(&self)-> S {
-        self.near
+        self.left
     }
 
     /// Get the right plane along the **positive x-axis**.
     #[inline]
     pub fn right_x(&self) -> S {
-        self.far
+        self.right
     }
 
     /// Get the bottom plane along the **negative y-axis**.
@@ -1253,6 +1876,133 @@ where
 
         Vector3::new(c0r0 * vector.x, c1r1 * vector.y, c2r2 * vector.z)
     }
+
+    /// Construct a copy of the orthographic projection whose image is offset 
+    /// by a subpixel jitter, for example for temporal anti-aliasing.
+    ///
+    /// The jitter is given in pixels of a viewport of size `width x height`, 
+    /// with the **x-axis** pointing right and the **y-axis** pointing up, as in 
+    /// normalized device coordinates. Every projected point moves by exactly 
+    /// `(2 * jitter.x / width, 2 * jitter.y / height)` in normalized device 
+    /// coordinates, while its depth stays the same.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Orthographic3,
+    /// #     Point3,
+    /// #     Vector2,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let orthographic = Orthographic3::new(-4_f64, 4_f64, -3_f64, 3_f64, 0.1_f64, 100_f64);
+    /// let jitter = Vector2::new(-0.125_f64, 0.375_f64);
+    /// let (width, height) = (800_f64, 600_f64);
+    /// let jittered = orthographic.jittered(&jitter, width, height);
+    /// let point = Point3::new(1_f64, -2_f64, -10_f64);
+    /// let expected = orthographic.project_point(&point) 
+    ///     + Vector3::new(2_f64 * jitter.x / width, 2_f64 * jitter.y / height, 0_f64);
+    /// let result = jittered.project_point(&point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    pub fn jittered(&self, jitter: &Vector2<S>, width: S, height: S) -> Orthographic3<S> {
+        let shift_x = jitter.x * (self.right - self.left) / width;
+        let shift_y = jitter.y * (self.top - self.bottom) / height;
+
+        Orthographic3::new(
+            self.left - shift_x,
+            self.right - shift_x,
+            self.bottom - shift_y,
+            self.top - shift_y,
+            self.near,
+            self.far
+        )
+    }
+
+    /// Construct a copy of the orthographic projection jittered by the 
+    /// `index`-th subpixel offset of the Halton (2, 3) sequence for a viewport 
+    /// of size `width x height`. See [`halton_jitter`] for the offsets.
+    #[inline]
+    pub fn jittered_halton(&self, index: usize, width: S, height: S) -> Orthographic3<S> {
+        self.jittered(&halton_jitter(index), width, height)
+    }
+
+    /// Decompose an orthographic projection matrix into the parameters of its 
+    /// viewing volume.
+    ///
+    /// The matrix can come from any graphics API, as long as it maps the near 
+    /// and far planes onto the depth range `depth_range` in normalized device 
+    /// coordinates. The function detects whether the matrix acts on a 
+    /// right-handed or a left-handed camera space, and returns the orthographic 
+    /// projection with the same viewing volume together with the handedness. 
+    /// The matrix may carry an arbitrary positive homogeneous scale factor.
+    ///
+    /// The function returns `None` if the matrix is not an orthographic 
+    /// projection matrix.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     DepthRange,
+    /// #     Handedness,
+    /// #     Orthographic3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let orthographic = Orthographic3::new(-4_f64, 2_f64, -1_f64, 3_f64, 0.5_f64, 20_f64);
+    /// let (result, handedness) = Orthographic3::decompose(
+    ///     orthographic.matrix(), DepthRange::NegativeOneToOne
+    /// ).unwrap();
+    ///
+    /// assert_eq!(handedness, Handedness::RightHanded);
+    /// assert!(relative_eq!(result, orthographic, epsilon = 1e-10));
+    /// ```
+    pub fn decompose(matrix: &Matrix4x4<S>, depth_range: DepthRange) -> Option<(Orthographic3<S>, Handedness)> {
+        let zero = S::zero();
+        let one = S::one();
+        let tolerance = projection_tolerance(matrix);
+        let is_zero = |value: S| value.abs() <= tolerance;
+        let has_orthographic_form = is_zero(matrix.c0r1) && is_zero(matrix.c0r2) && is_zero(matrix.c0r3)
+            && is_zero(matrix.c1r0) && is_zero(matrix.c1r2) && is_zero(matrix.c1r3)
+            && is_zero(matrix.c2r0) && is_zero(matrix.c2r1) && is_zero(matrix.c2r3);
+        let is_degenerate = is_zero(matrix.c0r0) || is_zero(matrix.c1r1) 
+            || is_zero(matrix.c2r2) || matrix.c3r3 <= tolerance;
+        if !has_orthographic_form || is_degenerate {
+            return None;
+        }
+
+        // Remove the homogeneous scale factor, so that `w == 1`.
+        let scale = matrix.c3r3;
+        let c0r0 = matrix.c0r0 / scale;
+        let c1r1 = matrix.c1r1 / scale;
+        let c2r2 = matrix.c2r2 / scale;
+        let c3r0 = matrix.c3r0 / scale;
+        let c3r1 = matrix.c3r1 / scale;
+        let c3r2 = matrix.c3r2 / scale;
+        // The depth increases from the near plane to the far plane with the 
+        // distance in front of the camera, which lies along the negative z-axis 
+        // in a right-handed camera space.
+        let near_depth = depth_range.near_depth::<S>();
+        let far_depth = depth_range.far_depth::<S>();
+        let sign = if c2r2 * (far_depth - near_depth) > zero { one } else { -one };
+        let handedness = if sign < zero { Handedness::RightHanded } else { Handedness::LeftHanded };
+        let near = (near_depth - c3r2) / (sign * c2r2);
+        let far = (far_depth - c3r2) / (sign * c2r2);
+        let left = (-one - c3r0) / c0r0;
+        let right = (one - c3r0) / c0r0;
+        let bottom = (-one - c3r1) / c1r1;
+        let top = (one - c3r1) / c1r1;
+
+        Some((Orthographic3::new(left, right, bottom, top, near, far), handedness))
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for Orthographic3<S> {
@@ -1664,6 +2414,58 @@ where
 
         Vector3::new(c0r0 * vector.x, c1r1 * vector.y, c2r2 * vector.z)
     }
+
+    /// Construct the general orthographic projection with the same viewing 
+    /// volume.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     OrthographicFov3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let orthographic = OrthographicFov3::new(Degrees(90_f64), 4_f64 / 3_f64, 0.1_f64, 100_f64);
+    /// let result = orthographic.to_orthographic();
+    ///
+    /// assert!(relative_eq!(result.matrix(), orthographic.matrix(), epsilon = 1e-10));
+    /// ```
+    pub fn to_orthographic(&self) -> Orthographic3<S> {
+        // This matches the viewing volume of `Matrix4x4::from_orthographic_fov`.
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let width = self.far * (self.vfov * one_half).tan();
+        let height = width / self.aspect;
+
+        Orthographic3::new(
+            -width * one_half, 
+            width * one_half, 
+            -height * one_half, 
+            height * one_half, 
+            self.near, 
+            self.far
+        )
+    }
+
+    /// Construct the orthographic projection with the same viewing volume 
+    /// offset by a subpixel jitter. A jittered viewing volume is no longer 
+    /// symmetric, so the result is a general orthographic projection.
+    /// See [`Orthographic3::jittered`].
+    #[inline]
+    pub fn jittered(&self, jitter: &Vector2<S>, width: S, height: S) -> Orthographic3<S> {
+        self.to_orthographic().jittered(jitter, width, height)
+    }
+
+    /// Construct the orthographic projection with the same viewing volume 
+    /// jittered by the `index`-th subpixel offset of the Halton (2, 3) sequence.
+    /// See [`Orthographic3::jittered_halton`].
+    #[inline]
+    pub fn jittered_halton(&self, index: usize, width: S, height: S) -> Orthographic3<S> {
+        self.to_orthographic().jittered_halton(index, width, height)
+    }
 }
 
 impl<S> AsRef<Matrix4x4<S>> for OrthographicFov3<S> {
@@ -1793,3 +2595,78 @@ where
     }
 }
 
+
+/// A projection from camera space into normalized device coordinates.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     Projection3,
+/// #     Orthographic3,
+/// #     Perspective3,
+/// #     Point3,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// fn round_trip<P>(projection: &P, point: &Point3<f64>) -> Point3<f64>
+/// where
+///     P: Projection3<Scalar = f64>
+/// {
+///     projection.unproject_point(&projection.project_point(point))
+/// }
+///
+/// let point = Point3::new(1_f64, -2_f64, -5_f64);
+/// let perspective = Perspective3::new(-1_f64, 1_f64, -1_f64, 1_f64, 0.1_f64, 100_f64);
+/// let orthographic = Orthographic3::new(-4_f64, 4_f64, -3_f64, 3_f64, 0.1_f64, 100_f64);
+///
+/// assert!(relative_eq!(round_trip(&perspective, &point), point, epsilon = 1e-10));
+/// assert!(relative_eq!(round_trip(&orthographic, &point), point, epsilon = 1e-10));
+/// ```
+pub trait Projection3 {
+    /// The scalar type of the projection.
+    type Scalar: ScalarFloat;
+
+    /// Get the matrix that implements the projection.
+    fn matrix(&self) -> &Matrix4x4<Self::Scalar>;
+
+    /// Project a point in camera space onto normalized device coordinates.
+    fn project_point(&self, point: &Point3<Self::Scalar>) -> Point3<Self::Scalar>;
+
+    /// Unproject a point in normalized device coordinates back into camera space.
+    fn unproject_point(&self, point: &Point3<Self::Scalar>) -> Point3<Self::Scalar>;
+}
+
+macro_rules! impl_projection3 {
+    ($Type:ident) => {
+        impl<S> Projection3 for $Type<S> 
+        where 
+            S: ScalarFloat 
+        {
+            type Scalar = S;
+
+            #[inline]
+            fn matrix(&self) -> &Matrix4x4<S> {
+                $Type::matrix(self)
+            }
+
+            #[inline]
+            fn project_point(&self, point: &Point3<S>) -> Point3<S> {
+                $Type::project_point(self, point)
+            }
+
+            #[inline]
+            fn unproject_point(&self, point: &Point3<S>) -> Point3<S> {
+                $Type::unproject_point(self, point)
+            }
+        }
+    }
+}
+
+impl_projection3!(Perspective3);
+impl_projection3!(PerspectiveFov3);
+impl_projection3!(Orthographic3);
+impl_projection3!(OrthographicFov3);
+
@@ -0,0 +1,1274 @@
+use crate::base::{
+    Magnitude,
+    Scalar,
+    ScalarFloat,
+};
+use crate::matrix::{
+    Matrix3x3,
+    Matrix4x4,
+};
+use crate::vector::{
+    Vector3,
+    Vector4,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
+use crate::affine::{
+    Affine2,
+    Affine3,
+};
+use crate::isometry::{
+    Isometry2,
+    Isometry3,
+};
+use crate::projection::{
+    Orthographic3,
+    OrthographicFov3,
+    Perspective3,
+    PerspectiveFov3,
+};
+use crate::reflection::{
+    Reflection2,
+    Reflection3,
+};
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+use crate::scale::{
+    Scale2,
+    Scale3,
+};
+use crate::shear::{
+    Shear2,
+    Shear3,
+};
+use crate::similarity::{
+    Similarity2,
+    Similarity3,
+};
+use crate::transform::{
+    Transform2,
+    Transform3,
+};
+use crate::translation::{
+    Translation2,
+    Translation3,
+};
+
+use crate::registration;
+
+use core::fmt;
+use core::ops;
+
+
+/// A two-dimensional projective transformation.
+///
+/// A two-dimensional projective transformation, or homography, is a
+/// transformation whose homogeneous matrix can have an arbitrary last row.
+/// Homographies describe the mapping between two images of the same plane
+/// taken by pinhole cameras, the perspective distortion of a rectangle into an
+/// arbitrary quadrilateral, and its rectification. A projective transformation
+/// can move points to infinity, so transforming a point returns an `Option`,
+/// which is `None` exactly when the image of the point lies at infinity.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Projective2<S> {
+    /// The homogeneous matrix of the transformation.
+    matrix: Matrix3x3<S>,
+}
+
+impl<S> Projective2<S> 
+where 
+    S: Scalar 
+{
+    /// Construct a projective transformation from a homogeneous matrix.
+    #[inline]
+    pub fn from_matrix(matrix: Matrix3x3<S>) -> Projective2<S> {
+        Projective2 {
+            matrix: matrix,
+        }
+    }
+
+    /// Get a reference to the homogeneous matrix of the transformation.
+    #[inline]
+    pub fn matrix(&self) -> &Matrix3x3<S> {
+        &self.matrix
+    }
+
+    /// Construct the identity transformation.
+    #[inline]
+    pub fn identity() -> Projective2<S> {
+        Projective2 {
+            matrix: Matrix3x3::identity(),
+        }
+    }
+
+    /// Determine whether the transformation is affine, i.e. whether the last
+    /// row of its homogeneous matrix is exactly `[0 0 1]`.
+    #[inline]
+    pub fn is_affine(&self) -> bool {
+        self.matrix.c0r2.is_zero() && self.matrix.c1r2.is_zero() && self.matrix.c2r2.is_one()
+    }
+
+    /// Convert the transformation into an affine transformation if its
+    /// homogeneous matrix has a last row of `[0 0 1]`.
+    #[inline]
+    pub fn to_affine(&self) -> Option<Affine2<S>> {
+        Affine2::try_from_affine_matrix(&self.matrix)
+    }
+
+    /// Convert a projective transformation into a generic transformation.
+    #[inline]
+    pub fn to_transform(&self) -> Transform2<S> {
+        Transform2::from_matrix_unchecked(self.matrix)
+    }
+
+    /// Apply the transformation to a vector in homogeneous coordinates.
+    ///
+    /// No perspective division takes place.
+    #[inline]
+    pub fn transform_homogeneous(&self, vector: &Vector3<S>) -> Vector3<S> {
+        self.matrix * vector
+    }
+}
+
+impl<S> Projective2<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Estimate the homography mapping the `source` points to the
+    /// corresponding `target` points using the normalized direct linear
+    /// transformation algorithm.
+    ///
+    /// At least four correspondences are needed. With more than four
+    /// correspondences, the homography is the least squares solution of the
+    /// linear equations in its entries. The returned matrix is scaled so that
+    /// its bottom right entry is one, unless that entry is zero.
+    ///
+    /// The function returns `None` if there are fewer than four
+    /// correspondences, if the point slices have different lengths, or if the
+    /// points are in a degenerate configuration, such as three of four points
+    /// being collinear.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Matrix3x3,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1.2_f64,  0.1_f64, 0.001_f64,
+    ///     -0.2_f64, 0.9_f64, 0.002_f64,
+    ///     30_f64,   -5_f64,  1_f64
+    /// );
+    /// let projective = Projective2::from_matrix(matrix);
+    /// let source = [
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(100_f64, 0_f64),
+    ///     Point2::new(100_f64, 80_f64),
+    ///     Point2::new(0_f64, 80_f64),
+    ///     Point2::new(40_f64, 30_f64),
+    /// ];
+    /// let target = source.map(|point| projective.transform_point(&point).unwrap());
+    /// let result = Projective2::from_point_correspondences(&source, &target).unwrap();
+    ///
+    /// assert!(relative_eq!(result, projective, epsilon = 1e-8));
+    /// ```
+    #[inline]
+    pub fn from_point_correspondences(source: &[Point2<S>], target: &[Point2<S>]) -> Option<Projective2<S>> {
+        registration::estimate_homography(source, target).map(Projective2::from_matrix)
+    }
+
+    /// Construct the projective transformation mapping the unit square onto
+    /// the quadrilateral `quad`.
+    ///
+    /// The corners `(0, 0)`, `(1, 0)`, `(1, 1)`, and `(0, 1)` of the unit square
+    /// map to the corners `quad[0]`, `quad[1]`, `quad[2]`, and `quad[3]`
+    /// respectively. The function returns `None` if the quadrilateral is
+    /// degenerate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let quad = [
+    ///     Point2::new(1_f64, 1_f64),
+    ///     Point2::new(5_f64, 2_f64),
+    ///     Point2::new(4_f64, 6_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    /// ];
+    /// let projective = Projective2::from_square_to_quad(&quad).unwrap();
+    /// let square = [
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(1_f64, 0_f64),
+    ///     Point2::new(1_f64, 1_f64),
+    ///     Point2::new(0_f64, 1_f64),
+    /// ];
+    ///
+    /// for (corner, expected) in square.iter().zip(quad.iter()) {
+    ///     let result = projective.transform_point(corner).unwrap();
+    ///     assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// }
+    /// ```
+    pub fn from_square_to_quad(quad: &[Point2<S>; 4]) -> Option<Projective2<S>> {
+        let [p0, p1, p2, p3] = *quad;
+        let sum_x = p0.x - p1.x + p2.x - p3.x;
+        let sum_y = p0.y - p1.y + p2.y - p3.y;
+        let dx1 = p1.x - p2.x;
+        let dx2 = p3.x - p2.x;
+        let dy1 = p1.y - p2.y;
+        let dy2 = p3.y - p2.y;
+        let denominator = dx1 * dy2 - dx2 * dy1;
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let g = (sum_x * dy2 - dx2 * sum_y) / denominator;
+        let h = (dx1 * sum_y - sum_x * dy1) / denominator;
+        let a = p1.x - p0.x + g * p1.x;
+        let b = p3.x - p0.x + h * p3.x;
+        let c = p0.x;
+        let d = p1.y - p0.y + g * p1.y;
+        let e = p3.y - p0.y + h * p3.y;
+        let f = p0.y;
+        let matrix = Matrix3x3::new(
+            a, d, g,
+            b, e, h,
+            c, f, S::one()
+        );
+
+        Some(Projective2::from_matrix(matrix))
+    }
+
+    /// Construct the projective transformation mapping the quadrilateral
+    /// `quad` onto the unit square.
+    ///
+    /// This is the inverse of [`Projective2::from_square_to_quad`]. It is
+    /// useful for rectifying a perspective distorted rectangle, such as a
+    /// photographed page or a projection surface. The function returns `None`
+    /// if the quadrilateral is degenerate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let quad = [
+    ///     Point2::new(1_f64, 1_f64),
+    ///     Point2::new(5_f64, 2_f64),
+    ///     Point2::new(4_f64, 6_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    /// ];
+    /// let projective = Projective2::from_quad_to_square(&quad).unwrap();
+    /// let expected = Point2::new(1_f64, 1_f64);
+    /// let result = projective.transform_point(&quad[2]).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_quad_to_square(quad: &[Point2<S>; 4]) -> Option<Projective2<S>> {
+        Self::from_square_to_quad(quad).and_then(|projective| projective.inverse())
+    }
+
+    /// Construct the projective transformation mapping the quadrilateral
+    /// `source` onto the quadrilateral `target`, corner by corner.
+    ///
+    /// The function returns `None` if either quadrilateral is degenerate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Point2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let source = [
+    ///     Point2::new(1_f64, 1_f64),
+    ///     Point2::new(5_f64, 2_f64),
+    ///     Point2::new(4_f64, 6_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    /// ];
+    /// let target = [
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(1920_f64, 0_f64),
+    ///     Point2::new(1920_f64, 1080_f64),
+    ///     Point2::new(0_f64, 1080_f64),
+    /// ];
+    /// let projective = Projective2::from_quad_to_quad(&source, &target).unwrap();
+    ///
+    /// for (corner, expected) in source.iter().zip(target.iter()) {
+    ///     let result = projective.transform_point(corner).unwrap();
+    ///     assert!(relative_eq!(result, expected, epsilon = 1e-8));
+    /// }
+    /// ```
+    #[inline]
+    pub fn from_quad_to_quad(source: &[Point2<S>; 4], target: &[Point2<S>; 4]) -> Option<Projective2<S>> {
+        let source_to_square = Self::from_quad_to_square(source)?;
+        let square_to_target = Self::from_square_to_quad(target)?;
+
+        Some(square_to_target * source_to_square)
+    }
+
+    /// Apply the transformation to a point.
+    ///
+    /// The point is lifted into homogeneous coordinates, transformed, and then
+    /// divided by its homogeneous `z` coordinate. If the image of the point has
+    /// `z == 0`, the image lies at infinity and the function returns `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Matrix3x3,
+    /// #     Point2,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 0_f64, 1_f64,
+    ///     0_f64, 1_f64, 0_f64,
+    ///     0_f64, 0_f64, 1_f64
+    /// );
+    /// let projective = Projective2::from_matrix(matrix);
+    /// let point = Point2::new(1_f64, 2_f64);
+    /// let expected = Point2::new(1_f64 / 2_f64, 2_f64 / 2_f64);
+    ///
+    /// assert_eq!(projective.transform_point(&point), Some(expected));
+    ///
+    /// // A point on the line `x == -1` maps to infinity.
+    /// let point_at_infinity = Point2::new(-1_f64, 3_f64);
+    ///
+    /// assert!(projective.transform_point(&point_at_infinity).is_none());
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Point2<S>) -> Option<Point2<S>> {
+        Point2::from_homogeneous(self.matrix * point.to_homogeneous())
+    }
+
+    /// Compute the inverse of the transformation if it exists.
+    #[inline]
+    pub fn inverse(&self) -> Option<Projective2<S>> {
+        self.matrix.inverse().map(Projective2::from_matrix)
+    }
+
+    /// Determine whether the transformation is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.matrix.is_invertible()
+    }
+
+    /// Apply the inverse of the transformation to a point.
+    ///
+    /// The function returns `None` if the transformation is not invertible, or
+    /// if the preimage of the point lies at infinity.
+    #[inline]
+    pub fn inverse_transform_point(&self, point: &Point2<S>) -> Option<Point2<S>> {
+        self.inverse()
+            .and_then(|projective_inv| projective_inv.transform_point(point))
+    }
+
+    /// Apply the transformation to a surface normal at a point.
+    ///
+    /// A projective transformation does not preserve parallel lines, so the 
+    /// image of a normal depends on where it is attached. The normal and the 
+    /// point define the line through the point perpendicular to the normal. 
+    /// In homogeneous coordinates, the line is a covector, which transforms by 
+    /// the inverse transpose of the matrix of the transformation. The result 
+    /// is the unit normal of the image line. For affine transformations, this 
+    /// agrees with the inverse transpose rule of [`Affine2::transform_normal`] 
+    /// at every point.
+    ///
+    /// The function returns `None` if the transformation is not invertible, or 
+    /// if the image of the line is the line at infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Matrix3x3,
+    /// #     Normal2,
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     1_f64, 0_f64, 1_f64,
+    ///     0_f64, 1_f64, 0_f64,
+    ///     0_f64, 0_f64, 1_f64
+    /// );
+    /// let projective = Projective2::from_matrix(matrix);
+    /// // The line `y == 1` maps to the line `x + y == 1`.
+    /// let point = Point2::new(0_f64, 1_f64);
+    /// let normal = Normal2::unit_y();
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let result = projective.transform_normal(&point, &normal).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, point: &Point2<S>, normal: &Normal2<S>) -> Option<Normal2<S>> {
+        let matrix_inv = self.matrix.inverse()?;
+        let line = normal.as_vector().extend(-normal.dot(&point.to_vector()));
+        let transformed_line = matrix_inv.transpose() * line;
+
+        Normal2::try_from_vector(&transformed_line.contract(), S::zero())
+    }
+
+    /// Decompose a homography induced by a plane into the pose of the plane
+    /// relative to a camera with intrinsic matrix `intrinsics`.
+    ///
+    /// The homography is assumed to map points `(x, y)` on the plane `z == 0`
+    /// of the plane coordinate system to image points of a pinhole camera
+    /// looking down the **positive z-axis**, so that it is proportional to
+    /// `intrinsics * [r0 r1 t]`, where `r0` and `r1` are the first two columns
+    /// of the rotation from plane coordinates to camera coordinates and `t` is
+    /// the translation. The overall sign of a homography is arbitrary, so the
+    /// sign is chosen such that the plane lies in front of the camera. The
+    /// opposite sign describes a plane behind the camera, which the camera
+    /// cannot observe, so the physically valid pose is unique. The rotation is
+    /// orthonormalized, so the decomposition tolerates small amounts of noise
+    /// in the homography.
+    ///
+    /// The function returns `None` if the intrinsic matrix is not invertible
+    /// or the homography is degenerate.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective2,
+    /// #     Degrees,
+    /// #     Matrix3x3,
+    /// #     Rotation3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let intrinsics = Matrix3x3::new(
+    ///     800_f64, 0_f64,   0_f64,
+    ///     0_f64,   800_f64, 0_f64,
+    ///     320_f64, 240_f64, 1_f64
+    /// );
+    /// let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 0.5_f64));
+    /// let rotation = Rotation3::from_axis_angle(&axis, Degrees(25_f64));
+    /// let translation = Vector3::new(0.1_f64, -0.2_f64, 3_f64);
+    /// let rotation_matrix = rotation.matrix();
+    /// let plane_to_camera = Matrix3x3::from_columns(
+    ///     rotation_matrix.column(0),
+    ///     rotation_matrix.column(1),
+    ///     translation
+    /// );
+    /// let projective = Projective2::from_matrix(intrinsics * plane_to_camera * 2_f64);
+    /// let (result_rotation, result_translation) = projective.decompose_planar_pose(&intrinsics).unwrap();
+    ///
+    /// assert!(relative_eq!(result_rotation, rotation, epsilon = 1e-10));
+    /// assert!(relative_eq!(result_translation, translation, epsilon = 1e-10));
+    /// ```
+    pub fn decompose_planar_pose(&self, intrinsics: &Matrix3x3<S>) -> Option<(Rotation3<S>, Vector3<S>)> {
+        let intrinsics_inv = intrinsics.inverse()?;
+        let matrix = intrinsics_inv * self.matrix;
+        let column0 = Vector3::new(matrix.c0r0, matrix.c0r1, matrix.c0r2);
+        let column1 = Vector3::new(matrix.c1r0, matrix.c1r1, matrix.c1r2);
+        let column2 = Vector3::new(matrix.c2r0, matrix.c2r1, matrix.c2r2);
+        let magnitude_sum = column0.magnitude() + column1.magnitude();
+        if magnitude_sum.is_zero() {
+            return None;
+        }
+
+        let one = S::one();
+        let scale = if column2.z < S::zero() {
+            -(one + one) / magnitude_sum
+        } else {
+            (one + one) / magnitude_sum
+        };
+        let r0 = column0 * scale;
+        let r1 = column1 * scale;
+        let r2 = r0.cross(&r1);
+        let translation = column2 * scale;
+        let (rotation, _) = registration::rotation_from_covariance([
+            [r0.x, r0.y, r0.z],
+            [r1.x, r1.y, r1.z],
+            [r2.x, r2.y, r2.z],
+        ]);
+
+        Some((rotation, translation))
+    }
+}
+
+impl<S> AsRef<Matrix3x3<S>> for Projective2<S> {
+    #[inline]
+    fn as_ref(&self) -> &Matrix3x3<S> {
+        &self.matrix
+    }
+}
+
+impl<S> fmt::Display for Projective2<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Projective2 [{}]",
+            self.matrix
+        )
+    }
+}
+
+impl<S> From<Projective2<S>> for Matrix3x3<S> 
+where 
+    S: Copy 
+{
+    #[inline]
+    fn from(projective: Projective2<S>) -> Matrix3x3<S> {
+        projective.matrix
+    }
+}
+
+impl<S> From<Projective2<S>> for Transform2<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(projective: Projective2<S>) -> Transform2<S> {
+        projective.to_transform()
+    }
+}
+
+impl<S> From<Transform2<S>> for Projective2<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(transform: Transform2<S>) -> Projective2<S> {
+        Projective2::from_matrix(*transform.matrix())
+    }
+}
+
+impl<S> From<&Transform2<S>> for Projective2<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(transform: &Transform2<S>) -> Projective2<S> {
+        Projective2::from_matrix(*transform.matrix())
+    }
+}
+
+impl<S> From<Affine2<S>> for Projective2<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: Affine2<S>) -> Projective2<S> {
+        Projective2::from_matrix(affine.to_affine_matrix())
+    }
+}
+
+impl<S> From<&Affine2<S>> for Projective2<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: &Affine2<S>) -> Projective2<S> {
+        Projective2::from_matrix(affine.to_affine_matrix())
+    }
+}
+
+macro_rules! impl_projective2_from_transformation {
+    ($($Type:ident),* $(,)*) => {
+        $(
+            impl<S> From<$Type<S>> for Projective2<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: $Type<S>) -> Projective2<S> {
+                    Projective2::from_matrix(*transformation.to_transform().matrix())
+                }
+            }
+
+            impl<S> From<&$Type<S>> for Projective2<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: &$Type<S>) -> Projective2<S> {
+                    Projective2::from_matrix(*transformation.to_transform().matrix())
+                }
+            }
+        )*
+    }
+}
+
+impl_projective2_from_transformation!(
+    Translation2, Rotation2, Scale2, Shear2, Reflection2, Isometry2, Similarity2,
+);
+
+impl<S> approx::AbsDiffEq for Projective2<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Matrix3x3::abs_diff_eq(&self.matrix, &other.matrix, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Projective2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Matrix3x3::relative_eq(&self.matrix, &other.matrix, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Projective2<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Matrix3x3::ulps_eq(&self.matrix, &other.matrix, epsilon, max_ulps)
+    }
+}
+
+
+/// A three-dimensional projective transformation.
+///
+/// A projective transformation is a transformation whose homogeneous matrix 
+/// can have an arbitrary last row. Unlike an affine transformation, a 
+/// projective transformation can move points to infinity: a point whose 
+/// image has a homogeneous `w` coordinate of zero has no finite image. 
+/// Perspective projections are the most common projective transformations.
+/// For this reason, transforming a point with a projective transformation 
+/// returns an `Option`, which is `None` exactly when the image of the point 
+/// lies at infinity.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Projective3<S> {
+    /// The homogeneous matrix of the transformation.
+    matrix: Matrix4x4<S>,
+}
+
+impl<S> Projective3<S> 
+where 
+    S: Scalar 
+{
+    /// Construct a projective transformation from a homogeneous matrix.
+    #[inline]
+    pub fn from_matrix(matrix: Matrix4x4<S>) -> Projective3<S> {
+        Projective3 {
+            matrix: matrix,
+        }
+    }
+
+    /// Get a reference to the homogeneous matrix of the transformation.
+    #[inline]
+    pub fn matrix(&self) -> &Matrix4x4<S> {
+        &self.matrix
+    }
+
+    /// Construct the identity transformation.
+    #[inline]
+    pub fn identity() -> Projective3<S> {
+        Projective3 {
+            matrix: Matrix4x4::identity(),
+        }
+    }
+
+    /// Determine whether the transformation is affine, i.e. whether the last 
+    /// row of its homogeneous matrix is exactly `[0 0 0 1]`.
+    #[inline]
+    pub fn is_affine(&self) -> bool {
+        self.matrix.c0r3.is_zero() && self.matrix.c1r3.is_zero() && 
+        self.matrix.c2r3.is_zero() && self.matrix.c3r3.is_one()
+    }
+
+    /// Convert the transformation into an affine transformation if its 
+    /// homogeneous matrix has a last row of `[0 0 0 1]`.
+    #[inline]
+    pub fn to_affine(&self) -> Option<Affine3<S>> {
+        Affine3::try_from_affine_matrix(&self.matrix)
+    }
+
+    /// Convert a projective transformation into a generic transformation.
+    #[inline]
+    pub fn to_transform(&self) -> Transform3<S> {
+        Transform3::from_matrix_unchecked(self.matrix)
+    }
+
+    /// Apply the transformation to a vector in homogeneous coordinates.
+    ///
+    /// No perspective division takes place.
+    #[inline]
+    pub fn transform_homogeneous(&self, vector: &Vector4<S>) -> Vector4<S> {
+        self.matrix * vector
+    }
+}
+
+impl<S> Projective3<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Apply the transformation to a point.
+    ///
+    /// The point is lifted into homogeneous coordinates, transformed, and then 
+    /// divided by its homogeneous `w` coordinate. If the image of the point 
+    /// has `w == 0`, the image lies at infinity and the function returns 
+    /// `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective3,
+    /// #     Perspective3,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+    /// let projective = Projective3::from(perspective);
+    /// let point = Point3::new(-1_f64, -1_f64, -4_f64);
+    /// let expected = perspective.project_point(&point);
+    /// let result = projective.transform_point(&point).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    ///
+    /// // A point in the plane of the eye maps to infinity.
+    /// let point_at_eye = Point3::new(1_f64, 1_f64, 0_f64);
+    ///
+    /// assert!(projective.transform_point(&point_at_eye).is_none());
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: &Point3<S>) -> Option<Point3<S>> {
+        Point3::from_homogeneous(self.matrix * point.to_homogeneous())
+    }
+
+    /// Compute the inverse of the transformation if it exists.
+    #[inline]
+    pub fn inverse(&self) -> Option<Projective3<S>> {
+        self.matrix.inverse().map(Projective3::from_matrix)
+    }
+
+    /// Determine whether the transformation is invertible.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.matrix.is_invertible()
+    }
+
+    /// Apply the inverse of the transformation to a point.
+    ///
+    /// The function returns `None` if the transformation is not invertible, or 
+    /// if the preimage of the point lies at infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective3,
+    /// #     PerspectiveFov3,
+    /// #     Degrees,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = PerspectiveFov3::new(Degrees(72_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64);
+    /// let projective = Projective3::from(perspective);
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let projected_point = projective.transform_point(&point).unwrap();
+    /// let result = projective.inverse_transform_point(&projected_point).unwrap();
+    ///
+    /// assert!(relative_eq!(result, point, epsilon = 1e-8));
+    /// ```
+    #[inline]
+    pub fn inverse_transform_point(&self, point: &Point3<S>) -> Option<Point3<S>> {
+        self.inverse()
+            .and_then(|projective_inv| projective_inv.transform_point(point))
+    }
+
+    /// Apply the transformation to a surface normal at a point.
+    ///
+    /// A projective transformation does not preserve parallel planes, so the 
+    /// image of a normal depends on where it is attached. The normal and the 
+    /// point define the plane through the point perpendicular to the normal. 
+    /// In homogeneous coordinates, the plane is a covector, which transforms 
+    /// by the inverse transpose of the matrix of the transformation. The 
+    /// result is the unit normal of the image plane. For affine 
+    /// transformations, this agrees with the inverse transpose rule of 
+    /// [`Affine3::transform_normal`] at every point.
+    ///
+    /// The function returns `None` if the transformation is not invertible, or 
+    /// if the image of the plane is the plane at infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Projective3,
+    /// #     Perspective3,
+    /// #     Normal3,
+    /// #     Point3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+    /// let projective = Projective3::from(perspective);
+    /// // A plane facing the eye maps to a plane of constant depth facing the 
+    /// // near plane.
+    /// let point = Point3::new(1_f64, 2_f64, -5_f64);
+    /// let normal = Normal3::unit_z();
+    /// let expected = -Normal3::unit_z();
+    /// let result = projective.transform_normal(&point, &normal).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, point: &Point3<S>, normal: &Normal3<S>) -> Option<Normal3<S>> {
+        let matrix_inv = self.matrix.inverse()?;
+        let plane = normal.as_vector().extend(-normal.dot(&point.to_vector()));
+        let transformed_plane = matrix_inv.transpose() * plane;
+
+        Normal3::try_from_vector(&transformed_plane.contract(), S::zero())
+    }
+}
+
+impl<S> AsRef<Matrix4x4<S>> for Projective3<S> {
+    #[inline]
+    fn as_ref(&self) -> &Matrix4x4<S> {
+        &self.matrix
+    }
+}
+
+impl<S> fmt::Display for Projective3<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Projective3 [{}]",
+            self.matrix
+        )
+    }
+}
+
+impl<S> From<Projective3<S>> for Matrix4x4<S> 
+where 
+    S: Copy 
+{
+    #[inline]
+    fn from(projective: Projective3<S>) -> Matrix4x4<S> {
+        projective.matrix
+    }
+}
+
+impl<S> From<Projective3<S>> for Transform3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(projective: Projective3<S>) -> Transform3<S> {
+        projective.to_transform()
+    }
+}
+
+impl<S> From<Transform3<S>> for Projective3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(transform: Transform3<S>) -> Projective3<S> {
+        Projective3::from_matrix(*transform.matrix())
+    }
+}
+
+impl<S> From<&Transform3<S>> for Projective3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(transform: &Transform3<S>) -> Projective3<S> {
+        Projective3::from_matrix(*transform.matrix())
+    }
+}
+
+impl<S> From<Affine3<S>> for Projective3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: Affine3<S>) -> Projective3<S> {
+        Projective3::from_matrix(affine.to_affine_matrix())
+    }
+}
+
+impl<S> From<&Affine3<S>> for Projective3<S> 
+where 
+    S: Scalar 
+{
+    #[inline]
+    fn from(affine: &Affine3<S>) -> Projective3<S> {
+        Projective3::from_matrix(affine.to_affine_matrix())
+    }
+}
+
+macro_rules! impl_projective3_from_projection {
+    ($($Type:ident),* $(,)*) => {
+        $(
+            impl<S> From<$Type<S>> for Projective3<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(projection: $Type<S>) -> Projective3<S> {
+                    Projective3::from_matrix(*projection.matrix())
+                }
+            }
+
+            impl<S> From<&$Type<S>> for Projective3<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(projection: &$Type<S>) -> Projective3<S> {
+                    Projective3::from_matrix(*projection.matrix())
+                }
+            }
+        )*
+    }
+}
+
+impl_projective3_from_projection!(Perspective3, PerspectiveFov3, Orthographic3, OrthographicFov3);
+
+macro_rules! impl_projective3_from_transformation {
+    ($($Type:ident),* $(,)*) => {
+        $(
+            impl<S> From<$Type<S>> for Projective3<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: $Type<S>) -> Projective3<S> {
+                    Projective3::from_matrix(*transformation.to_transform().matrix())
+                }
+            }
+
+            impl<S> From<&$Type<S>> for Projective3<S> 
+            where 
+                S: ScalarFloat 
+            {
+                #[inline]
+                fn from(transformation: &$Type<S>) -> Projective3<S> {
+                    Projective3::from_matrix(*transformation.to_transform().matrix())
+                }
+            }
+        )*
+    }
+}
+
+impl_projective3_from_transformation!(
+    Translation3, Rotation3, Scale3, Shear3, Reflection3, Isometry3, Similarity3,
+);
+
+impl<S> approx::AbsDiffEq for Projective3<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Matrix4x4::abs_diff_eq(&self.matrix, &other.matrix, epsilon)
+    }
+}
+
+impl<S> approx::RelativeEq for Projective3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Matrix4x4::relative_eq(&self.matrix, &other.matrix, epsilon, max_relative)
+    }
+}
+
+impl<S> approx::UlpsEq for Projective3<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Matrix4x4::ulps_eq(&self.matrix, &other.matrix, epsilon, max_ulps)
+    }
+}
+
+impl<S> ops::Mul<Vector3<S>> for Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn mul(self, other: Vector3<S>) -> Self::Output {
+        self.transform_homogeneous(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector3<S>> for Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector3<S>) -> Self::Output {
+        self.transform_homogeneous(other)
+    }
+}
+
+impl<S> ops::Mul<Vector3<S>> for &Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn mul(self, other: Vector3<S>) -> Self::Output {
+        self.transform_homogeneous(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector3<S>> for &Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector3<S>) -> Self::Output {
+        self.transform_homogeneous(other)
+    }
+}
+
+impl<S> ops::Mul<Projective2<S>> for Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Projective2<S>;
+
+    #[inline]
+    fn mul(self, other: Projective2<S>) -> Self::Output {
+        Projective2::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<&Projective2<S>> for Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Projective2<S>;
+
+    #[inline]
+    fn mul(self, other: &Projective2<S>) -> Self::Output {
+        Projective2::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<Projective2<S>> for &Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Projective2<S>;
+
+    #[inline]
+    fn mul(self, other: Projective2<S>) -> Self::Output {
+        Projective2::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<&Projective2<S>> for &Projective2<S>
+where
+    S: Scalar
+{
+    type Output = Projective2<S>;
+
+    #[inline]
+    fn mul(self, other: &Projective2<S>) -> Self::Output {
+        Projective2::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<Vector4<S>> for Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Vector4<S>;
+
+    #[inline]
+    fn mul(self, other: Vector4<S>) -> Self::Output {
+        self.transform_homogeneous(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector4<S>> for Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Vector4<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector4<S>) -> Self::Output {
+        self.transform_homogeneous(other)
+    }
+}
+
+impl<S> ops::Mul<Vector4<S>> for &Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Vector4<S>;
+
+    #[inline]
+    fn mul(self, other: Vector4<S>) -> Self::Output {
+        self.transform_homogeneous(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector4<S>> for &Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Vector4<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector4<S>) -> Self::Output {
+        self.transform_homogeneous(other)
+    }
+}
+
+impl<S> ops::Mul<Projective3<S>> for Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Projective3<S>;
+
+    #[inline]
+    fn mul(self, other: Projective3<S>) -> Self::Output {
+        Projective3::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<&Projective3<S>> for Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Projective3<S>;
+
+    #[inline]
+    fn mul(self, other: &Projective3<S>) -> Self::Output {
+        Projective3::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<Projective3<S>> for &Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Projective3<S>;
+
+    #[inline]
+    fn mul(self, other: Projective3<S>) -> Self::Output {
+        Projective3::from_matrix(self.matrix * other.matrix)
+    }
+}
+
+impl<S> ops::Mul<&Projective3<S>> for &Projective3<S>
+where
+    S: Scalar
+{
+    type Output = Projective3<S>;
+
+    #[inline]
+    fn mul(self, other: &Projective3<S>) -> Self::Output {
+        Projective3::from_matrix(self.matrix * other.matrix)
+    }
+}
@@ -3,6 +3,8 @@ use crate::base::{
     Scalar,
     ScalarSigned,
     ScalarFloat,
+    fast_rsqrt,
+    use_fast_math,
     Unit,
 };
 use crate::angle::{
@@ -16,6 +18,9 @@ use crate::matrix::{
 use crate::vector::{
     Vector3,
 };
+use crate::normal::{
+    Normal3,
+};
 
 use num_traits::NumCast;
 use core::fmt;
@@ -1537,6 +1542,42 @@ where
         Self::from(&Matrix3x3::face_towards(direction, up).transpose())
     }
 
+    /// Rotate a surface normal with a quaternion rotor.
+    ///
+    /// The normal is rotated as a pure quaternion by computing `q * n * q*`. 
+    /// A nonzero quaternion that is not a unit quaternion also scales the 
+    /// normal uniformly, which does not change its direction, so the result 
+    /// is renormalized. The quaternion must be nonzero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Quaternion,
+    /// #     Degrees,
+    /// #     Normal3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let quaternion = Quaternion::from_axis_angle(&axis, Degrees(90_f64)) * 2_f64;
+    /// let normal = Normal3::unit_x();
+    /// let expected = Normal3::unit_y();
+    /// let result = quaternion.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        let rotated = self * Quaternion::from_pure(*normal.as_vector()) * self.conjugate();
+
+        Normal3::from_vector(&rotated.v)
+    }
+
     /// Linearly interpolate between two quaternions.
     ///
     /// ## Example
@@ -1861,6 +1902,63 @@ where
 
         Quaternion::from_parts(scalar, vector)
     }
+
+    /// Compute the swing-twist decomposition of a unit quaternion about
+    /// the axis `axis`.
+    ///
+    /// Every rotation `q` can be factored into a rotation about the axis
+    /// `axis` (the twist), followed by a rotation about an axis perpendicular
+    /// to `axis` (the swing), so that
+    /// ```text
+    /// q == swing * twist
+    /// ```
+    /// The twist is the normalized projection of `q` onto the plane spanned
+    /// by the real axis and `axis`. When `q` rotates `axis` by half a turn,
+    /// this projection vanishes and the twist is not well defined. In this
+    /// case the function returns the identity for the twist, and the swing
+    /// is all of `q`.
+    ///
+    /// The function returns the pair `(swing, twist)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Quaternion,
+    /// #     Degrees,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis_x = Unit::from_value(Vector3::unit_x());
+    /// let axis_y = Unit::from_value(Vector3::unit_y());
+    /// let expected_swing = Quaternion::from_axis_angle(&axis_x, Degrees(30_f64));
+    /// let expected_twist = Quaternion::from_axis_angle(&axis_y, Degrees(70_f64));
+    /// let quaternion = expected_swing * expected_twist;
+    /// let (swing, twist) = quaternion.swing_twist_decomposition(&axis_y);
+    ///
+    /// assert!(relative_eq!(swing, expected_swing, epsilon = 1e-10));
+    /// assert!(relative_eq!(twist, expected_twist, epsilon = 1e-10));
+    /// assert!(relative_eq!(swing * twist, quaternion, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn swing_twist_decomposition(
+        &self, axis: &Unit<Vector3<S>>) -> (Quaternion<S>, Quaternion<S>) {
+
+        let unit_axis = axis.as_ref();
+        let projected_vector = unit_axis * self.v.dot(unit_axis);
+        let projected = Quaternion::from_parts(self.s, projected_vector);
+        let twist = match Unit::try_from_value(projected, S::default_epsilon()) {
+            Some(unit_twist) => unit_twist.into_inner(),
+            None => Quaternion::identity(),
+        };
+        let swing = self * twist.conjugate();
+
+        (swing, twist)
+    }
 }
 
 impl<S> AsRef<[S; 4]> for Quaternion<S> {
@@ -2574,7 +2672,11 @@ where
 
     #[inline]
     fn normalize(&self) -> Self {
-        self / self.magnitude()
+        if use_fast_math::<S>() {
+            self * fast_rsqrt(self.magnitude_squared())
+        } else {
+            self / self.magnitude()
+        }
     }
 
     #[inline]
@@ -18,6 +18,10 @@ use crate::transform::{
     Transform2,
     Transform3,
 };
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -75,9 +79,11 @@ where
         &self.matrix
     }
 
-    /* FIXME: Can we calculate an inverse reflection?
     /// Calculate the inverse reflection transformation.
     ///
+    /// A reflection is its own inverse: reflecting twice across the same 
+    /// line returns every point to where it started.
+    ///
     /// ## Example
     ///
     /// ```
@@ -86,6 +92,9 @@ where
     /// #     Vector2,
     /// #     Unit,
     /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
     /// #
     /// let normal: Unit<Vector2<f64>> = Unit::from_value(Vector2::new(
     ///     -1_f64 / 2_f64, 
@@ -95,45 +104,16 @@ where
     /// let reflection = Reflection2::from_normal_bias(&normal, &bias);
     /// let reflection_inv = reflection.inverse();
     /// let vector = Vector2::new(1_f64, 1_f64);
-    /// let expected = vector; // Vector2::new(7_f64 / 5_f64, 1_f64 / 5_f64);
+    /// let expected = vector;
     /// let reflected_vector = reflection.reflect_vector(&vector);
     /// let result = reflection_inv.reflect_vector(&reflected_vector);
     ///
-    /// assert_eq!(result, expected);
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
     /// ```
     #[inline]
     pub fn inverse(&self) -> Reflection2<S> {
-        let zero = S::zero();
-        let one = S::one();
-        let two = one + one;
-        let normal = self.normal;
-        let inverse_det = one / (one - two * normal.x * normal.x - two * normal.y * normal.y);
-
-        let c0r0 = one - two * normal.y * normal.y;
-        let c0r1 = two * normal.x * normal.y;
-        let c0r2 = zero;
-        
-        let c1r0 = two * normal.x * normal.y;
-        let c1r1 = one - two * normal.x * normal.x - two * normal.y * normal.y;
-        let c1r2 = zero;
-        
-        let c2r0 = zero;
-        let c2r1 = zero;
-        let c2r2 = one;
-
-        let matrix = Matrix3x3::new(
-            c0r0, c0r1, c0r2,                                   
-            c1r0, c1r1, c1r2, 
-            c2r0, c2r1, c2r2
-        );
-
-        Reflection2 {
-            bias: self.bias,
-            normal: normal, 
-            matrix: matrix * inverse_det 
-        }
+        *self
     }
-    */
 
     /// Reflect a vector across a line described by the reflection 
     /// transformation.
@@ -167,6 +147,37 @@ where
         (self.matrix * vector.extend(S::zero())).contract()
     }
 
+    /// Apply the reflection to a normal vector.
+    ///
+    /// A reflection matrix is its own inverse transpose, so normals reflect the 
+    /// same way as ordinary vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Reflection2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// #     Unit,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let reflection_normal = Unit::from_value(Vector2::unit_y());
+    /// let reflection = Reflection2::from_normal_bias(&reflection_normal, &Vector2::zero());
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, -1_f64));
+    /// let result = reflection.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        Normal2::from_vector_unchecked(self.reflect_vector(normal.as_vector()))
+    }
+
     /// Reflect a point across a line described by the reflection 
     /// transformation.
     ///
@@ -422,51 +433,39 @@ where
         self.normal
     }
 
-    /* FIXME: Can we calculate an inverse reflection?
     /// Calculate the inverse reflection transformation.
+    ///
+    /// A reflection is its own inverse: reflecting twice across the same 
+    /// plane returns every point to where it started.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Reflection3,
+    /// #     Point3,
+    /// #     Vector3,
+    /// #     Unit,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let normal: Unit<Vector3<f64>> = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+    /// let bias = Vector3::new(0_f64, 0_f64, 1_f64);
+    /// let reflection = Reflection3::from_normal_bias(&normal, &bias);
+    /// let reflection_inv = reflection.inverse();
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = point;
+    /// let reflected_point = reflection.reflect_point(&point);
+    /// let result = reflection_inv.reflect_point(&reflected_point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
     #[inline]
     pub fn inverse(&self) -> Reflection3<S> {
-        let zero = S::zero();
-        let one = S::one();
-        let two = one + one;
-        let normal = self.normal;
-        let inverse_det = one / (one - two * normal.x * normal.x - two * normal.y * normal.y - two * normal.z * normal.z);
-
-        let c0r0 = one - two * normal.y * normal.y - normal.z * normal.z;
-        let c0r1 = two * normal.x * normal.y;
-        let c0r2 = two * normal.x * normal.z;
-        let c0r3 = zero;
-
-        let c1r0 = two * normal.x * normal.y;
-        let c1r1 = one - two * normal.x * normal.x - two * normal.z * normal.z;
-        let c1r2 = two * normal.y * normal.z;
-        let c1r3 = zero;
-
-        let c2r0 = two * normal.x * normal.z;
-        let c2r1 = two * normal.y * normal.z;
-        let c2r2 = one - two * normal.x * normal.x - two * normal.y * normal.y;
-        let c2r3 = zero;
-
-        let c3r0 = zero;
-        let c3r1 = zero;
-        let c3r2 = zero;
-        let c3r3 = one;
-
-
-        let matrix = Matrix4x4::new(
-            c0r0, c0r1, c0r2, c0r3,
-            c1r0, c1r1, c1r2, c1r3,
-            c2r0, c2r1, c2r2, c2r3,
-            c3r0, c3r1, c3r2, c3r3
-        );
-
-        Reflection3 { 
-            bias: self.bias,
-            normal: normal, 
-            matrix: matrix * inverse_det,
-        }
+        *self
     }
-    */
 
     /// Reflect a vector across the plane described by the reflection 
     /// transformation.
@@ -494,6 +493,37 @@ where
         (self.matrix * vector.extend(S::zero())).contract()
     }
 
+    /// Apply the reflection to a normal vector.
+    ///
+    /// A reflection matrix is its own inverse transpose, so normals reflect the 
+    /// same way as ordinary vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Reflection3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// #     Unit,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let reflection_normal = Unit::from_value(Vector3::unit_z());
+    /// let reflection = Reflection3::from_normal_bias(&reflection_normal, &Vector3::zero());
+    /// let normal = Normal3::from_vector(&Vector3::new(0_f64, 1_f64, 1_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(0_f64, 1_f64, -1_f64));
+    /// let result = reflection.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        Normal3::from_vector_unchecked(self.reflect_vector(normal.as_vector()))
+    }
+
     /// Reflect a point across the plane described by the reflection 
     /// transformation.
     ///
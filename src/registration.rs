@@ -0,0 +1,412 @@
+use crate::base::{
+    Magnitude,
+    ScalarFloat,
+};
+use crate::angle::{
+    Radians,
+};
+use crate::matrix::{
+    Matrix3x3,
+};
+use crate::vector::{
+    Vector2,
+    Vector3,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::quaternion::{
+    Quaternion,
+};
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+
+
+/// The maximum number of sweeps the Jacobi eigenvalue iteration performs.
+const MAX_JACOBI_SWEEPS: usize = 64;
+
+
+/// The optimal rotation between two centered point sets in two dimensions,
+/// along with the statistics needed to complete a rigid or similarity alignment.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct PointAlignment2<S> {
+    /// The centroid of the source points.
+    pub(crate) source_centroid: Point2<S>,
+    /// The centroid of the target points.
+    pub(crate) target_centroid: Point2<S>,
+    /// The rotation best aligning the centered source points to the centered
+    /// target points.
+    pub(crate) rotation: Rotation2<S>,
+    /// The correlation `sum(dot(q_i, R * p_i))` of the centered points under the
+    /// optimal rotation `R`.
+    pub(crate) correlation: S,
+    /// The sum of the squared distances of the source points from their centroid.
+    pub(crate) source_spread: S,
+}
+
+/// The optimal rotation between two centered point sets in three dimensions,
+/// along with the statistics needed to complete a rigid or similarity alignment.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct PointAlignment3<S> {
+    /// The centroid of the source points.
+    pub(crate) source_centroid: Point3<S>,
+    /// The centroid of the target points.
+    pub(crate) target_centroid: Point3<S>,
+    /// The rotation best aligning the centered source points to the centered
+    /// target points.
+    pub(crate) rotation: Rotation3<S>,
+    /// The correlation `sum(dot(q_i, R * p_i))` of the centered points under the
+    /// optimal rotation `R`.
+    pub(crate) correlation: S,
+    /// The sum of the squared distances of the source points from their centroid.
+    pub(crate) source_spread: S,
+}
+
+/// Compute the centroid of a nonempty slice of points.
+fn centroid2<S>(points: &[Point2<S>]) -> Point2<S>
+where
+    S: ScalarFloat
+{
+    let count: S = num_traits::cast(points.len()).unwrap();
+    let sum = points.iter().fold(Vector2::zero(), |sum, point| sum + point.to_vector());
+
+    Point2::from_vector(sum / count)
+}
+
+/// Compute the centroid of a nonempty slice of points.
+fn centroid3<S>(points: &[Point3<S>]) -> Point3<S>
+where
+    S: ScalarFloat
+{
+    let count: S = num_traits::cast(points.len()).unwrap();
+    let sum = points.iter().fold(Vector3::zero(), |sum, point| sum + point.to_vector());
+
+    Point3::from_vector(sum / count)
+}
+
+/// Find the rotation that best aligns the centered `source` points with the
+/// centered `target` points in the least squares sense.
+///
+/// In two dimensions the optimal rotation angle has the closed form
+/// `atan2(sum(cross(p_i, q_i)), sum(dot(p_i, q_i)))`.
+///
+/// The function returns `None` if the slices are empty or have different lengths.
+pub(crate) fn align_points2<S>(source: &[Point2<S>], target: &[Point2<S>]) -> Option<PointAlignment2<S>>
+where
+    S: ScalarFloat
+{
+    if source.is_empty() || source.len() != target.len() {
+        return None;
+    }
+
+    let source_centroid = centroid2(source);
+    let target_centroid = centroid2(target);
+    let mut sum_dot = S::zero();
+    let mut sum_cross = S::zero();
+    let mut source_spread = S::zero();
+    for (source_point, target_point) in source.iter().zip(target.iter()) {
+        let p = source_point - source_centroid;
+        let q = target_point - target_centroid;
+        sum_dot += p.dot(&q);
+        sum_cross += p.x * q.y - p.y * q.x;
+        source_spread += p.dot(&p);
+    }
+
+    let rotation = Rotation2::from_angle(Radians(sum_cross.atan2(sum_dot)));
+    let correlation = sum_dot.hypot(sum_cross);
+
+    Some(PointAlignment2 {
+        source_centroid: source_centroid,
+        target_centroid: target_centroid,
+        rotation: rotation,
+        correlation: correlation,
+        source_spread: source_spread,
+    })
+}
+
+/// Find the rotation that best aligns the centered `source` points with the
+/// centered `target` points in the least squares sense.
+///
+/// The optimal rotation maximizes the correlation with the cross-covariance
+/// matrix `sum(p_i * transpose(q_i))` of the centered point sets.
+///
+/// The function returns `None` if the slices are empty or have different lengths.
+pub(crate) fn align_points3<S>(source: &[Point3<S>], target: &[Point3<S>]) -> Option<PointAlignment3<S>>
+where
+    S: ScalarFloat
+{
+    if source.is_empty() || source.len() != target.len() {
+        return None;
+    }
+
+    let source_centroid = centroid3(source);
+    let target_centroid = centroid3(target);
+    let mut covariance = [[S::zero(); 3]; 3];
+    let mut source_spread = S::zero();
+    for (source_point, target_point) in source.iter().zip(target.iter()) {
+        let p = source_point - source_centroid;
+        let q = target_point - target_centroid;
+        for (row, p_i) in covariance.iter_mut().zip([p.x, p.y, p.z]) {
+            for (entry, q_j) in row.iter_mut().zip([q.x, q.y, q.z]) {
+                *entry += p_i * q_j;
+            }
+        }
+        source_spread += p.dot(&p);
+    }
+
+    let (rotation, correlation) = rotation_from_covariance(covariance);
+
+    Some(PointAlignment3 {
+        source_centroid: source_centroid,
+        target_centroid: target_centroid,
+        rotation: rotation,
+        correlation: correlation,
+        source_spread: source_spread,
+    })
+}
+
+/// Find the rotation `R` maximizing the correlation `trace(R * covariance)`,
+/// where `covariance` is given in row-major order.
+///
+/// The function uses Horn's closed form solution with unit quaternions. The
+/// optimal rotation is the unit quaternion eigenvector belonging to the largest
+/// eigenvalue of a symmetric 4x4 matrix built from the covariance matrix, and
+/// that eigenvalue is the maximal correlation. Unlike the singular value
+/// decomposition formulation of the Kabsch algorithm, the quaternion formulation
+/// always yields a proper rotation, so no reflection correction is needed.
+pub(crate) fn rotation_from_covariance<S>(covariance: [[S; 3]; 3]) -> (Rotation3<S>, S)
+where
+    S: ScalarFloat
+{
+    let [[s_xx, s_xy, s_xz], [s_yx, s_yy, s_yz], [s_zx, s_zy, s_zz]] = covariance;
+    let horn_matrix = [
+        [s_xx + s_yy + s_zz, s_yz - s_zy,         s_zx - s_xz,         s_xy - s_yx        ],
+        [s_yz - s_zy,         s_xx - s_yy - s_zz, s_xy + s_yx,         s_zx + s_xz        ],
+        [s_zx - s_xz,         s_xy + s_yx,        -s_xx + s_yy - s_zz, s_yz + s_zy        ],
+        [s_xy - s_yx,         s_zx + s_xz,         s_yz + s_zy,        -s_xx - s_yy + s_zz],
+    ];
+    let (eigenvalues, eigenvectors) = symmetric_eigen(horn_matrix);
+    let mut largest = 0;
+    for (i, eigenvalue) in eigenvalues.iter().enumerate() {
+        if *eigenvalue > eigenvalues[largest] {
+            largest = i;
+        }
+    }
+    let quaternion = Quaternion::new(
+        eigenvectors[0][largest],
+        eigenvectors[1][largest],
+        eigenvectors[2][largest],
+        eigenvectors[3][largest]
+    );
+    let rotation = Rotation3::from_quaternion(&quaternion.normalize());
+
+    (rotation, eigenvalues[largest])
+}
+
+/// Compute the similarity transformation that moves the centroid of a point set
+/// to the origin and scales the points to have mean distance `sqrt(2)` from the
+/// origin.
+///
+/// The function returns `None` if all the points coincide.
+fn normalizing_transform2<S>(points: &[Point2<S>]) -> Option<Matrix3x3<S>>
+where
+    S: ScalarFloat
+{
+    let count: S = num_traits::cast(points.len()).unwrap();
+    let centroid = centroid2(points);
+    let mean_distance = points.iter()
+        .fold(S::zero(), |sum, point| sum + (point - centroid).magnitude()) / count;
+    if mean_distance <= S::epsilon() {
+        return None;
+    }
+
+    let zero = S::zero();
+    let one = S::one();
+    let scale = (one + one).sqrt() / mean_distance;
+
+    Some(Matrix3x3::new(
+        scale,               zero,                zero,
+        zero,                scale,               zero,
+        -scale * centroid.x, -scale * centroid.y, one
+    ))
+}
+
+/// Estimate the homography mapping the `source` points to the corresponding
+/// `target` points in the least squares sense using the normalized direct
+/// linear transformation algorithm.
+///
+/// Both point sets are first normalized for numerical conditioning. Each
+/// correspondence contributes two linear equations in the nine entries of the
+/// homography, and the solution is the eigenvector of the normal matrix of the
+/// equations belonging to its smallest eigenvalue. The result is scaled so that
+/// its bottom right entry is one whenever that entry is nonzero.
+///
+/// The function returns `None` if there are fewer than four correspondences,
+/// the slices have different lengths, or the points are in a degenerate
+/// configuration, such as too many of them being collinear.
+pub(crate) fn estimate_homography<S>(source: &[Point2<S>], target: &[Point2<S>]) -> Option<Matrix3x3<S>>
+where
+    S: ScalarFloat
+{
+    if source.len() < 4 || source.len() != target.len() {
+        return None;
+    }
+
+    let source_normalization = normalizing_transform2(source)?;
+    let target_normalization = normalizing_transform2(target)?;
+    let zero = S::zero();
+    let one = S::one();
+    let mut normal_matrix = [[zero; 9]; 9];
+    for (source_point, target_point) in source.iter().zip(target.iter()) {
+        let p = source_normalization * source_point.to_homogeneous();
+        let q = target_normalization * target_point.to_homogeneous();
+        let equations = [
+            [-p.x, -p.y, -one, zero, zero, zero, q.x * p.x, q.x * p.y, q.x],
+            [zero, zero, zero, -p.x, -p.y, -one, q.y * p.x, q.y * p.y, q.y],
+        ];
+        for equation in equations.iter() {
+            for (normal_row, equation_i) in normal_matrix.iter_mut().zip(equation.iter()) {
+                for (entry, equation_j) in normal_row.iter_mut().zip(equation.iter()) {
+                    *entry += *equation_i * *equation_j;
+                }
+            }
+        }
+    }
+
+    let (eigenvalues, eigenvectors) = symmetric_eigen(normal_matrix);
+    let mut order = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    order.sort_unstable_by(|&i, &j| eigenvalues[i].partial_cmp(&eigenvalues[j]).unwrap_or(core::cmp::Ordering::Equal));
+    let smallest = order[0];
+    let second_smallest = order[1];
+    let largest = order[8];
+    if eigenvalues[second_smallest] <= S::default_epsilon().sqrt() * eigenvalues[largest] {
+        return None;
+    }
+
+    let h = |k: usize| eigenvectors[k][smallest];
+    let normalized_homography = Matrix3x3::new(
+        h(0), h(3), h(6),
+        h(1), h(4), h(7),
+        h(2), h(5), h(8)
+    );
+    let homography = target_normalization.inverse()? * normalized_homography * source_normalization;
+    if homography.c2r2.is_zero() {
+        Some(homography)
+    } else {
+        Some(homography / homography.c2r2)
+    }
+}
+
+/// Compute the root mean square distance between the images of the `source`
+/// points under `transform` and the `target` points.
+pub(crate) fn rms_residual2<S, F>(source: &[Point2<S>], target: &[Point2<S>], transform: F) -> S
+where
+    S: ScalarFloat,
+    F: Fn(&Point2<S>) -> Point2<S>
+{
+    let count: S = num_traits::cast(source.len()).unwrap();
+    let sum_squares = source.iter()
+        .zip(target.iter())
+        .fold(S::zero(), |sum, (source_point, target_point)| {
+            let residual = transform(source_point) - target_point;
+            sum + residual.dot(&residual)
+        });
+
+    (sum_squares / count).sqrt()
+}
+
+/// Compute the root mean square distance between the images of the `source`
+/// points under `transform` and the `target` points.
+pub(crate) fn rms_residual3<S, F>(source: &[Point3<S>], target: &[Point3<S>], transform: F) -> S
+where
+    S: ScalarFloat,
+    F: Fn(&Point3<S>) -> Point3<S>
+{
+    let count: S = num_traits::cast(source.len()).unwrap();
+    let sum_squares = source.iter()
+        .zip(target.iter())
+        .fold(S::zero(), |sum, (source_point, target_point)| {
+            let residual = transform(source_point) - target_point;
+            sum + residual.dot(&residual)
+        });
+
+    (sum_squares / count).sqrt()
+}
+
+/// Compute the eigenvalues and eigenvectors of a symmetric matrix using the
+/// cyclic Jacobi eigenvalue algorithm.
+///
+/// The matrix is given in row-major order. The function returns the eigenvalues
+/// and a matrix whose columns are the corresponding orthonormal eigenvectors.
+pub(crate) fn symmetric_eigen<S, const N: usize>(matrix: [[S; N]; N]) -> ([S; N], [[S; N]; N])
+where
+    S: ScalarFloat
+{
+    let zero = S::zero();
+    let one = S::one();
+    let two = one + one;
+    let mut a = matrix;
+    let mut v = [[zero; N]; N];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = one;
+    }
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut off_diagonal = zero;
+        let mut total = zero;
+        for (p, row) in a.iter().enumerate() {
+            for (q, entry) in row.iter().enumerate() {
+                total += *entry * *entry;
+                if p != q {
+                    off_diagonal += *entry * *entry;
+                }
+            }
+        }
+        if off_diagonal <= S::epsilon() * S::epsilon() * total {
+            break;
+        }
+
+        for p in 0..N {
+            for q in (p + 1)..N {
+                if a[p][q] == zero {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let sign = if theta >= zero { one } else { -one };
+                let t = sign / (theta.abs() + (theta * theta + one).sqrt());
+                let c = one / (t * t + one).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let a_kp = row[p];
+                    let a_kq = row[q];
+                    row[p] = c * a_kp - s * a_kq;
+                    row[q] = s * a_kp + c * a_kq;
+                }
+                let (rows_head, rows_tail) = a.split_at_mut(q);
+                for (a_pk, a_qk) in rows_head[p].iter_mut().zip(rows_tail[0].iter_mut()) {
+                    let a_pk_old = *a_pk;
+                    let a_qk_old = *a_qk;
+                    *a_pk = c * a_pk_old - s * a_qk_old;
+                    *a_qk = s * a_pk_old + c * a_qk_old;
+                }
+                for row in v.iter_mut() {
+                    let v_kp = row[p];
+                    let v_kq = row[q];
+                    row[p] = c * v_kp - s * v_kq;
+                    row[q] = s * v_kp + c * v_kq;
+                }
+            }
+        }
+    }
+
+    let mut eigenvalues = [zero; N];
+    for (i, eigenvalue) in eigenvalues.iter_mut().enumerate() {
+        *eigenvalue = a[i][i];
+    }
+
+    (eigenvalues, v)
+}
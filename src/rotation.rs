@@ -22,10 +22,17 @@ use crate::vector::{
 use crate::quaternion::{
     Quaternion,
 };
+use crate::complex::{
+    Complex,
+};
 use crate::transform::{
     Transform2,
     Transform3,
 };
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -117,6 +124,34 @@ where
         }
     }
 
+    /// Construct a two-dimensional rotation from a unit complex number.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Rotation2,
+    /// #     UnitComplex,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq, 
+    /// # };
+    /// #
+    /// let angle = Degrees(45_f64);
+    /// let unit_complex = UnitComplex::from_angle(angle);
+    /// let expected = Rotation2::from_angle(angle);
+    /// let result = Rotation2::from_unit_complex(&unit_complex);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn from_unit_complex(unit_complex: &Unit<Complex<S>>) -> Rotation2<S> {
+        Rotation2 {
+            matrix: unit_complex.to_matrix(),
+        }
+    }
+
     /// Construct a rotation that rotates the shortest angular distance 
     /// between two unit vectors.
     ///
@@ -332,6 +367,36 @@ where
         inverse.matrix * vector
     }
 
+    /// Apply the rotation to a normal vector.
+    ///
+    /// The inverse transpose of a rotation matrix is the rotation matrix itself, 
+    /// so normals rotate the same way as ordinary vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Rotation2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation = Rotation2::from_angle(Degrees(90_f64));
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 0_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(0_f64, 1_f64));
+    /// let result = rotation.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        Normal2::from_vector_unchecked(self.rotate_vector(normal.as_vector()))
+    }
+
     /// Apply the inverse rotation operation to a point.
     ///
     /// ## Example
@@ -1187,6 +1252,36 @@ where
         inverse.matrix * vector
     }
 
+    /// Apply the rotation to a normal vector.
+    ///
+    /// The inverse transpose of a rotation matrix is the rotation matrix itself, 
+    /// so normals rotate the same way as ordinary vectors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Rotation3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// #     Degrees,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let rotation = Rotation3::from_angle_z(Degrees(90_f64));
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 0_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(0_f64, 1_f64, 0_f64));
+    /// let result = rotation.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        Normal3::from_vector_unchecked(self.rotate_vector(normal.as_vector()))
+    }
+
     /// Apply the inverse of the rotation to a point.
     ///
     /// ## Example
@@ -1255,6 +1350,84 @@ where
     pub fn to_transform(&self) -> Transform3<S> {
         Transform3::from_specialized(self.matrix)
     }
+
+    /// Compute the swing-twist decomposition of a rotation about the
+    /// axis `axis`.
+    ///
+    /// The rotation factors into a rotation about `axis` (the twist),
+    /// followed by a rotation about an axis perpendicular to `axis`
+    /// (the swing), so that `rotation == swing * twist`. If the rotation
+    /// turns `axis` by half a turn, the twist is not well defined, and the
+    /// function returns the identity for the twist. The function returns
+    /// the pair `(swing, twist)`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Rotation3,
+    /// #     Degrees,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis_x = Unit::from_value(Vector3::unit_x());
+    /// let axis_z = Unit::from_value(Vector3::unit_z());
+    /// let expected_swing = Rotation3::from_axis_angle(&axis_x, Degrees(45_f64));
+    /// let expected_twist = Rotation3::from_axis_angle(&axis_z, Degrees(-60_f64));
+    /// let rotation = expected_swing * expected_twist;
+    /// let (swing, twist) = rotation.swing_twist_decomposition(&axis_z);
+    ///
+    /// assert!(relative_eq!(swing, expected_swing, epsilon = 1e-10));
+    /// assert!(relative_eq!(twist, expected_twist, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn swing_twist_decomposition(
+        &self, axis: &Unit<Vector3<S>>) -> (Rotation3<S>, Rotation3<S>) {
+
+        let quaternion = Quaternion::from(*self);
+        let (swing, twist) = quaternion.swing_twist_decomposition(axis);
+
+        (Rotation3::from_quaternion(&swing), Rotation3::from_quaternion(&twist))
+    }
+
+    /// Spherically linearly interpolate between two rotations.
+    ///
+    /// The interpolation proceeds at constant angular speed along the 
+    /// shortest arc between the two rotations.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Rotation3,
+    /// #     Degrees,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let rotation1 = Rotation3::from_axis_angle(&axis, Degrees(30_f64));
+    /// let rotation2 = Rotation3::from_axis_angle(&axis, Degrees(150_f64));
+    /// let expected = Rotation3::from_axis_angle(&axis, Degrees(90_f64));
+    /// let result = rotation1.slerp(&rotation2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn slerp(&self, other: &Rotation3<S>, amount: S) -> Rotation3<S> {
+        let quaternion1 = Quaternion::from(*self);
+        let quaternion2 = Quaternion::from(*other);
+        let result = quaternion1.slerp(&quaternion2, amount);
+
+        Rotation3::from_quaternion(&result)
+    }
 }
 
 impl<S> fmt::Display for Rotation3<S> 
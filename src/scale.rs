@@ -18,6 +18,10 @@ use crate::transform::{
     Transform2,
     Transform3,
 };
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -177,6 +181,36 @@ where
         )
     }
 
+    /// Apply the scale transformation to a normal vector.
+    ///
+    /// Normals transform by the inverse transpose of the scale matrix, which is 
+    /// the inverse scale. The result is renormalized. Under a non-uniform scale, 
+    /// this differs from scaling the normal like an ordinary vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Scale2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let scale = Scale2::from_nonuniform_scale(2_f64, 1_f64);
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, 2_f64));
+    /// let result = scale.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        Normal2::from_vector(&self.inverse_scale_vector(normal.as_vector()))
+    }
+
     /// Apply the inverse transformation of the scale transformation to a point.
     ///
     /// ## Example
@@ -622,6 +656,36 @@ where
         )
     }
 
+    /// Apply the scale transformation to a normal vector.
+    ///
+    /// Normals transform by the inverse transpose of the scale matrix, which is 
+    /// the inverse scale. The result is renormalized. Under a non-uniform scale, 
+    /// this differs from scaling the normal like an ordinary vector.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Scale3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let scale = Scale3::from_nonuniform_scale(2_f64, 1_f64, 1_f64);
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(1_f64, 2_f64, 0_f64));
+    /// let result = scale.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        Normal3::from_vector(&self.inverse_scale_vector(normal.as_vector()))
+    }
+
     /// Apply the inverse transformation of the scale transformation to a point.
     ///
     /// Construct a scale transformation that scales each coordinate by the 
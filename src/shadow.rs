@@ -0,0 +1,340 @@
+use crate::base::{
+    ScalarFloat,
+    Unit,
+};
+use crate::angle::{
+    Angle,
+};
+use crate::matrix::{
+    Matrix4x4,
+};
+use crate::point::{
+    Point3,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::isometry::{
+    Isometry3,
+};
+use crate::rotation::{
+    Rotation3,
+};
+use crate::translation::{
+    Translation3,
+};
+use crate::projection::{
+    Orthographic3,
+    PerspectiveFov3,
+};
+
+use core::fmt;
+
+
+/// One cascade of a cascaded shadow map for a directional light.
+///
+/// A cascade covers the slice of the viewing frustum of a camera between the
+/// distances `near_z` and `far_z` along the viewing direction. It consists of
+/// a light view transformation that maps world space into light space, where
+/// the light shines down the **negative z-axis**, and an orthographic
+/// projection whose viewing volume encloses the frustum slice.
+///
+/// The viewing volume is fitted to the smallest sphere that encloses the
+/// frustum slice rather than to the frustum slice itself. The sphere does not
+/// change when the camera rotates, so the size of a shadow map texel in world
+/// space stays fixed. The light view is snapped to whole texels, so the shadow
+/// map texels stay fixed in world space as the camera moves. Together they
+/// remove the shimmering of shadow edges that refitting every frame causes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShadowCascade<S> {
+    /// The distance along the viewing direction of the camera of the near
+    /// end of the frustum slice.
+    near: S,
+    /// The distance along the viewing direction of the camera of the far
+    /// end of the frustum slice.
+    far: S,
+    /// The transformation from world space into light space.
+    light_view: Isometry3<S>,
+    /// The orthographic projection of light space onto the shadow map.
+    projection: Orthographic3<S>,
+}
+
+impl<S> ShadowCascade<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Compute the distance ranges of `N` cascades covering the distances
+    /// from `near` to `far` along the viewing direction of a camera, using the
+    /// practical split scheme.
+    ///
+    /// The practical split scheme blends the logarithmic split scheme, which
+    /// gives each cascade the same ratio of far to near distance, with the
+    /// uniform split scheme, which gives each cascade the same depth. The
+    /// blend factor `lambda` ranges from `0`, for uniform splits, to `1`, for
+    /// logarithmic splits. Consecutive cascades share their split distances.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     ShadowCascade,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let logarithmic: [(f64, f64); 3] = ShadowCascade::practical_splits(1_f64, 1000_f64, 1_f64);
+    /// let uniform: [(f64, f64); 3] = ShadowCascade::practical_splits(1_f64, 1000_f64, 0_f64);
+    ///
+    /// assert!(relative_eq!(logarithmic[0].1, 10_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(logarithmic[1].1, 100_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(logarithmic[2].1, 1000_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(uniform[0].1, 334_f64, epsilon = 1e-10));
+    /// assert!(relative_eq!(uniform[1].1, 667_f64, epsilon = 1e-10));
+    /// assert_eq!(logarithmic[0].0, 1_f64);
+    /// assert_eq!(logarithmic[1].0, logarithmic[0].1);
+    /// ```
+    pub fn practical_splits<const N: usize>(near: S, far: S, lambda: S) -> [(S, S); N] {
+        let one = S::one();
+        let count: S = num_traits::cast(N).unwrap();
+        let split = |i: usize| {
+            if i == 0 {
+                return near;
+            } else if i == N {
+                return far;
+            }
+
+            let fraction = num_traits::cast::<usize, S>(i).unwrap() / count;
+            let logarithmic = near * (far / near).powf(fraction);
+            let uniform = near + (far - near) * fraction;
+
+            lambda * logarithmic + (one - lambda) * uniform
+        };
+
+        core::array::from_fn(|i| (split(i), split(i + 1)))
+    }
+
+    /// Construct the shadow cascade of a directional light for the slice of
+    /// the viewing frustum of a camera between the distances `near` and `far`
+    /// along the viewing direction.
+    ///
+    /// The camera is described by its view transformation `view`, which maps
+    /// world space into camera space, such as the one constructed by
+    /// [`Isometry3::look_at_rh`], and its perspective projection `projection`.
+    /// The light shines in the direction `light_direction` in world space, and
+    /// `resolution` is the width and height of the shadow map in texels, which
+    /// must be greater than two.
+    ///
+    /// The orthographic viewing volume encloses the frustum slice only. Shadow
+    /// casters that lie between the light and the frustum slice need the viewing
+    /// volume extended with [`ShadowCascade::extend_toward_light`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     PerspectiveFov3,
+    /// #     Point3,
+    /// #     ShadowCascade,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let view = Isometry3::look_at_rh(
+    ///     &Point3::new(0_f64, 2_f64, 10_f64), &Point3::origin(), &Vector3::unit_y()
+    /// );
+    /// let projection = PerspectiveFov3::new(Degrees(60_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64);
+    /// let light_direction = Unit::from_value(Vector3::new(1_f64, -2_f64, -1_f64));
+    /// let cascade = ShadowCascade::from_frustum_slice(
+    ///     &view, &projection, &light_direction, 0.1_f64, 10_f64, 2048_f64
+    /// );
+    /// // A point in the middle of the frustum slice lands inside the shadow map.
+    /// let point = view.inverse_transform_point(&Point3::new(0_f64, 0_f64, -5_f64));
+    /// let shadow_map_point = cascade.project_point(&point);
+    ///
+    /// assert!(shadow_map_point.x.abs() < 1_f64);
+    /// assert!(shadow_map_point.y.abs() < 1_f64);
+    /// assert!(shadow_map_point.z.abs() < 1_f64);
+    /// ```
+    pub fn from_frustum_slice(
+        view: &Isometry3<S>,
+        projection: &PerspectiveFov3<S>,
+        light_direction: &Unit<Vector3<S>>,
+        near: S,
+        far: S,
+        resolution: S) -> ShadowCascade<S>
+    {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        // The corners of the frustum slice at the distance `d` lie at the
+        // distance `d * k` from the viewing axis.
+        let tan_half_vfov = (projection.vfov() * one_half).tan();
+        let k_squared = tan_half_vfov * tan_half_vfov * (one + projection.aspect() * projection.aspect());
+        // The center of the smallest enclosing sphere lies on the viewing axis,
+        // equidistant from the near and the far corners, unless the far corners
+        // alone determine the sphere.
+        let center_distance = ((far + near) * (one + k_squared) * one_half).min(far);
+        let radius = ((far - center_distance) * (far - center_distance) + far * far * k_squared).sqrt();
+        let center = view.inverse_transform_point(&Point3::new(zero, zero, -center_distance));
+
+        // Enlarge the viewing volume by one texel on each side, so the sphere
+        // still fits after the center snaps to the texel grid.
+        let half_extent = radius * resolution / (resolution - two);
+        let texel_size = (two * half_extent) / resolution;
+
+        let direction = light_direction.as_ref();
+        let up = if direction.y.abs() < num_traits::cast(0.99).unwrap() {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+        let light_rotation = Rotation3::look_at_rh(direction, &up);
+        let center_light = light_rotation.rotate_point(&center);
+        let snapped_x = (center_light.x / texel_size).floor() * texel_size;
+        let snapped_y = (center_light.y / texel_size).floor() * texel_size;
+        // Place the light so that the sphere lies just in front of it.
+        let translation = Translation3::new(-snapped_x, -snapped_y, -center_light.z - half_extent);
+        let light_view = Isometry3::from_parts(&translation, &light_rotation);
+        let projection = Orthographic3::new(
+            -half_extent, half_extent, -half_extent, half_extent, zero, two * half_extent
+        );
+
+        ShadowCascade {
+            near: near,
+            far: far,
+            light_view: light_view,
+            projection: projection,
+        }
+    }
+
+    /// Construct `N` shadow cascades of a directional light that cover the
+    /// viewing frustum of a camera between its near and far planes. The
+    /// cascades split the frustum with the practical split scheme with blend
+    /// factor `lambda`.
+    ///
+    /// See [`ShadowCascade::practical_splits`] and
+    /// [`ShadowCascade::from_frustum_slice`] for the meaning of the parameters.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     Isometry3,
+    /// #     PerspectiveFov3,
+    /// #     Point3,
+    /// #     ShadowCascade,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let view = Isometry3::look_at_rh(
+    ///     &Point3::new(0_f64, 2_f64, 10_f64), &Point3::origin(), &Vector3::unit_y()
+    /// );
+    /// let projection = PerspectiveFov3::new(Degrees(60_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64);
+    /// let light_direction = Unit::from_value(Vector3::new(1_f64, -2_f64, -1_f64));
+    /// let cascades: [ShadowCascade<f64>; 4] = ShadowCascade::from_frustum(
+    ///     &view, &projection, &light_direction, 0.75_f64, 2048_f64
+    /// );
+    ///
+    /// assert_eq!(cascades[0].near_z(), projection.near_z());
+    /// assert_eq!(cascades[3].far_z(), projection.far_z());
+    /// assert_eq!(cascades[1].near_z(), cascades[0].far_z());
+    /// ```
+    pub fn from_frustum<const N: usize>(
+        view: &Isometry3<S>,
+        projection: &PerspectiveFov3<S>,
+        light_direction: &Unit<Vector3<S>>,
+        lambda: S,
+        resolution: S) -> [ShadowCascade<S>; N]
+    {
+        let splits: [(S, S); N] = Self::practical_splits(projection.near_z(), projection.far_z(), lambda);
+
+        core::array::from_fn(|i| {
+            let (near, far) = splits[i];
+
+            Self::from_frustum_slice(view, projection, light_direction, near, far, resolution)
+        })
+    }
+
+    /// Construct a copy of the shadow cascade whose viewing volume extends
+    /// the distance `distance` further toward the light, so that it captures
+    /// shadow casters that lie between the light and the frustum slice.
+    ///
+    /// The extension moves the near plane of the orthographic projection only,
+    /// so the shadow map texels stay fixed in world space.
+    #[inline]
+    pub fn extend_toward_light(&self, distance: S) -> ShadowCascade<S> {
+        let projection = Orthographic3::new(
+            self.projection.left_x(),
+            self.projection.right_x(),
+            self.projection.bottom_y(),
+            self.projection.top_y(),
+            self.projection.near_z() - distance,
+            self.projection.far_z()
+        );
+
+        ShadowCascade {
+            projection: projection,
+            ..*self
+        }
+    }
+
+    /// Get the distance along the viewing direction of the camera of the
+    /// near end of the frustum slice.
+    #[inline]
+    pub fn near_z(&self) -> S {
+        self.near
+    }
+
+    /// Get the distance along the viewing direction of the camera of the
+    /// far end of the frustum slice.
+    #[inline]
+    pub fn far_z(&self) -> S {
+        self.far
+    }
+
+    /// Get the transformation from world space into light space.
+    #[inline]
+    pub fn light_view(&self) -> &Isometry3<S> {
+        &self.light_view
+    }
+
+    /// Get the orthographic projection of light space onto the shadow map.
+    #[inline]
+    pub fn projection(&self) -> &Orthographic3<S> {
+        &self.projection
+    }
+
+    /// Get the matrix that maps world space onto the normalized device
+    /// coordinates of the shadow map.
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix4x4<S> {
+        self.projection.matrix() * self.light_view.to_affine_matrix()
+    }
+
+    /// Map a point in world space onto the normalized device coordinates of
+    /// the shadow map.
+    #[inline]
+    pub fn project_point(&self, point: &Point3<S>) -> Point3<S> {
+        self.projection.project_point(&self.light_view.transform_point(point))
+    }
+}
+
+impl<S> fmt::Display for ShadowCascade<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "ShadowCascade [near={}, far={}, light_view={}, projection={}]",
+            self.near, self.far, self.light_view, self.projection
+        )
+    }
+}
+
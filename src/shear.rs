@@ -19,6 +19,10 @@ use crate::transform::{
     Transform2,
     Transform3,
 };
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -289,6 +293,37 @@ where
     
         inverse.matrix * vector
     }
+
+    /// Apply the shear transformation to a normal vector.
+    ///
+    /// Normals transform by the inverse transpose of the shear matrix. The 
+    /// result is renormalized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Shear2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let shear = Shear2::from_shear_x(1_f64);
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 0_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, -1_f64));
+    /// let result = shear.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        let matrix_inverse_transpose = self.inverse().matrix.transpose();
+
+        Normal2::from_vector(&(matrix_inverse_transpose * normal.as_vector()))
+    }
     
     /// Apply the inverse of the shear transformation to a point.
     ///
@@ -841,6 +876,37 @@ where
         inverse.matrix * vector
     }
 
+    /// Apply the shear transformation to a normal vector.
+    ///
+    /// Normals transform by the inverse transpose of the shear matrix. The 
+    /// result is renormalized.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Shear3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let shear = Shear3::from_shear_x(1_f64, 0_f64);
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 0_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(1_f64, -1_f64, 0_f64));
+    /// let result = shear.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        let matrix_inverse_transpose = self.inverse().matrix.transpose();
+
+        Normal3::from_vector(&(matrix_inverse_transpose * normal.as_vector()))
+    }
+
     /// Apply the inverse of the shear transformation to a point.
     ///
     /// ## Example
@@ -33,6 +33,11 @@ use crate::isometry::{
     Isometry2,
     Isometry3,
 };
+use crate::registration;
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -225,6 +230,62 @@ where
         }
     }
 
+    /// Estimate the similarity transformation that best maps the points 
+    /// `source` onto the corresponding points `target` in the least squares 
+    /// sense, using the Umeyama algorithm.
+    ///
+    /// The function returns the best fit similarity transformation together 
+    /// with the root mean square distance between the transformed source points 
+    /// and the target points. It returns `None` if the point slices are empty, 
+    /// have different lengths, or if all the source points coincide, in which 
+    /// case the scale factor is undefined.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Similarity2,
+    /// #     Degrees,
+    /// #     Point2,
+    /// #     Rotation2,
+    /// #     Translation2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let similarity = Similarity2::from_parts(
+    ///     &Translation2::new(1_f64, -2_f64),
+    ///     &Rotation2::from_angle(Degrees(72_f64)),
+    ///     2.5_f64
+    /// );
+    /// let source = [
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(1_f64, 0_f64),
+    ///     Point2::new(0_f64, 2_f64),
+    ///     Point2::new(-3_f64, 1_f64),
+    /// ];
+    /// let target = source.map(|point| similarity.transform_point(&point));
+    /// let (result, residual) = Similarity2::umeyama(&source, &target).unwrap();
+    ///
+    /// assert!(relative_eq!(result, similarity, epsilon = 1e-10));
+    /// assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    /// ```
+    pub fn umeyama(source: &[Point2<S>], target: &[Point2<S>]) -> Option<(Similarity2<S>, S)> {
+        let alignment = registration::align_points2(source, target)?;
+        if alignment.source_spread <= S::epsilon() {
+            return None;
+        }
+
+        let scale = alignment.correlation / alignment.source_spread;
+        let rotated_centroid = alignment.rotation.rotate_point(&alignment.source_centroid);
+        let translation = Translation2::from_vector(&(alignment.target_centroid - rotated_centroid * scale));
+        let similarity = Similarity2::from_parts(&translation, &alignment.rotation, scale);
+        let residual = registration::rms_residual2(source, target, |point| similarity.transform_point(point));
+
+        Some((similarity, residual))
+    }
+
     /// Convert a similarity transformation to an affine matrix.
     ///
     /// ## Example
@@ -465,6 +526,49 @@ where
         self.isometry.inverse_transform_vector(vector) / self.scale
     }
 
+    /// Apply the similarity transformation to a normal vector.
+    ///
+    /// The inverse transpose of the linear part of a similarity transformation 
+    /// is the rotation part divided by the scale factor. After renormalization, 
+    /// the normal is rotated by the rotation part, and flipped when the scale 
+    /// factor is negative.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Similarity2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// #     Degrees,
+    /// #     Translation2,
+    /// #     Rotation2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let similarity = Similarity2::from_parts(
+    ///     &Translation2::new(1_f64, 2_f64),
+    ///     &Rotation2::from_angle(Degrees(90_f64)),
+    ///     -3_f64
+    /// );
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 0_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(0_f64, -1_f64));
+    /// let result = similarity.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        let rotated_normal = self.isometry.rotation().rotate_vector(normal.as_vector());
+        if self.scale < S::zero() {
+            Normal2::from_vector_unchecked(-rotated_normal)
+        } else {
+            Normal2::from_vector_unchecked(rotated_normal)
+        }
+    }
+
     /// Apply the similarity transformation to a point.
     ///
     /// The transformation applies the scaling, followed by the rotation,
@@ -1110,6 +1214,66 @@ where
         Self::from_isometry(&isometry)
     }
 
+    /// Estimate the similarity transformation that best maps the points 
+    /// `source` onto the corresponding points `target` in the least squares 
+    /// sense, using the Umeyama algorithm.
+    ///
+    /// The function returns the best fit similarity transformation together 
+    /// with the root mean square distance between the transformed source points 
+    /// and the target points. It returns `None` if the point slices are empty, 
+    /// have different lengths, or if all the source points coincide, in which 
+    /// case the scale factor is undefined.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Similarity3,
+    /// #     Degrees,
+    /// #     Point3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+    /// let similarity = Similarity3::from_parts(
+    ///     &Translation3::new(1_f64, -2_f64, 3_f64),
+    ///     &Rotation3::from_axis_angle(&axis, Degrees(72_f64)),
+    ///     2.5_f64
+    /// );
+    /// let source = [
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(1_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, 2_f64, 0_f64),
+    ///     Point3::new(0_f64, 0_f64, 3_f64),
+    ///     Point3::new(-1_f64, 1_f64, 1_f64),
+    /// ];
+    /// let target = source.map(|point| similarity.transform_point(&point));
+    /// let (result, residual) = Similarity3::umeyama(&source, &target).unwrap();
+    ///
+    /// assert!(relative_eq!(result, similarity, epsilon = 1e-10));
+    /// assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    /// ```
+    pub fn umeyama(source: &[Point3<S>], target: &[Point3<S>]) -> Option<(Similarity3<S>, S)> {
+        let alignment = registration::align_points3(source, target)?;
+        if alignment.source_spread <= S::epsilon() {
+            return None;
+        }
+
+        let scale = alignment.correlation / alignment.source_spread;
+        let rotated_centroid = alignment.rotation.rotate_point(&alignment.source_centroid);
+        let translation = Translation3::from_vector(&(alignment.target_centroid - rotated_centroid * scale));
+        let similarity = Similarity3::from_parts(&translation, &alignment.rotation, scale);
+        let residual = registration::rms_residual3(source, target, |point| similarity.transform_point(point));
+
+        Some((similarity, residual))
+    }
+
     /// Convert a similarity transformation to an affine matrix.
     ///
     /// ## Example
@@ -1201,6 +1365,111 @@ where
         }
     }
 
+    /// Interpolate between two similarity transformations by interpolating 
+    /// the scale, rotation, and translation parts independently.
+    ///
+    /// The rotation parts are spherically linearly interpolated along the 
+    /// shortest arc, the translation parts are linearly interpolated, and the 
+    /// scale factors are interpolated logarithmically, so that the scale 
+    /// changes by the same ratio over equal time steps.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Similarity3,
+    /// #     Degrees,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let similarity1 = Similarity3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 0_f64),
+    ///     &Rotation3::from_axis_angle(&axis, Degrees(0_f64)),
+    ///     1_f64
+    /// );
+    /// let similarity2 = Similarity3::from_parts(
+    ///     &Translation3::new(4_f64, 0_f64, 0_f64),
+    ///     &Rotation3::from_axis_angle(&axis, Degrees(90_f64)),
+    ///     4_f64
+    /// );
+    /// let expected = Similarity3::from_parts(
+    ///     &Translation3::new(2_f64, 0_f64, 0_f64),
+    ///     &Rotation3::from_axis_angle(&axis, Degrees(45_f64)),
+    ///     2_f64
+    /// );
+    /// let result = similarity1.lerp_slerp(&similarity2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn lerp_slerp(&self, other: &Similarity3<S>, amount: S) -> Similarity3<S> {
+        Similarity3 {
+            isometry: self.isometry.lerp_slerp(&other.isometry, amount),
+            scale: Self::interpolate_scale(self.scale, other.scale, amount),
+        }
+    }
+
+    /// Interpolate between two similarity transformations along a screw 
+    /// motion.
+    ///
+    /// The rigid body parts are interpolated along a screw motion as in 
+    /// [`Isometry3::sclerp`], and the scale factors are interpolated 
+    /// logarithmically.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Similarity3,
+    /// #     Degrees,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let axis = Unit::from_value(Vector3::unit_z());
+    /// let similarity1 = Similarity3::from_scale(2_f64);
+    /// let similarity2 = Similarity3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 4_f64),
+    ///     &Rotation3::from_axis_angle(&axis, Degrees(90_f64)),
+    ///     8_f64
+    /// );
+    /// let expected = Similarity3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 2_f64),
+    ///     &Rotation3::from_axis_angle(&axis, Degrees(45_f64)),
+    ///     4_f64
+    /// );
+    /// let result = similarity1.sclerp(&similarity2, 0.5_f64);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn sclerp(&self, other: &Similarity3<S>, amount: S) -> Similarity3<S> {
+        Similarity3 {
+            isometry: self.isometry.sclerp(&other.isometry, amount),
+            scale: Self::interpolate_scale(self.scale, other.scale, amount),
+        }
+    }
+
+    #[inline]
+    fn interpolate_scale(scale1: S, scale2: S, amount: S) -> S {
+        let log_scale1 = scale1.ln();
+        let log_scale2 = scale2.ln();
+
+        (log_scale1 + (log_scale2 - log_scale1) * amount).exp()
+    }
+
     /// Convert a similarity transformation to a generic transformation.
     #[inline]
     pub fn to_transform(&self) -> Transform3<S> {
@@ -1365,6 +1634,49 @@ where
         self.isometry.inverse_transform_vector(vector) / self.scale
     }
 
+    /// Apply the similarity transformation to a normal vector.
+    ///
+    /// The inverse transpose of the linear part of a similarity transformation 
+    /// is the rotation part divided by the scale factor. After renormalization, 
+    /// the normal is rotated by the rotation part, and flipped when the scale 
+    /// factor is negative.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Similarity3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// #     Degrees,
+    /// #     Translation3,
+    /// #     Rotation3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let similarity = Similarity3::from_parts(
+    ///     &Translation3::new(1_f64, 2_f64, 3_f64),
+    ///     &Rotation3::from_angle_z(Degrees(90_f64)),
+    ///     -3_f64
+    /// );
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 0_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(0_f64, -1_f64, 0_f64));
+    /// let result = similarity.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        let rotated_normal = self.isometry.rotation().rotate_vector(normal.as_vector());
+        if self.scale < S::zero() {
+            Normal3::from_vector_unchecked(-rotated_normal)
+        } else {
+            Normal3::from_vector_unchecked(rotated_normal)
+        }
+    }
+
     /// Apply a similarity transformation to a point.
     ///
     /// The transformation applies the scaling, followed by the rotation,
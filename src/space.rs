@@ -0,0 +1,800 @@
+use crate::base::{
+    ScalarFloat,
+};
+use crate::point::{
+    Point3,
+};
+use crate::vector::{
+    Vector3,
+};
+use crate::isometry::{
+    Isometry3,
+};
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops;
+
+
+/// A marker type naming a coordinate space.
+///
+/// Coordinate spaces are zero sized types that exist only at compile time.
+/// The crate provides the common spaces [`LocalSpace`], [`WorldSpace`], and
+/// [`ViewSpace`], and applications can declare their own. The typed wrappers
+/// [`TypedPoint3`], [`TypedVector3`], and [`TypedIsometry3`] only accept
+/// types implementing this trait as coordinate spaces.
+///
+/// The coordinate space tags live in separate wrapper types instead of an
+/// extra type parameter on [`Point3`], [`Vector3`], and [`Isometry3`]. Rust
+/// does not use defaulted type parameters during type inference, so adding a
+/// space parameter to the existing types would break inference in existing
+/// code, and it would change the signature of every function and macro
+/// generated implementation that mentions them. The wrappers keep the
+/// untyped API unchanged, cost nothing at runtime, and convert to and from
+/// the untyped types with `from_point`, `from_vector`, `from_isometry`, and
+/// `to_untyped`. They cover the three dimensional points, vectors, and rigid
+/// transformations that move geometry between spaces.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     CoordinateSpace,
+/// #     TypedPoint3,
+/// # };
+/// #
+/// struct TangentSpace;
+///
+/// impl CoordinateSpace for TangentSpace {}
+///
+/// let point: TypedPoint3<f64, TangentSpace> = TypedPoint3::new(1_f64, 2_f64, 3_f64);
+/// ```
+///
+/// Types that do not implement the trait are not coordinate spaces.
+///
+/// ```compile_fail
+/// # use cglinalg::{
+/// #     TypedPoint3,
+/// # };
+/// #
+/// let point: TypedPoint3<f64, String> = TypedPoint3::new(1_f64, 2_f64, 3_f64);
+/// ```
+pub trait CoordinateSpace {}
+
+/// The coordinate space of a model, relative to its own origin.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LocalSpace;
+
+/// The coordinate space shared by every object in a scene.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WorldSpace;
+
+/// The coordinate space of a camera, with the camera at the origin.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ViewSpace;
+
+impl CoordinateSpace for LocalSpace {}
+impl CoordinateSpace for WorldSpace {}
+impl CoordinateSpace for ViewSpace {}
+
+
+/// A point in three dimensions tagged with the coordinate space it lives in.
+///
+/// A typed point has the same layout as a [`Point3`]. The coordinate space
+/// exists only in the type, so points, vectors, and transformations from
+/// different spaces cannot be mixed by accident.
+///
+/// ## Example
+///
+/// ```compile_fail
+/// # use cglinalg::{
+/// #     LocalSpace,
+/// #     TypedPoint3,
+/// #     WorldSpace,
+/// # };
+/// #
+/// let local: TypedPoint3<f64, LocalSpace> = TypedPoint3::new(1_f64, 2_f64, 3_f64);
+/// let world: TypedPoint3<f64, WorldSpace> = TypedPoint3::new(4_f64, 5_f64, 6_f64);
+///
+/// // Points in different spaces cannot be subtracted.
+/// let difference = world - local;
+/// ```
+#[repr(transparent)]
+pub struct TypedPoint3<S, Space> 
+where 
+    Space: CoordinateSpace,
+{
+    point: Point3<S>,
+    _space: PhantomData<Space>,
+}
+
+impl<S, Space> TypedPoint3<S, Space> 
+where 
+    Space: CoordinateSpace,
+{
+    /// Tag a point with a coordinate space.
+    #[inline]
+    pub const fn from_point(point: Point3<S>) -> TypedPoint3<S, Space> {
+        TypedPoint3 {
+            point: point,
+            _space: PhantomData,
+        }
+    }
+
+    /// Construct a new point in a coordinate space.
+    #[inline]
+    pub const fn new(x: S, y: S, z: S) -> TypedPoint3<S, Space> {
+        TypedPoint3::from_point(Point3::new(x, y, z))
+    }
+
+    /// Get the underlying untyped point.
+    #[inline]
+    pub fn as_point(&self) -> &Point3<S> {
+        &self.point
+    }
+
+    /// Remove the coordinate space tag from the point.
+    #[inline]
+    pub fn to_untyped(self) -> Point3<S> {
+        self.point
+    }
+
+    /// Reinterpret the point as a point in another coordinate space.
+    ///
+    /// This is an escape hatch for coordinate spaces that coincide. Use it
+    /// sparingly, because it defeats the purpose of tagging points.
+    #[inline]
+    pub fn cast_space<NewSpace>(self) -> TypedPoint3<S, NewSpace> 
+    where 
+        NewSpace: CoordinateSpace,
+    {
+        TypedPoint3::from_point(self.point)
+    }
+}
+
+impl<S, Space> TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    /// Construct the origin of a coordinate space.
+    #[inline]
+    pub fn origin() -> TypedPoint3<S, Space> {
+        TypedPoint3::from_point(Point3::origin())
+    }
+
+    /// Get the position vector of the point in its coordinate space.
+    #[inline]
+    pub fn to_vector(self) -> TypedVector3<S, Space> {
+        TypedVector3::from_vector(self.point.to_vector())
+    }
+}
+
+impl<S, Space> Copy for TypedPoint3<S, Space> where S: Copy, Space: CoordinateSpace {}
+
+impl<S, Space> Clone for TypedPoint3<S, Space> 
+where 
+    S: Copy,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, Space> PartialEq for TypedPoint3<S, Space> 
+where 
+    S: PartialEq,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<S, Space> fmt::Debug for TypedPoint3<S, Space> 
+where 
+    S: fmt::Debug,
+    Space: CoordinateSpace,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("TypedPoint3")
+            .field("point", &self.point)
+            .field("space", &core::any::type_name::<Space>())
+            .finish()
+    }
+}
+
+impl<S, Space> fmt::Display for TypedPoint3<S, Space> 
+where 
+    S: fmt::Display,
+    Space: CoordinateSpace,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} in {}", self.point, core::any::type_name::<Space>())
+    }
+}
+
+impl<S, Space> ops::Sub<TypedPoint3<S, Space>> for TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedVector3<S, Space>;
+
+    #[inline]
+    fn sub(self, other: TypedPoint3<S, Space>) -> Self::Output {
+        TypedVector3::from_vector(self.point - other.point)
+    }
+}
+
+impl<S, Space> ops::Add<TypedVector3<S, Space>> for TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedPoint3<S, Space>;
+
+    #[inline]
+    fn add(self, other: TypedVector3<S, Space>) -> Self::Output {
+        TypedPoint3::from_point(self.point + other.vector)
+    }
+}
+
+impl<S, Space> ops::Sub<TypedVector3<S, Space>> for TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedPoint3<S, Space>;
+
+    #[inline]
+    fn sub(self, other: TypedVector3<S, Space>) -> Self::Output {
+        TypedPoint3::from_point(self.point - other.vector)
+    }
+}
+
+impl<S, Space> approx::AbsDiffEq for TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Point3::abs_diff_eq(&self.point, &other.point, epsilon)
+    }
+}
+
+impl<S, Space> approx::RelativeEq for TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.point, &other.point, epsilon, max_relative)
+    }
+}
+
+impl<S, Space> approx::UlpsEq for TypedPoint3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.point, &other.point, epsilon, max_ulps)
+    }
+}
+
+
+/// A vector in three dimensions tagged with the coordinate space it lives in.
+///
+/// A typed vector has the same layout as a [`Vector3`].
+#[repr(transparent)]
+pub struct TypedVector3<S, Space> 
+where 
+    Space: CoordinateSpace,
+{
+    vector: Vector3<S>,
+    _space: PhantomData<Space>,
+}
+
+impl<S, Space> TypedVector3<S, Space> 
+where 
+    Space: CoordinateSpace,
+{
+    /// Tag a vector with a coordinate space.
+    #[inline]
+    pub const fn from_vector(vector: Vector3<S>) -> TypedVector3<S, Space> {
+        TypedVector3 {
+            vector: vector,
+            _space: PhantomData,
+        }
+    }
+
+    /// Construct a new vector in a coordinate space.
+    #[inline]
+    pub const fn new(x: S, y: S, z: S) -> TypedVector3<S, Space> {
+        TypedVector3::from_vector(Vector3::new(x, y, z))
+    }
+
+    /// Get the underlying untyped vector.
+    #[inline]
+    pub fn as_vector(&self) -> &Vector3<S> {
+        &self.vector
+    }
+
+    /// Remove the coordinate space tag from the vector.
+    #[inline]
+    pub fn to_untyped(self) -> Vector3<S> {
+        self.vector
+    }
+
+    /// Reinterpret the vector as a vector in another coordinate space.
+    ///
+    /// This is an escape hatch for coordinate spaces that coincide. Use it
+    /// sparingly, because it defeats the purpose of tagging vectors.
+    #[inline]
+    pub fn cast_space<NewSpace>(self) -> TypedVector3<S, NewSpace> 
+    where 
+        NewSpace: CoordinateSpace,
+    {
+        TypedVector3::from_vector(self.vector)
+    }
+}
+
+impl<S, Space> TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    /// Construct the zero vector of a coordinate space.
+    #[inline]
+    pub fn zero() -> TypedVector3<S, Space> {
+        TypedVector3::from_vector(Vector3::zero())
+    }
+
+    /// Compute the dot product of two vectors in the same coordinate space.
+    #[inline]
+    pub fn dot(&self, other: &TypedVector3<S, Space>) -> S {
+        self.vector.dot(&other.vector)
+    }
+
+    /// Compute the cross product of two vectors in the same coordinate space.
+    #[inline]
+    pub fn cross(&self, other: &TypedVector3<S, Space>) -> TypedVector3<S, Space> {
+        TypedVector3::from_vector(self.vector.cross(&other.vector))
+    }
+}
+
+impl<S, Space> Copy for TypedVector3<S, Space> where S: Copy, Space: CoordinateSpace {}
+
+impl<S, Space> Clone for TypedVector3<S, Space> 
+where 
+    S: Copy,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, Space> PartialEq for TypedVector3<S, Space> 
+where 
+    S: PartialEq,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<S, Space> fmt::Debug for TypedVector3<S, Space> 
+where 
+    S: fmt::Debug,
+    Space: CoordinateSpace,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("TypedVector3")
+            .field("vector", &self.vector)
+            .field("space", &core::any::type_name::<Space>())
+            .finish()
+    }
+}
+
+impl<S, Space> fmt::Display for TypedVector3<S, Space> 
+where 
+    S: fmt::Display,
+    Space: CoordinateSpace,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} in {}", self.vector, core::any::type_name::<Space>())
+    }
+}
+
+impl<S, Space> ops::Add<TypedVector3<S, Space>> for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedVector3<S, Space>;
+
+    #[inline]
+    fn add(self, other: TypedVector3<S, Space>) -> Self::Output {
+        TypedVector3::from_vector(self.vector + other.vector)
+    }
+}
+
+impl<S, Space> ops::Sub<TypedVector3<S, Space>> for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedVector3<S, Space>;
+
+    #[inline]
+    fn sub(self, other: TypedVector3<S, Space>) -> Self::Output {
+        TypedVector3::from_vector(self.vector - other.vector)
+    }
+}
+
+impl<S, Space> ops::Mul<S> for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedVector3<S, Space>;
+
+    #[inline]
+    fn mul(self, other: S) -> Self::Output {
+        TypedVector3::from_vector(self.vector * other)
+    }
+}
+
+impl<S, Space> ops::Neg for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Output = TypedVector3<S, Space>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        TypedVector3::from_vector(-self.vector)
+    }
+}
+
+impl<S, Space> approx::AbsDiffEq for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.vector, &other.vector, epsilon)
+    }
+}
+
+impl<S, Space> approx::RelativeEq for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.vector, &other.vector, epsilon, max_relative)
+    }
+}
+
+impl<S, Space> approx::UlpsEq for TypedVector3<S, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.vector, &other.vector, epsilon, max_ulps)
+    }
+}
+
+
+/// An isometry in three dimensions that maps the coordinate space `Source`
+/// into the coordinate space `Target`.
+///
+/// A typed isometry has the same layout as an [`Isometry3`]. It only accepts
+/// points and vectors from its source space, and it only composes with
+/// isometries whose spaces line up.
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     Isometry3,
+/// #     LocalSpace,
+/// #     TypedIsometry3,
+/// #     Translation3,
+/// #     TypedPoint3,
+/// #     Vector3,
+/// #     ViewSpace,
+/// #     WorldSpace,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// let model: TypedIsometry3<f64, LocalSpace, WorldSpace> = TypedIsometry3::from_isometry(
+///     Isometry3::from_translation(&Translation3::from_vector(&Vector3::new(1_f64, 2_f64, 3_f64)))
+/// );
+/// let view: TypedIsometry3<f64, WorldSpace, ViewSpace> = TypedIsometry3::from_isometry(
+///     Isometry3::from_translation(&Translation3::from_vector(&Vector3::new(0_f64, 0_f64, -10_f64)))
+/// );
+/// let model_view = view * model;
+/// let point: TypedPoint3<f64, LocalSpace> = TypedPoint3::new(1_f64, 1_f64, 1_f64);
+/// let result: TypedPoint3<f64, ViewSpace> = model_view.transform_point(&point);
+///
+/// assert!(relative_eq!(result, TypedPoint3::new(2_f64, 3_f64, -6_f64), epsilon = 1e-10));
+/// ```
+///
+/// Transforming a point from the wrong space fails to compile.
+///
+/// ```compile_fail
+/// # use cglinalg::{
+/// #     Isometry3,
+/// #     LocalSpace,
+/// #     TypedIsometry3,
+/// #     TypedPoint3,
+/// #     WorldSpace,
+/// # };
+/// #
+/// let model: TypedIsometry3<f64, LocalSpace, WorldSpace> = TypedIsometry3::from_isometry(Isometry3::identity());
+/// let point: TypedPoint3<f64, WorldSpace> = TypedPoint3::new(1_f64, 1_f64, 1_f64);
+/// let result = model.transform_point(&point);
+/// ```
+#[repr(transparent)]
+pub struct TypedIsometry3<S, Source, Target> 
+where 
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    isometry: Isometry3<S>,
+    _spaces: PhantomData<(Source, Target)>,
+}
+
+impl<S, Source, Target> TypedIsometry3<S, Source, Target> 
+where 
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    /// Tag an isometry with its source and target coordinate spaces.
+    #[inline]
+    pub const fn from_isometry(isometry: Isometry3<S>) -> TypedIsometry3<S, Source, Target> {
+        TypedIsometry3 {
+            isometry: isometry,
+            _spaces: PhantomData,
+        }
+    }
+
+    /// Get the underlying untyped isometry.
+    #[inline]
+    pub fn as_isometry(&self) -> &Isometry3<S> {
+        &self.isometry
+    }
+
+    /// Remove the coordinate space tags from the isometry.
+    #[inline]
+    pub fn to_untyped(self) -> Isometry3<S> {
+        self.isometry
+    }
+}
+
+impl<S, Source, Target> TypedIsometry3<S, Source, Target> 
+where 
+    S: ScalarFloat,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    /// Transform a point from the source space into the target space.
+    #[inline]
+    pub fn transform_point(&self, point: &TypedPoint3<S, Source>) -> TypedPoint3<S, Target> {
+        TypedPoint3::from_point(self.isometry.transform_point(&point.point))
+    }
+
+    /// Transform a vector from the source space into the target space.
+    #[inline]
+    pub fn transform_vector(&self, vector: &TypedVector3<S, Source>) -> TypedVector3<S, Target> {
+        TypedVector3::from_vector(self.isometry.transform_vector(&vector.vector))
+    }
+
+    /// Compute the inverse isometry, which maps the target space back into
+    /// the source space.
+    #[inline]
+    pub fn inverse(&self) -> TypedIsometry3<S, Target, Source> {
+        TypedIsometry3::from_isometry(self.isometry.inverse())
+    }
+
+    /// Compose the isometry with an isometry that continues from its target
+    /// space. The result applies `self` first and `other` second.
+    #[inline]
+    pub fn then<NewTarget>(&self, other: &TypedIsometry3<S, Target, NewTarget>) -> TypedIsometry3<S, Source, NewTarget> 
+    where 
+        NewTarget: CoordinateSpace,
+    {
+        TypedIsometry3::from_isometry(other.isometry * self.isometry)
+    }
+}
+
+impl<S, Space> TypedIsometry3<S, Space, Space> 
+where 
+    S: ScalarFloat,
+    Space: CoordinateSpace,
+{
+    /// Construct the identity isometry of a coordinate space.
+    #[inline]
+    pub fn identity() -> TypedIsometry3<S, Space, Space> {
+        TypedIsometry3::from_isometry(Isometry3::identity())
+    }
+}
+
+impl<S, Source, Target> Copy for TypedIsometry3<S, Source, Target> where S: Copy, Source: CoordinateSpace, Target: CoordinateSpace {}
+
+impl<S, Source, Target> Clone for TypedIsometry3<S, Source, Target> 
+where 
+    S: Copy,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, Source, Target> PartialEq for TypedIsometry3<S, Source, Target> 
+where 
+    S: PartialEq,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.isometry == other.isometry
+    }
+}
+
+impl<S, Source, Target> fmt::Debug for TypedIsometry3<S, Source, Target> 
+where 
+    S: fmt::Debug,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("TypedIsometry3")
+            .field("isometry", &self.isometry)
+            .field("source", &core::any::type_name::<Source>())
+            .field("target", &core::any::type_name::<Target>())
+            .finish()
+    }
+}
+
+impl<S, Source, Target> fmt::Display for TypedIsometry3<S, Source, Target> 
+where 
+    S: fmt::Display,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} from {} to {}",
+            self.isometry, core::any::type_name::<Source>(), core::any::type_name::<Target>()
+        )
+    }
+}
+
+impl<S, A, B, C> ops::Mul<TypedIsometry3<S, A, B>> for TypedIsometry3<S, B, C> 
+where 
+    S: ScalarFloat,
+    A: CoordinateSpace,
+    B: CoordinateSpace,
+    C: CoordinateSpace,
+{
+    type Output = TypedIsometry3<S, A, C>;
+
+    #[inline]
+    fn mul(self, other: TypedIsometry3<S, A, B>) -> Self::Output {
+        TypedIsometry3::from_isometry(self.isometry * other.isometry)
+    }
+}
+
+impl<S, Source, Target> approx::AbsDiffEq for TypedIsometry3<S, Source, Target> 
+where 
+    S: ScalarFloat,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        Isometry3::abs_diff_eq(&self.isometry, &other.isometry, epsilon)
+    }
+}
+
+impl<S, Source, Target> approx::RelativeEq for TypedIsometry3<S, Source, Target> 
+where 
+    S: ScalarFloat,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Isometry3::relative_eq(&self.isometry, &other.isometry, epsilon, max_relative)
+    }
+}
+
+impl<S, Source, Target> approx::UlpsEq for TypedIsometry3<S, Source, Target> 
+where 
+    S: ScalarFloat,
+    Source: CoordinateSpace,
+    Target: CoordinateSpace,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Isometry3::ulps_eq(&self.isometry, &other.isometry, epsilon, max_ulps)
+    }
+}
+
@@ -3,6 +3,7 @@ use crate::base::{
     ScalarFloat,
 };
 use crate::matrix::{
+    Matrix2x2,
     Matrix3x3,
     Matrix4x4,
 };
@@ -14,6 +15,10 @@ use crate::point::{
     Point2,
     Point3,
 };
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -211,6 +216,49 @@ where
             .map(|matrix_inverse| matrix_inverse.transform_vector(vector))
     }
 
+    /// Apply the transformation to a normal vector.
+    ///
+    /// Normals transform by the inverse transpose of the linear part of the 
+    /// transformation. The result is renormalized. The function returns `None` 
+    /// if the linear part of the transformation is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Transform2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// #     Matrix3x3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     0_f64, 1_f64, 0_f64,
+    ///     5_f64, 6_f64, 1_f64
+    /// );
+    /// let transform = Transform2::from_matrix_unchecked(matrix);
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, 2_f64));
+    /// let result = transform.transform_normal(&normal).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Option<Normal2<S>> {
+        let linear = Matrix2x2::new(
+            self.matrix.c0r0, self.matrix.c0r1,
+            self.matrix.c1r0, self.matrix.c1r1
+        );
+
+        linear.inverse().map(|linear_inverse| {
+            Normal2::from_vector(&(linear_inverse.transpose() * normal.as_vector()))
+        })
+    }
+
     /// Apply the inverse of the transformation to a point.
     ///
     /// ## Example
@@ -633,6 +681,51 @@ where
             .map(|matrix_inverse| matrix_inverse.transform_vector(vector))
     }
 
+    /// Apply the transformation to a normal vector.
+    ///
+    /// Normals transform by the inverse transpose of the linear part of the 
+    /// transformation. The result is renormalized. The function returns `None` 
+    /// if the linear part of the transformation is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Transform3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// #     Matrix4x4,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let matrix = Matrix4x4::new(
+    ///     2_f64, 0_f64, 0_f64, 0_f64,
+    ///     0_f64, 1_f64, 0_f64, 0_f64,
+    ///     0_f64, 0_f64, 1_f64, 0_f64,
+    ///     5_f64, 6_f64, 7_f64, 1_f64
+    /// );
+    /// let transform = Transform3::from_matrix_unchecked(matrix);
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 1_f64, 0_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(1_f64, 2_f64, 0_f64));
+    /// let result = transform.transform_normal(&normal).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Option<Normal3<S>> {
+        let linear = Matrix3x3::new(
+            self.matrix.c0r0, self.matrix.c0r1, self.matrix.c0r2,
+            self.matrix.c1r0, self.matrix.c1r1, self.matrix.c1r2,
+            self.matrix.c2r0, self.matrix.c2r1, self.matrix.c2r2
+        );
+
+        linear.inverse().map(|linear_inverse| {
+            Normal3::from_vector(&(linear_inverse.transpose() * normal.as_vector()))
+        })
+    }
+
     /// Apply the inverse of the transformation to a point.
     ///
     /// ## Example
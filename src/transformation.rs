@@ -0,0 +1,456 @@
+use crate::base::{
+    ScalarFloat,
+};
+use crate::point::{
+    Point2,
+    Point3,
+};
+use crate::vector::{
+    Vector2,
+    Vector3,
+};
+use crate::isometry::{
+    Isometry2,
+    Isometry3,
+};
+use crate::reflection::{
+    Reflection2,
+    Reflection3,
+};
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+use crate::scale::{
+    Scale2,
+    Scale3,
+};
+use crate::shear::{
+    Shear2,
+    Shear3,
+};
+use crate::similarity::{
+    Similarity2,
+    Similarity3,
+};
+use crate::transform::{
+    Transform2,
+    Transform3,
+};
+use crate::translation::{
+    Translation2,
+    Translation3,
+};
+
+
+/// A type with this trait acts as a transformation of points and vectors
+/// in two dimensions.
+///
+/// Every two-dimensional transformation type implements this trait, so that
+/// generic code can accept any kind of transformation without knowing its
+/// concrete type. The trait methods mirror the inherent methods of each
+/// transformation type, e.g. [`Rotation2::rotate_point`] or
+/// [`Scale2::scale_point`].
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     Transformation2,
+/// #     Point2,
+/// #     Rotation2,
+/// #     Scale2,
+/// #     Degrees,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// fn transform_all<T>(transform: &T, points: &mut [Point2<f64>])
+/// where
+///     T: Transformation2<Scalar = f64>
+/// {
+///     for point in points.iter_mut() {
+///         *point = transform.transform_point(point);
+///     }
+/// }
+///
+/// let mut points = [Point2::new(1_f64, 0_f64), Point2::new(0_f64, 2_f64)];
+/// transform_all(&Rotation2::from_angle(Degrees(90_f64)), &mut points);
+/// transform_all(&Scale2::from_nonuniform_scale(2_f64, 3_f64), &mut points);
+///
+/// assert!(relative_eq!(points[0], Point2::new(0_f64, 3_f64), epsilon = 1e-10));
+/// assert!(relative_eq!(points[1], Point2::new(-4_f64, 0_f64), epsilon = 1e-10));
+/// ```
+pub trait Transformation2
+where
+    Self: Sized
+{
+    type Scalar: ScalarFloat;
+
+    /// Construct the identity transformation.
+    fn identity() -> Self;
+
+    /// Apply the transformation to a point.
+    fn transform_point(&self, point: &Point2<Self::Scalar>) -> Point2<Self::Scalar>;
+
+    /// Apply the transformation to a vector.
+    fn transform_vector(&self, vector: &Vector2<Self::Scalar>) -> Vector2<Self::Scalar>;
+
+    /// Convert the transformation into a generic transformation.
+    fn to_transform(&self) -> Transform2<Self::Scalar>;
+}
+
+/// A two-dimensional transformation that always has an inverse.
+///
+/// Every structured transformation type is invertible by construction. A
+/// generic [`Transform2`] is not, because its matrix may be singular.
+pub trait InvertibleTransformation2: Transformation2 {
+    /// Construct the inverse transformation.
+    fn inverse(&self) -> Self;
+
+    /// Apply the inverse of the transformation to a point.
+    fn inverse_transform_point(&self, point: &Point2<Self::Scalar>) -> Point2<Self::Scalar>;
+
+    /// Apply the inverse of the transformation to a vector.
+    fn inverse_transform_vector(&self, vector: &Vector2<Self::Scalar>) -> Vector2<Self::Scalar>;
+}
+
+/// A type with this trait acts as a transformation of points and vectors
+/// in three dimensions.
+///
+/// Every three-dimensional transformation type implements this trait, so that
+/// generic code can accept any kind of transformation without knowing its
+/// concrete type. The trait methods mirror the inherent methods of each
+/// transformation type, e.g. [`Rotation3::rotate_point`] or
+/// [`Scale3::scale_point`].
+///
+/// ## Example
+///
+/// ```
+/// # use cglinalg::{
+/// #     Transformation3,
+/// #     InvertibleTransformation3,
+/// #     Point3,
+/// #     Isometry3,
+/// #     Translation3,
+/// #     Vector3,
+/// # };
+/// # use approx::{
+/// #     relative_eq,
+/// # };
+/// #
+/// fn round_trip<T>(transform: &T, point: &Point3<f64>) -> Point3<f64>
+/// where
+///     T: InvertibleTransformation3<Scalar = f64>
+/// {
+///     transform.inverse().transform_point(&transform.transform_point(point))
+/// }
+///
+/// let point = Point3::new(1_f64, 2_f64, 3_f64);
+/// let translation = Translation3::new(4_f64, 5_f64, 6_f64);
+/// let isometry = Isometry3::from_translation(&translation);
+///
+/// assert!(relative_eq!(round_trip(&translation, &point), point, epsilon = 1e-10));
+/// assert!(relative_eq!(round_trip(&isometry, &point), point, epsilon = 1e-10));
+/// ```
+pub trait Transformation3
+where
+    Self: Sized
+{
+    type Scalar: ScalarFloat;
+
+    /// Construct the identity transformation.
+    fn identity() -> Self;
+
+    /// Apply the transformation to a point.
+    fn transform_point(&self, point: &Point3<Self::Scalar>) -> Point3<Self::Scalar>;
+
+    /// Apply the transformation to a vector.
+    fn transform_vector(&self, vector: &Vector3<Self::Scalar>) -> Vector3<Self::Scalar>;
+
+    /// Convert the transformation into a generic transformation.
+    fn to_transform(&self) -> Transform3<Self::Scalar>;
+}
+
+/// A three-dimensional transformation that always has an inverse.
+///
+/// Every structured transformation type is invertible by construction. A
+/// generic [`Transform3`] is not, because its matrix may be singular.
+pub trait InvertibleTransformation3: Transformation3 {
+    /// Construct the inverse transformation.
+    fn inverse(&self) -> Self;
+
+    /// Apply the inverse of the transformation to a point.
+    fn inverse_transform_point(&self, point: &Point3<Self::Scalar>) -> Point3<Self::Scalar>;
+
+    /// Apply the inverse of the transformation to a vector.
+    fn inverse_transform_vector(&self, vector: &Vector3<Self::Scalar>) -> Vector3<Self::Scalar>;
+}
+
+
+macro_rules! impl_transformation {
+    (
+        $Transformation:ident, $InvertibleTransformation:ident, $Transform:ident,
+        $Type:ident, $Point:ident, $Vector:ident,
+        $transform_point:ident, $transform_vector:ident,
+        $inverse_transform_point:ident, $inverse_transform_vector:ident
+    ) => {
+        impl<S> $Transformation for $Type<S>
+        where
+            S: ScalarFloat
+        {
+            type Scalar = S;
+
+            #[inline]
+            fn identity() -> Self {
+                $Type::identity()
+            }
+
+            #[inline]
+            fn transform_point(&self, point: &$Point<S>) -> $Point<S> {
+                self.$transform_point(point)
+            }
+
+            #[inline]
+            fn transform_vector(&self, vector: &$Vector<S>) -> $Vector<S> {
+                self.$transform_vector(vector)
+            }
+
+            #[inline]
+            fn to_transform(&self) -> $Transform<S> {
+                $Type::to_transform(self)
+            }
+        }
+
+        impl<S> $InvertibleTransformation for $Type<S>
+        where
+            S: ScalarFloat
+        {
+            #[inline]
+            fn inverse(&self) -> Self {
+                $Type::inverse(self)
+            }
+
+            #[inline]
+            fn inverse_transform_point(&self, point: &$Point<S>) -> $Point<S> {
+                self.$inverse_transform_point(point)
+            }
+
+            #[inline]
+            fn inverse_transform_vector(&self, vector: &$Vector<S>) -> $Vector<S> {
+                self.$inverse_transform_vector(vector)
+            }
+        }
+    }
+}
+
+impl_transformation!(
+    Transformation2, InvertibleTransformation2, Transform2,
+    Translation2, Point2, Vector2,
+    translate_point, translate_vector, inverse_translate_point, inverse_translate_vector
+);
+impl_transformation!(
+    Transformation2, InvertibleTransformation2, Transform2,
+    Rotation2, Point2, Vector2,
+    rotate_point, rotate_vector, inverse_rotate_point, inverse_rotate_vector
+);
+impl_transformation!(
+    Transformation2, InvertibleTransformation2, Transform2,
+    Scale2, Point2, Vector2,
+    scale_point, scale_vector, inverse_scale_point, inverse_scale_vector
+);
+impl_transformation!(
+    Transformation2, InvertibleTransformation2, Transform2,
+    Shear2, Point2, Vector2,
+    shear_point, shear_vector, inverse_shear_point, inverse_shear_vector
+);
+impl_transformation!(
+    Transformation2, InvertibleTransformation2, Transform2,
+    Isometry2, Point2, Vector2,
+    transform_point, transform_vector, inverse_transform_point, inverse_transform_vector
+);
+impl_transformation!(
+    Transformation2, InvertibleTransformation2, Transform2,
+    Similarity2, Point2, Vector2,
+    transform_point, transform_vector, inverse_transform_point, inverse_transform_vector
+);
+
+impl_transformation!(
+    Transformation3, InvertibleTransformation3, Transform3,
+    Translation3, Point3, Vector3,
+    translate_point, translate_vector, inverse_translate_point, inverse_translate_vector
+);
+impl_transformation!(
+    Transformation3, InvertibleTransformation3, Transform3,
+    Rotation3, Point3, Vector3,
+    rotate_point, rotate_vector, inverse_rotate_point, inverse_rotate_vector
+);
+impl_transformation!(
+    Transformation3, InvertibleTransformation3, Transform3,
+    Scale3, Point3, Vector3,
+    scale_point, scale_vector, inverse_scale_point, inverse_scale_vector
+);
+impl_transformation!(
+    Transformation3, InvertibleTransformation3, Transform3,
+    Shear3, Point3, Vector3,
+    shear_point, shear_vector, inverse_shear_point, inverse_shear_vector
+);
+impl_transformation!(
+    Transformation3, InvertibleTransformation3, Transform3,
+    Isometry3, Point3, Vector3,
+    transform_point, transform_vector, inverse_transform_point, inverse_transform_vector
+);
+impl_transformation!(
+    Transformation3, InvertibleTransformation3, Transform3,
+    Similarity3, Point3, Vector3,
+    transform_point, transform_vector, inverse_transform_point, inverse_transform_vector
+);
+
+impl<S> Transformation2 for Reflection2<S>
+where
+    S: ScalarFloat
+{
+    type Scalar = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Reflection2::identity()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point2<S>) -> Point2<S> {
+        self.reflect_point(point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        self.reflect_vector(vector)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform2<S> {
+        Reflection2::to_transform(self)
+    }
+}
+
+impl<S> InvertibleTransformation2 for Reflection2<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn inverse(&self) -> Self {
+        Reflection2::inverse(self)
+    }
+
+    #[inline]
+    fn inverse_transform_point(&self, point: &Point2<S>) -> Point2<S> {
+        Reflection2::inverse(self).reflect_point(point)
+    }
+
+    #[inline]
+    fn inverse_transform_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        Reflection2::inverse(self).reflect_vector(vector)
+    }
+}
+
+impl<S> Transformation2 for Transform2<S>
+where
+    S: ScalarFloat
+{
+    type Scalar = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Transform2::identity()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point2<S>) -> Point2<S> {
+        Transform2::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        Transform2::transform_vector(self, vector)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform2<S> {
+        *self
+    }
+}
+
+impl<S> Transformation3 for Reflection3<S>
+where
+    S: ScalarFloat
+{
+    type Scalar = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Reflection3::identity()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        self.reflect_point(point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        self.reflect_vector(vector)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3<S> {
+        Reflection3::to_transform(self)
+    }
+}
+
+impl<S> InvertibleTransformation3 for Reflection3<S>
+where
+    S: ScalarFloat
+{
+    #[inline]
+    fn inverse(&self) -> Self {
+        Reflection3::inverse(self)
+    }
+
+    #[inline]
+    fn inverse_transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        Reflection3::inverse(self).reflect_point(point)
+    }
+
+    #[inline]
+    fn inverse_transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        Reflection3::inverse(self).reflect_vector(vector)
+    }
+}
+
+impl<S> Transformation3 for Transform3<S>
+where
+    S: ScalarFloat
+{
+    type Scalar = S;
+
+    #[inline]
+    fn identity() -> Self {
+        Transform3::identity()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        Transform3::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &Vector3<S>) -> Vector3<S> {
+        Transform3::transform_vector(self, vector)
+    }
+
+    #[inline]
+    fn to_transform(&self) -> Transform3<S> {
+        *self
+    }
+}
@@ -18,6 +18,10 @@ use crate::transform::{
     Transform2,
     Transform3,
 };
+use crate::normal::{
+    Normal2,
+    Normal3,
+};
 
 use core::fmt;
 use core::ops;
@@ -253,6 +257,35 @@ where
         *vector
     }
 
+    /// Apply the translation to a normal vector.
+    ///
+    /// A translation does not change directions, so the normal is returned 
+    /// unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Translation2,
+    /// #     Normal2,
+    /// #     Vector2,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let translation = Translation2::new(1_f64, 2_f64);
+    /// let normal = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let expected = Normal2::from_vector(&Vector2::new(1_f64, 1_f64));
+    /// let result = translation.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal2<S>) -> Normal2<S> {
+        *normal
+    }
+
     /// The identity transformation for translations, which displaces
     /// a vector or point zero distance.
     ///
@@ -693,6 +726,35 @@ where
         *vector
     }
 
+    /// Apply the translation to a normal vector.
+    ///
+    /// A translation does not change directions, so the normal is returned 
+    /// unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Translation3,
+    /// #     Normal3,
+    /// #     Vector3,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let translation = Translation3::new(1_f64, 2_f64, 3_f64);
+    /// let normal = Normal3::from_vector(&Vector3::new(1_f64, 1_f64, 1_f64));
+    /// let expected = Normal3::from_vector(&Vector3::new(1_f64, 1_f64, 1_f64));
+    /// let result = translation.transform_normal(&normal);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn transform_normal(&self, normal: &Normal3<S>) -> Normal3<S> {
+        *normal
+    }
+
     /// The identity transformation for translations, which displaces
     /// a vector or point zero distance.
     ///
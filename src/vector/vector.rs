@@ -5,6 +5,8 @@ use crate::base::{
 };
 use crate::base::{
     Magnitude,
+    fast_rsqrt,
+    use_fast_math,
 };
 use crate::{
     impl_coords,
@@ -2327,7 +2329,11 @@ macro_rules! impl_magnitude {
             
             #[inline]
             fn normalize(&self) -> Self {
-                self / self.magnitude()
+                if use_fast_math::<S>() {
+                    self * fast_rsqrt(self.magnitude_squared())
+                } else {
+                    self / self.magnitude()
+                }
             }
             
             #[inline]
@@ -0,0 +1,416 @@
+use crate::base::{
+    ScalarFloat,
+};
+use crate::matrix::{
+    Matrix4x4,
+};
+use crate::point::{
+    Point3,
+};
+use crate::projection::{
+    Projection3,
+};
+
+use core::fmt;
+
+
+/// The corner of the window that window coordinates are measured from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ViewportOrigin {
+    /// The origin lies at the top left corner of the window, and the
+    /// **y-axis** points down. This is the convention of Direct3D, Vulkan,
+    /// Metal, and most windowing systems.
+    TopLeft,
+    /// The origin lies at the bottom left corner of the window, and the
+    /// **y-axis** points up. This is the convention of OpenGL.
+    BottomLeft,
+}
+
+
+/// A viewport transformation that maps normalized device coordinates to
+/// window coordinates.
+///
+/// The viewport is the rectangle of the window with the corner `(x, y)` and
+/// the size `width x height` in pixels, together with the range of depths
+/// `[min_depth, max_depth]` in the depth buffer. It maps the cube `[-1, 1]^3` of
+/// normalized device coordinates that the projections in this crate produce
+/// onto the rectangle, and the depth range `[-1, 1]` onto `[min_depth, max_depth]`.
+/// The corner `(x, y)` is the corner of the rectangle that lies nearest to the
+/// origin of the window, as determined by the viewport origin.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Viewport<S> {
+    /// The horizontal position of the viewport in the window, in pixels.
+    x: S,
+    /// The vertical position of the viewport in the window, in pixels.
+    y: S,
+    /// The width of the viewport, in pixels.
+    width: S,
+    /// The height of the viewport, in pixels.
+    height: S,
+    /// The window depth that the near plane maps to.
+    min_depth: S,
+    /// The window depth that the far plane maps to.
+    max_depth: S,
+    /// The corner of the window that window coordinates are measured from.
+    origin: ViewportOrigin,
+}
+
+impl<S> Viewport<S> 
+where 
+    S: ScalarFloat 
+{
+    /// Construct a new viewport.
+    #[inline]
+    pub fn new(
+        x: S,
+        y: S,
+        width: S,
+        height: S,
+        min_depth: S,
+        max_depth: S,
+        origin: ViewportOrigin) -> Viewport<S>
+    {
+        Viewport {
+            x: x,
+            y: y,
+            width: width,
+            height: height,
+            min_depth: min_depth,
+            max_depth: max_depth,
+            origin: origin,
+        }
+    }
+
+    /// Construct a viewport that covers a whole window of size `width x height`
+    /// with the depth range `[0, 1]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Viewport,
+    /// #     ViewportOrigin,
+    /// # };
+    /// #
+    /// let viewport = Viewport::from_size(1920_f64, 1080_f64, ViewportOrigin::TopLeft);
+    /// let expected = Viewport::new(
+    ///     0_f64, 0_f64, 1920_f64, 1080_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft
+    /// );
+    ///
+    /// assert_eq!(viewport, expected);
+    /// ```
+    #[inline]
+    pub fn from_size(width: S, height: S, origin: ViewportOrigin) -> Viewport<S> {
+        Viewport::new(S::zero(), S::zero(), width, height, S::zero(), S::one(), origin)
+    }
+
+    /// Get the horizontal position of the viewport in the window, in pixels.
+    #[inline]
+    pub fn x(&self) -> S {
+        self.x
+    }
+
+    /// Get the vertical position of the viewport in the window, in pixels.
+    #[inline]
+    pub fn y(&self) -> S {
+        self.y
+    }
+
+    /// Get the width of the viewport, in pixels.
+    #[inline]
+    pub fn width(&self) -> S {
+        self.width
+    }
+
+    /// Get the height of the viewport, in pixels.
+    #[inline]
+    pub fn height(&self) -> S {
+        self.height
+    }
+
+    /// Get the window depth that the near plane maps to.
+    #[inline]
+    pub fn min_depth(&self) -> S {
+        self.min_depth
+    }
+
+    /// Get the window depth that the far plane maps to.
+    #[inline]
+    pub fn max_depth(&self) -> S {
+        self.max_depth
+    }
+
+    /// Get the corner of the window that window coordinates are measured from.
+    #[inline]
+    pub fn origin(&self) -> ViewportOrigin {
+        self.origin
+    }
+
+    /// Get the aspect ratio of the viewport. The aspect ratio is the ratio of
+    /// the width of the viewport to its height.
+    #[inline]
+    pub fn aspect(&self) -> S {
+        self.width / self.height
+    }
+
+    /// Get the matrix that maps normalized device coordinates to window
+    /// coordinates.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Viewport,
+    /// #     ViewportOrigin,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let viewport = Viewport::new(
+    ///     10_f64, 20_f64, 640_f64, 480_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft
+    /// );
+    /// let point = Point3::new(0.5_f64, -0.25_f64, 0.3_f64);
+    /// let expected = viewport.ndc_to_window(&point);
+    /// let result = Point3::from_homogeneous(viewport.to_matrix() * point.to_homogeneous()).unwrap();
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[rustfmt::skip]
+    pub fn to_matrix(&self) -> Matrix4x4<S> {
+        let zero = S::zero();
+        let one = S::one();
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let half_width = self.width * one_half;
+        let half_height = self.height * one_half;
+        let half_depth = (self.max_depth - self.min_depth) * one_half;
+        let (c1r1, c3r1) = match self.origin {
+            ViewportOrigin::TopLeft => (-half_height, self.y + half_height),
+            ViewportOrigin::BottomLeft => (half_height, self.y + half_height),
+        };
+
+        Matrix4x4::new(
+            half_width,          zero, zero,                        zero,
+            zero,                c1r1, zero,                        zero,
+            zero,                zero, half_depth,                  zero,
+            self.x + half_width, c3r1, self.min_depth + half_depth, one
+        )
+    }
+
+    /// Map a point in normalized device coordinates to window coordinates.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Viewport,
+    /// #     ViewportOrigin,
+    /// # };
+    /// #
+    /// let top_left = Viewport::from_size(800_f64, 600_f64, ViewportOrigin::TopLeft);
+    /// let bottom_left = Viewport::from_size(800_f64, 600_f64, ViewportOrigin::BottomLeft);
+    /// let point = Point3::new(-1_f64, 1_f64, -1_f64);
+    ///
+    /// assert_eq!(top_left.ndc_to_window(&point), Point3::new(0_f64, 0_f64, 0_f64));
+    /// assert_eq!(bottom_left.ndc_to_window(&point), Point3::new(0_f64, 600_f64, 0_f64));
+    /// ```
+    pub fn ndc_to_window(&self, point: &Point3<S>) -> Point3<S> {
+        let one = S::one();
+        let one_half: S = num_traits::cast(0.5).unwrap();
+        let ndc_y = match self.origin {
+            ViewportOrigin::TopLeft => -point.y,
+            ViewportOrigin::BottomLeft => point.y,
+        };
+        let window_x = self.x + (point.x + one) * one_half * self.width;
+        let window_y = self.y + (ndc_y + one) * one_half * self.height;
+        let window_z = self.min_depth + (point.z + one) * one_half * (self.max_depth - self.min_depth);
+
+        Point3::new(window_x, window_y, window_z)
+    }
+
+    /// Map a point in window coordinates to normalized device coordinates.
+    /// This is the inverse of [`Viewport::ndc_to_window`].
+    ///
+    /// The viewport must have a nonzero width, height, and depth range.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Point3,
+    /// #     Viewport,
+    /// #     ViewportOrigin,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let viewport = Viewport::new(
+    ///     100_f64, 50_f64, 1280_f64, 720_f64, 0.25_f64, 0.75_f64, ViewportOrigin::BottomLeft
+    /// );
+    /// let point = Point3::new(0.1_f64, -0.7_f64, 0.4_f64);
+    /// let result = viewport.window_to_ndc(&viewport.ndc_to_window(&point));
+    ///
+    /// assert!(relative_eq!(result, point, epsilon = 1e-10));
+    /// ```
+    pub fn window_to_ndc(&self, point: &Point3<S>) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let ndc_x = (two * (point.x - self.x)) / self.width - one;
+        let ndc_y = (two * (point.y - self.y)) / self.height - one;
+        let ndc_z = (two * (point.z - self.min_depth)) / (self.max_depth - self.min_depth) - one;
+        let ndc_y = match self.origin {
+            ViewportOrigin::TopLeft => -ndc_y,
+            ViewportOrigin::BottomLeft => ndc_y,
+        };
+
+        Point3::new(ndc_x, ndc_y, ndc_z)
+    }
+
+    /// Project a point in camera space to window coordinates with a projection.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Degrees,
+    /// #     PerspectiveFov3,
+    /// #     Point3,
+    /// #     Viewport,
+    /// #     ViewportOrigin,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let viewport = Viewport::from_size(800_f64, 600_f64, ViewportOrigin::TopLeft);
+    /// let perspective = PerspectiveFov3::new(Degrees(90_f64), viewport.aspect(), 1_f64, 100_f64);
+    /// // The point lies on the top edge of the viewing frustum, on the near plane.
+    /// let point = Point3::new(0_f64, 1_f64, -1_f64);
+    /// let expected = Point3::new(400_f64, 0_f64, 0_f64);
+    /// let result = viewport.project_point(&perspective, &point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn project_point<P>(&self, projection: &P, point: &Point3<S>) -> Point3<S>
+    where
+        P: Projection3<Scalar = S>
+    {
+        self.ndc_to_window(&projection.project_point(point))
+    }
+
+    /// Unproject a point in window coordinates back into camera space with
+    /// a projection. This is the inverse of [`Viewport::project_point`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use cglinalg::{
+    /// #     Orthographic3,
+    /// #     Point3,
+    /// #     Viewport,
+    /// #     ViewportOrigin,
+    /// # };
+    /// # use approx::{
+    /// #     relative_eq,
+    /// # };
+    /// #
+    /// let viewport = Viewport::from_size(800_f64, 600_f64, ViewportOrigin::BottomLeft);
+    /// let orthographic = Orthographic3::new(-4_f64, 4_f64, -3_f64, 3_f64, 1_f64, 100_f64);
+    /// // The window pixel at the center of the viewport on the near plane.
+    /// let window_point = Point3::new(400_f64, 300_f64, 0_f64);
+    /// let expected = Point3::new(0_f64, 0_f64, -1_f64);
+    /// let result = viewport.unproject_point(&orthographic, &window_point);
+    ///
+    /// assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    /// ```
+    #[inline]
+    pub fn unproject_point<P>(&self, projection: &P, point: &Point3<S>) -> Point3<S>
+    where
+        P: Projection3<Scalar = S>
+    {
+        projection.unproject_point(&self.window_to_ndc(point))
+    }
+}
+
+impl<S> fmt::Display for Viewport<S> 
+where 
+    S: fmt::Display 
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Viewport [x={}, y={}, width={}, height={}, depth=[{}, {}], origin={:?}]",
+            self.x, self.y, self.width, self.height, self.min_depth, self.max_depth, self.origin
+        )
+    }
+}
+
+impl<S> approx::AbsDiffEq for Viewport<S> 
+where 
+    S: ScalarFloat 
+{
+    type Epsilon = <S as approx::AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        S::abs_diff_eq(&self.x, &other.x, epsilon)
+            && S::abs_diff_eq(&self.y, &other.y, epsilon)
+            && S::abs_diff_eq(&self.width, &other.width, epsilon)
+            && S::abs_diff_eq(&self.height, &other.height, epsilon)
+            && S::abs_diff_eq(&self.min_depth, &other.min_depth, epsilon)
+            && S::abs_diff_eq(&self.max_depth, &other.max_depth, epsilon)
+            && self.origin == other.origin
+    }
+}
+
+impl<S> approx::RelativeEq for Viewport<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.x, &other.x, epsilon, max_relative)
+            && S::relative_eq(&self.y, &other.y, epsilon, max_relative)
+            && S::relative_eq(&self.width, &other.width, epsilon, max_relative)
+            && S::relative_eq(&self.height, &other.height, epsilon, max_relative)
+            && S::relative_eq(&self.min_depth, &other.min_depth, epsilon, max_relative)
+            && S::relative_eq(&self.max_depth, &other.max_depth, epsilon, max_relative)
+            && self.origin == other.origin
+    }
+}
+
+impl<S> approx::UlpsEq for Viewport<S> 
+where 
+    S: ScalarFloat 
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.x, &other.x, epsilon, max_ulps)
+            && S::ulps_eq(&self.y, &other.y, epsilon, max_ulps)
+            && S::ulps_eq(&self.width, &other.width, epsilon, max_ulps)
+            && S::ulps_eq(&self.height, &other.height, epsilon, max_ulps)
+            && S::ulps_eq(&self.min_depth, &other.min_depth, epsilon, max_ulps)
+            && S::ulps_eq(&self.max_depth, &other.max_depth, epsilon, max_ulps)
+            && self.origin == other.origin
+    }
+}
+
@@ -0,0 +1,206 @@
+extern crate cglinalg;
+extern crate num_traits;
+extern crate proptest;
+
+
+use proptest::prelude::*;
+
+
+fn any_angle<S>(bound: f64) -> impl Strategy<Value = S>
+where 
+    S: num_traits::Float + core::fmt::Debug
+{
+    (-bound..=bound).prop_map(|angle| num_traits::cast(angle).unwrap())
+}
+
+fn any_ratio<S>() -> impl Strategy<Value = S>
+where 
+    S: num_traits::Float + core::fmt::Debug
+{
+    (-1_f64..=1_f64).prop_map(|ratio| num_traits::cast(ratio).unwrap())
+}
+
+fn any_coordinate<S>() -> impl Strategy<Value = S>
+where 
+    S: num_traits::Float + core::fmt::Debug
+{
+    (-1_000_000_f64..=1_000_000_f64).prop_map(|coordinate| num_traits::cast(coordinate).unwrap())
+}
+
+fn any_positive<S>() -> impl Strategy<Value = S>
+where 
+    S: num_traits::Float + core::fmt::Debug
+{
+    (-30_f64..=30_f64).prop_map(|exponent| num_traits::cast(10_f64.powf(exponent)).unwrap())
+}
+
+
+/// Generate property tests for the error bounds of the fast approximate 
+/// functions against the reference implementations in `num_traits::Float`.
+///
+/// ### Macro Parameters
+///
+/// The macro parameters are the following:
+/// * `$TestModuleName` is a name we give to the module we place the property 
+///   tests in to separate them from each other for each scalar type to prevent 
+///   namespace collisions.
+/// * `$ScalarType` denotes the underlying system of numbers.
+/// * `$angle_bound` is the largest magnitude of the angles generated for the
+///   sine and cosine.
+/// * `$trig_tolerance` is the largest acceptable absolute error of the 
+///   trigonometric functions.
+/// * `$inverse_tolerance` is the largest acceptable absolute error of the 
+///   inverse trigonometric functions.
+/// * `$rsqrt_tolerance` is the largest acceptable relative error of the 
+///   reciprocal square root.
+macro_rules! fast_math_props {
+    ($TestModuleName:ident, $ScalarType:ty, $angle_bound:expr, $trig_tolerance:expr, $inverse_tolerance:expr, $rsqrt_tolerance:expr) => {
+    #[cfg(test)]
+    mod $TestModuleName {
+        use proptest::prelude::*;
+        use cglinalg::{
+            fast_acos,
+            fast_atan2,
+            fast_cos,
+            fast_rsqrt,
+            fast_sin,
+        };
+        use super::{
+            any_angle,
+            any_coordinate,
+            any_positive,
+            any_ratio,
+        };
+
+
+        proptest! {
+            /// The fast sine approximates the sine.
+            ///
+            /// Given an angle `angle`
+            /// ```text
+            /// |fast_sin(angle) - sin(angle)| <= tolerance
+            /// ```
+            #[test]
+            fn prop_fast_sin_error_bound(angle in any_angle::<$ScalarType>($angle_bound)) {
+                let error = (fast_sin(angle) - angle.sin()).abs();
+
+                prop_assert!(error <= $trig_tolerance, "angle = {}, error = {}", angle, error);
+            }
+
+            /// The fast cosine approximates the cosine.
+            ///
+            /// Given an angle `angle`
+            /// ```text
+            /// |fast_cos(angle) - cos(angle)| <= tolerance
+            /// ```
+            #[test]
+            fn prop_fast_cos_error_bound(angle in any_angle::<$ScalarType>($angle_bound)) {
+                let error = (fast_cos(angle) - angle.cos()).abs();
+
+                prop_assert!(error <= $trig_tolerance, "angle = {}, error = {}", angle, error);
+            }
+
+            /// The fast four quadrant arc tangent approximates the four quadrant
+            /// arc tangent.
+            ///
+            /// Given numbers `y` and `x`
+            /// ```text
+            /// |fast_atan2(y, x) - atan2(y, x)| <= tolerance
+            /// ```
+            #[test]
+            fn prop_fast_atan2_error_bound(y in any_coordinate::<$ScalarType>(), x in any_coordinate::<$ScalarType>()) {
+                let error = (fast_atan2(y, x) - y.atan2(x)).abs();
+
+                prop_assert!(error <= $inverse_tolerance, "y = {}, x = {}, error = {}", y, x, error);
+            }
+
+            /// The fast arc cosine approximates the arc cosine.
+            ///
+            /// Given a ratio `ratio` in `[-1, 1]`
+            /// ```text
+            /// |fast_acos(ratio) - acos(ratio)| <= tolerance
+            /// ```
+            #[test]
+            fn prop_fast_acos_error_bound(ratio in any_ratio::<$ScalarType>()) {
+                let error = (fast_acos(ratio) - ratio.acos()).abs();
+
+                prop_assert!(error <= $inverse_tolerance, "ratio = {}, error = {}", ratio, error);
+            }
+
+            /// The fast reciprocal square root approximates the reciprocal square
+            /// root with a bounded relative error.
+            ///
+            /// Given a positive number `value`
+            /// ```text
+            /// |fast_rsqrt(value) * sqrt(value) - 1| <= tolerance
+            /// ```
+            #[test]
+            fn prop_fast_rsqrt_error_bound(value in any_positive::<$ScalarType>()) {
+                let one: $ScalarType = num_traits::one();
+                let error = (fast_rsqrt(value) * value.sqrt() - one).abs();
+
+                prop_assert!(error <= $rsqrt_tolerance, "value = {}, error = {}", value, error);
+            }
+        }
+    }
+    }
+}
+
+fast_math_props!(fast_math_f64_props, f64, 1000_f64, 1e-7, 5e-8, 1e-15);
+fast_math_props!(fast_math_f32_props, f32, 2_f64 * core::f64::consts::PI, 1e-6, 1e-6, 5e-6);
+
+
+/// With the `fast-math` feature enabled, the trigonometry of single precision 
+/// typed angles and single precision vector normalization use the fast 
+/// approximations, while double precision results do not change.
+#[cfg(feature = "fast-math")]
+#[cfg(test)]
+mod fast_math_feature_props {
+    use proptest::prelude::*;
+    use approx::relative_eq;
+    use cglinalg::{
+        Angle,
+        Degrees,
+        Magnitude,
+        Radians,
+        Vector3,
+    };
+    use super::{
+        any_angle,
+        any_coordinate,
+    };
+
+
+    proptest! {
+        #[test]
+        fn prop_f32_typed_angle_trigonometry_error_bound(angle in any_angle::<f32>(2_f64 * core::f64::consts::PI)) {
+            prop_assert!(relative_eq!(Radians(angle).sin(), angle.sin(), epsilon = 1e-6));
+            prop_assert!(relative_eq!(Radians(angle).cos(), angle.cos(), epsilon = 1e-6));
+            prop_assert!(relative_eq!(Degrees::from(Radians(angle)).sin(), angle.sin(), epsilon = 2e-6));
+        }
+
+        #[test]
+        fn prop_f32_normalize_error_bound(
+            x in any_coordinate::<f32>(), y in any_coordinate::<f32>(), z in any_coordinate::<f32>()) {
+
+            let vector = Vector3::new(x, y, z);
+            prop_assume!(vector.magnitude() > 1e-3);
+
+            prop_assert!(relative_eq!(vector.normalize().magnitude(), 1_f32, epsilon = 1e-5));
+        }
+
+        #[test]
+        fn prop_f64_results_unchanged(
+            angle in any_angle::<f64>(1000_f64), 
+            x in any_coordinate::<f64>(), y in any_coordinate::<f64>(), z in any_coordinate::<f64>()) {
+
+            let vector = Vector3::new(x, y, z);
+
+            prop_assert_eq!(Radians(angle).sin(), angle.sin());
+            prop_assert_eq!(Radians(angle).cos(), angle.cos());
+            prop_assert_eq!(Radians::acos(angle / 1000_f64), Radians((angle / 1000_f64).acos()));
+            prop_assert_eq!(Radians::atan2(y, x), Radians(y.atan2(x)));
+            prop_assert_eq!(vector.normalize(), vector / vector.magnitude());
+        }
+    }
+}
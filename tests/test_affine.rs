@@ -0,0 +1,329 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod affine2_tests {
+    use cglinalg::{
+        Affine2,
+        Degrees,
+        Isometry2,
+        Matrix2x2,
+        Matrix2x3,
+        Matrix3x3,
+        Normal2,
+        Point2,
+        Rotation2,
+        Scale2,
+        Shear2,
+        Transform2,
+        Translation2,
+        Vector2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_from_parts() {
+        let linear = Matrix2x2::new(2_f64, 1_f64, -1_f64, 3_f64);
+        let translation = Vector2::new(4_f64, 5_f64);
+        let expected = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let result = Affine2::from_parts(&linear, &translation);
+
+        assert_eq!(result, expected);
+        assert_eq!(result.linear(), linear);
+        assert_eq!(result.translation(), translation);
+    }
+
+    #[test]
+    fn test_transform_point_matches_transform() {
+        let affine = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let point = Point2::new(-3_f64, 7_f64);
+        let expected = affine.to_transform().transform_point(&point);
+        let result = affine.transform_point(&point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        assert!(relative_eq!(affine * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let affine = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let affine_inv = affine.inverse().unwrap();
+        let expected = affine.to_transform().inverse().unwrap();
+
+        assert!(relative_eq!(affine_inv.to_transform(), expected, epsilon = 1e-10));
+        assert!(relative_eq!(affine * affine_inv, Affine2::identity(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let affine = Affine2::from_parts(&Matrix2x2::new(1_f64, 2_f64, 2_f64, 4_f64), &Vector2::zero());
+
+        assert!(affine.inverse().is_none());
+        assert!(!affine.is_invertible());
+    }
+
+    #[test]
+    fn test_transform_normal_stays_perpendicular() {
+        let affine = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let tangent = Vector2::new(1_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 1_f64));
+        let transformed_tangent = affine.transform_vector(&tangent);
+        let transformed_normal = affine.transform_normal(&normal).unwrap();
+
+        assert!(relative_eq!(transformed_normal.dot(&transformed_tangent), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_try_from_affine_matrix() {
+        let affine = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let matrix = affine.to_affine_matrix();
+        let projective = Matrix3x3::new(
+            1_f64, 0_f64, 1_f64,
+            0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 1_f64
+        );
+
+        assert_eq!(Affine2::try_from_affine_matrix(&matrix), Some(affine));
+        assert!(Affine2::try_from_affine_matrix(&projective).is_none());
+    }
+
+    #[test]
+    fn test_composition_with_structured_types() {
+        let affine = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let point = Point2::new(-3_f64, 7_f64);
+        let rotation = Rotation2::from_angle(Degrees(72_f64));
+        let translation = Translation2::new(1_f64, -2_f64);
+        let scale = Scale2::from_nonuniform_scale(2_f64, 3_f64);
+        let shear = Shear2::from_shear_x(2_f64);
+        let isometry = Isometry2::from_parts(&translation, &rotation);
+
+        assert!(relative_eq!((affine * rotation) * point, affine * (rotation * point), epsilon = 1e-10));
+        assert!(relative_eq!((translation * affine) * point, translation * (affine * point), epsilon = 1e-10));
+        assert!(relative_eq!((scale * affine) * point, scale * (affine * point), epsilon = 1e-10));
+        assert!(relative_eq!((affine * shear) * point, affine * (shear * point), epsilon = 1e-10));
+        assert!(relative_eq!((isometry * affine) * point, isometry * (affine * point), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_composition_with_transform() {
+        let affine = Affine2::from_matrix(Matrix2x3::new(
+            2_f64, 1_f64,
+            -1_f64, 3_f64,
+            4_f64, 5_f64
+        ));
+        let point = Point2::new(-3_f64, 7_f64);
+        let transform = Transform2::from_matrix_unchecked(Matrix3x3::new(
+            1_f64, 0_f64, 0.5_f64,
+            0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 1_f64
+        ));
+        let result: Transform2<f64> = transform * affine;
+        let expected = transform * (affine * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod affine3_tests {
+    use cglinalg::{
+        Affine3,
+        Degrees,
+        Matrix3x3,
+        Matrix3x4,
+        Matrix4x4,
+        Normal3,
+        Point3,
+        Reflection3,
+        Rotation3,
+        Scale3,
+        Similarity3,
+        Transform3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_transform_point_matches_transform() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let point = Point3::new(-3_f64, 7_f64, 2_f64);
+        let expected = affine.to_transform().transform_point(&point);
+        let result = affine.transform_point(&point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_vector_ignores_translation() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let vector = Vector3::new(-3_f64, 7_f64, 2_f64);
+        let expected = affine.linear() * vector;
+        let result = affine.transform_vector(&vector);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let affine_inv = affine.inverse().unwrap();
+        let expected = affine.to_transform().inverse().unwrap();
+
+        assert!(relative_eq!(affine_inv.to_transform(), expected, epsilon = 1e-10));
+        assert!(relative_eq!(affine_inv * affine, Affine3::identity(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse_transform_point() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let point = Point3::new(-3_f64, 7_f64, 2_f64);
+        let result = affine.inverse_transform_point(&affine.transform_point(&point)).unwrap();
+
+        assert!(relative_eq!(result, point, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_normal_stays_perpendicular() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let transformed_normal = affine.transform_normal(&normal).unwrap();
+
+        assert!(relative_eq!(transformed_normal.dot(&affine.transform_vector(&tangent1)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(transformed_normal.dot(&affine.transform_vector(&tangent2)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_normal_singular() {
+        let affine: Affine3<f64> = Affine3::from_parts(&Matrix3x3::zero(), &Vector3::zero());
+
+        assert!(affine.transform_normal(&Normal3::unit_z()).is_none());
+    }
+
+    #[test]
+    fn test_to_affine_matrix_round_trip() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let matrix = affine.to_affine_matrix();
+
+        assert_eq!(Affine3::try_from_affine_matrix(&matrix), Some(affine));
+        assert_eq!(Matrix4x4::from(affine), matrix);
+    }
+
+    #[test]
+    fn test_from_structured_types() {
+        let point = Point3::new(-3_f64, 7_f64, 2_f64);
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let similarity = Similarity3::from_parts(&Translation3::new(1_f64, 2_f64, 3_f64), &rotation, 2_f64);
+        let reflection = Reflection3::from_normal_bias(&axis, &Vector3::new(1_f64, 0_f64, 0_f64));
+
+        assert!(relative_eq!(Affine3::from(rotation) * point, rotation * point, epsilon = 1e-10));
+        assert!(relative_eq!(Affine3::from(similarity) * point, similarity * point, epsilon = 1e-10));
+        assert!(relative_eq!(Affine3::from(&reflection) * point, reflection * point, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_composition_with_structured_types() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let point = Point3::new(-3_f64, 7_f64, 2_f64);
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let scale = Scale3::from_nonuniform_scale(2_f64, 3_f64, 0.5_f64);
+        let similarity = Similarity3::from_parts(&Translation3::new(1_f64, 2_f64, 3_f64), &rotation, 2_f64);
+
+        assert!(relative_eq!((affine * rotation) * point, affine * (rotation * point), epsilon = 1e-10));
+        assert!(relative_eq!((scale * affine) * point, scale * (affine * point), epsilon = 1e-10));
+        assert!(relative_eq!((affine * similarity) * point, affine * (similarity * point), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_composition_with_transform() {
+        let affine = Affine3::from_matrix(Matrix3x4::new(
+            2_f64, 1_f64, 0_f64,
+            -1_f64, 3_f64, 1_f64,
+            0_f64, 1_f64, 4_f64,
+            4_f64, 5_f64, 6_f64
+        ));
+        let point = Point3::new(-3_f64, 7_f64, 2_f64);
+        let transform = Transform3::from_matrix_unchecked(Matrix4x4::new(
+            1_f64, 0_f64, 0_f64, 0.5_f64,
+            0_f64, 1_f64, 0_f64, 0_f64,
+            0_f64, 0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 0_f64, 1_f64
+        ));
+        let result: Transform3<f64> = affine * transform;
+        let expected = affine * (transform * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+}
+
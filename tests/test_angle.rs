@@ -706,3 +706,350 @@ mod degree_angle_tests {
         assert!(relative_eq!(result, expected, epsilon = 1e-10));
     }
 }
+
+#[cfg(test)]
+mod turn_gradian_conversion_tests {
+    use cglinalg::{
+        Degrees,
+        Gradians,
+        Radians,
+        Turns,
+    };
+    use approx::relative_eq;
+    use core::f64;
+
+
+    #[test]
+    fn test_turns_to_other_units() {
+        let angle = Turns(0.25_f64);
+
+        assert!(relative_eq!(Radians::from(angle), Radians(f64::consts::FRAC_PI_2), epsilon = 1e-10));
+        assert_eq!(Degrees::from(angle), Degrees(90_f64));
+        assert_eq!(Gradians::from(angle), Gradians(100_f64));
+    }
+
+    #[test]
+    fn test_gradians_to_other_units() {
+        let angle = Gradians(50_f64);
+
+        assert!(relative_eq!(Radians::from(angle), Radians(f64::consts::FRAC_PI_4), epsilon = 1e-10));
+        assert_eq!(Degrees::from(angle), Degrees(45_f64));
+        assert_eq!(Turns::from(angle), Turns(0.125_f64));
+    }
+
+    #[test]
+    fn test_other_units_to_turns_and_gradians() {
+        assert!(relative_eq!(Turns::from(Radians(f64::consts::PI)), Turns(0.5_f64), epsilon = 1e-10));
+        assert!(relative_eq!(Gradians::from(Radians(f64::consts::PI)), Gradians(200_f64), epsilon = 1e-10));
+        assert_eq!(Turns::from(Degrees(270_f64)), Turns(0.75_f64));
+        assert_eq!(Gradians::from(Degrees(270_f64)), Gradians(300_f64));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Turns(0.5_f64)), "0.5 turns");
+        assert_eq!(format!("{}", Gradians(100_f64)), "100 gradians");
+    }
+}
+
+#[cfg(test)]
+mod turn_angle_tests {
+    use cglinalg::{
+        Angle,
+        Turns,
+    };
+    use approx::relative_eq;
+
+
+    #[test]
+    fn test_full_turn() {
+        assert_eq!(Turns::full_turn(), Turns(1_f64));
+        assert_eq!(Turns::full_turn_div_4(), Turns(0.25_f64));
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        let (sin, cos) = Turns(1_f64 / 12_f64).sin_cos();
+
+        assert!(relative_eq!(sin, 1_f64 / 2_f64, epsilon = 1e-10));
+        assert!(relative_eq!(cos, f64::sqrt(3_f64) / 2_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_atan2() {
+        let result: Turns<f64> = Turns::atan2(1_f64, 0_f64);
+
+        assert!(relative_eq!(result, Turns(0.25_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Turns(2.25_f64).normalize(), Turns(0.25_f64));
+        assert_eq!(Turns(-0.25_f64).normalize(), Turns(0.75_f64));
+        assert_eq!(Turns(0.75_f64).normalize_signed(), Turns(-0.25_f64));
+    }
+}
+
+#[cfg(test)]
+mod gradian_angle_tests {
+    use cglinalg::{
+        Angle,
+        Gradians,
+    };
+    use approx::relative_eq;
+
+
+    #[test]
+    fn test_full_turn() {
+        assert_eq!(Gradians::full_turn(), Gradians(400_f64));
+        assert_eq!(Gradians::full_turn_div_4(), Gradians(100_f64));
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        let (sin, cos) = Gradians(100_f64).sin_cos();
+
+        assert!(relative_eq!(sin, 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(cos, 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_acos() {
+        let result: Gradians<f64> = Gradians::acos(0_f64);
+
+        assert!(relative_eq!(result, Gradians(100_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_opposite() {
+        assert_eq!(Gradians(50_f64).opposite(), Gradians(250_f64));
+    }
+}
+
+#[cfg(test)]
+mod degrees_minutes_seconds_tests {
+    use cglinalg::{
+        Degrees,
+        DegreesMinutesSeconds,
+        Gradians,
+        Radians,
+        Turns,
+    };
+    use approx::relative_eq;
+
+
+    #[test]
+    fn test_to_degrees() {
+        let angle = DegreesMinutesSeconds::new(12_f64, 30_f64, 36_f64).unwrap();
+
+        assert_eq!(Degrees::from(angle), Degrees(12.51_f64));
+    }
+
+    #[test]
+    fn test_from_degrees() {
+        let angle = DegreesMinutesSeconds::from(Degrees(-12.51_f64));
+
+        assert!(angle.is_negative());
+        assert_eq!(angle.degrees(), 12_f64);
+        assert_eq!(angle.minutes(), 30_f64);
+        assert!(relative_eq!(angle.seconds(), 36_f64, epsilon = 1e-8));
+    }
+
+    #[test]
+    fn test_total_seconds_round_trip_is_exact() {
+        for total_seconds in (-1_296_000_i32..1_296_000_i32).step_by(997) {
+            let total_seconds = total_seconds as f64 + 0.125_f64;
+            let angle = DegreesMinutesSeconds::from_total_seconds(total_seconds);
+
+            assert_eq!(angle.total_seconds(), total_seconds);
+        }
+    }
+
+    #[test]
+    fn test_degrees_round_trip() {
+        for total_seconds in (-1_296_000_i32..1_296_000_i32).step_by(997) {
+            let angle = DegreesMinutesSeconds::from_total_seconds(total_seconds as f64);
+            let result = DegreesMinutesSeconds::from(Degrees::from(angle));
+
+            assert!(relative_eq!(result, angle, epsilon = 1e-12));
+            assert!(relative_eq!(result.total_seconds(), angle.total_seconds(), epsilon = 1e-8));
+        }
+    }
+
+    #[test]
+    fn test_negative_angle_smaller_than_one_degree() {
+        let angle = DegreesMinutesSeconds::new(-0_f64, 15_f64, 0_f64).unwrap();
+
+        assert!(angle.is_negative());
+        assert_eq!(Degrees::from(angle), Degrees(-0.25_f64));
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_components() {
+        assert!(DegreesMinutesSeconds::new(12.5_f64, 0_f64, 0_f64).is_none());
+        assert!(DegreesMinutesSeconds::new(12_f64, 1.5_f64, 0_f64).is_none());
+        assert!(DegreesMinutesSeconds::new(12_f64, 60_f64, 0_f64).is_none());
+        assert!(DegreesMinutesSeconds::new(12_f64, 0_f64, 60_f64).is_none());
+        assert!(DegreesMinutesSeconds::new(12_f64, -1_f64, 0_f64).is_none());
+        assert!(DegreesMinutesSeconds::new(f64::NAN, 0_f64, 0_f64).is_none());
+    }
+
+    #[test]
+    fn test_conversions_to_other_units() {
+        let angle = DegreesMinutesSeconds::new(90_f64, 0_f64, 0_f64).unwrap();
+
+        assert!(relative_eq!(Radians::from(angle), Radians(core::f64::consts::FRAC_PI_2), epsilon = 1e-10));
+        assert_eq!(Turns::from(angle), Turns(0.25_f64));
+        assert_eq!(Gradians::from(angle), Gradians(100_f64));
+        assert_eq!(DegreesMinutesSeconds::from(Gradians(100_f64)), angle);
+        assert_eq!(DegreesMinutesSeconds::from(Turns(0.25_f64)), angle);
+    }
+
+    #[test]
+    fn test_display() {
+        let angle = DegreesMinutesSeconds::new(-12_f64, 30_f64, 36_f64).unwrap();
+
+        assert_eq!(format!("{}", angle), "-12° 30' 36\"");
+    }
+}
+
+#[cfg(test)]
+mod angle_interpolation_tests {
+    use cglinalg::{
+        Angle,
+        Degrees,
+        Radians,
+    };
+    use approx::relative_eq;
+    use core::f64;
+
+
+    #[test]
+    fn test_signed_difference_across_zero() {
+        assert!(relative_eq!(Degrees(350_f64).signed_difference(Degrees(10_f64)), Degrees(20_f64), epsilon = 1e-10));
+        assert!(relative_eq!(Degrees(10_f64).signed_difference(Degrees(350_f64)), Degrees(-20_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_signed_difference_of_unnormalized_angles() {
+        let angle1 = Radians(-3_f64 * f64::consts::PI);
+        let angle2 = Radians(5_f64 * f64::consts::FRAC_PI_2);
+        let expected = Radians(-f64::consts::FRAC_PI_2);
+        let result = angle1.signed_difference(angle2);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_shortest_endpoints() {
+        let angle1 = Degrees(300_f64);
+        let angle2 = Degrees(60_f64);
+
+        assert_eq!(angle1.lerp_shortest(angle2, 0_f64), angle1);
+        assert!(relative_eq!(angle1.lerp_shortest(angle2, 1_f64).normalize(), angle2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_shortest_takes_short_arc() {
+        let angle1 = Degrees(300_f64);
+        let angle2 = Degrees(60_f64);
+        let result = angle1.lerp_shortest(angle2, 0.5_f64);
+
+        assert!(relative_eq!(result, Degrees(360_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_smooth_damp_converges_without_overshoot() {
+        let target = Degrees(20_f64);
+        let mut angle = Degrees(300_f64);
+        let mut velocity = Degrees(0_f64);
+        let mut previous_distance = angle.signed_difference(target);
+        for _ in 0..300 {
+            angle = angle.smooth_damp(target, &mut velocity, 0.25_f64, 1_f64 / 60_f64);
+            let distance = angle.signed_difference(target);
+
+            assert!(distance.0 >= 0_f64);
+            assert!(distance.0 <= previous_distance.0);
+            previous_distance = distance;
+        }
+
+        assert!(relative_eq!(angle.signed_difference(target), Degrees(0_f64), epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_smooth_damp_at_target_stays_at_target() {
+        let target = Radians(1_f64);
+        let mut velocity = Radians(0_f64);
+        let result = target.smooth_damp(target, &mut velocity, 0.5_f64, 0.1_f64);
+
+        assert_eq!(result, target);
+        assert_eq!(velocity, Radians(0_f64));
+    }
+}
+
+#[cfg(test)]
+mod angle_range_tests {
+    use cglinalg::{
+        Angle,
+        AngleRange,
+        Degrees,
+    };
+    use approx::relative_eq;
+
+
+    #[test]
+    fn test_range_contains() {
+        let range = AngleRange::new(Degrees(30_f64), Degrees(120_f64));
+
+        assert!(range.contains(Degrees(30_f64)));
+        assert!(range.contains(Degrees(90_f64)));
+        assert!(range.contains(Degrees(120_f64)));
+        assert!(range.contains(Degrees(450_f64)));
+        assert!(!range.contains(Degrees(200_f64)));
+        assert!(!range.contains(Degrees(0_f64)));
+    }
+
+    #[test]
+    fn test_range_wrapping_zero() {
+        let range = AngleRange::new(Degrees(300_f64), Degrees(60_f64));
+
+        assert!(relative_eq!(range.length(), Degrees(120_f64), epsilon = 1e-10));
+        assert!(relative_eq!(range.midpoint(), Degrees(0_f64), epsilon = 1e-10));
+        assert!(range.contains(Degrees(0_f64)));
+        assert!(range.contains(Degrees(-30_f64)));
+        assert!(!range.contains(Degrees(180_f64)));
+    }
+
+    #[test]
+    fn test_range_display_normalizes_end() {
+        let range = AngleRange::new(Degrees(350_f64), Degrees(10_f64));
+
+        assert_eq!(format!("{}", range), "AngleRange [350 degrees, 10 degrees]");
+    }
+
+    #[test]
+    fn test_range_clamp() {
+        let range = AngleRange::new(Degrees(30_f64), Degrees(120_f64));
+
+        assert_eq!(range.clamp(Degrees(60_f64)), Degrees(60_f64));
+        assert!(relative_eq!(range.clamp(Degrees(150_f64)), Degrees(120_f64), epsilon = 1e-10));
+        assert!(relative_eq!(range.clamp(Degrees(0_f64)), Degrees(30_f64), epsilon = 1e-10));
+        assert!(relative_eq!(range.clamp(Degrees(-150_f64)), Degrees(120_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_full_range() {
+        let range: AngleRange<Degrees<f64>> = AngleRange::full();
+
+        assert_eq!(range.length(), Degrees::full_turn());
+        assert!(range.contains(Degrees(359.9_f64)));
+        assert_eq!(range.clamp(Degrees(200_f64)), Degrees(200_f64));
+    }
+
+    #[test]
+    fn test_from_length() {
+        assert!(AngleRange::from_length(Degrees(10_f64), Degrees(360_f64)).is_some());
+        assert!(AngleRange::from_length(Degrees(10_f64), Degrees(361_f64)).is_none());
+        assert!(AngleRange::from_length(Degrees(10_f64), Degrees(-1_f64)).is_none());
+    }
+}
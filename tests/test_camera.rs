@@ -0,0 +1,184 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod brown_conrady_tests {
+    use cglinalg::{
+        BrownConrady,
+        Point2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_zero_distortion_is_identity() {
+        let distortion: BrownConrady<f64> = BrownConrady::zero();
+        let point = Point2::new(-0.7_f64, 0.45_f64);
+
+        assert_eq!(distortion.distort(&point), point);
+        assert_eq!(distortion.undistort(&point), Some(point));
+    }
+
+    #[test]
+    fn test_tangential_distortion() {
+        let distortion = BrownConrady::new(0_f64, 0_f64, 0_f64, 0.01_f64, 0.02_f64);
+        let point = Point2::new(0.5_f64, 0.25_f64);
+        let r2 = 0.5_f64 * 0.5_f64 + 0.25_f64 * 0.25_f64;
+        let expected = Point2::new(
+            0.5_f64 + 2_f64 * 0.01_f64 * 0.5_f64 * 0.25_f64 + 0.02_f64 * (r2 + 2_f64 * 0.25_f64),
+            0.25_f64 + 0.01_f64 * (r2 + 2_f64 * 0.0625_f64) + 2_f64 * 0.02_f64 * 0.5_f64 * 0.25_f64
+        );
+        let result = distortion.distort(&point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_undistort_inverts_distort() {
+        let distortion = BrownConrady::new(-0.28_f64, 0.07_f64, -0.01_f64, 0.0012_f64, -0.0008_f64);
+        for &(x, y) in [(0_f64, 0_f64), (0.1_f64, 0.2_f64), (-0.5_f64, 0.35_f64), (0.6_f64, -0.4_f64)].iter() {
+            let point = Point2::new(x, y);
+            let distorted = distortion.distort(&point);
+            let result = distortion.undistort(&distorted).unwrap();
+
+            assert!(relative_eq!(result, point, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_undistort_fails_past_fold_of_distortion() {
+        let distortion = BrownConrady::new(-1_f64, 0_f64, 0_f64, 0_f64, 0_f64);
+        let point = Point2::new(2_f64, 0_f64);
+
+        assert!(distortion.undistort(&point).is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod camera_intrinsics_tests {
+    use cglinalg::{
+        BrownConrady,
+        CameraIntrinsics,
+        Degrees,
+        Matrix3x3,
+        Perspective3,
+        PerspectiveFov3,
+        Point2,
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let intrinsics = CameraIntrinsics::new(812_f64, 806_f64, 331_f64, 247_f64, 1.5_f64);
+        let result = CameraIntrinsics::from_matrix(&intrinsics.to_matrix()).unwrap();
+
+        assert_eq!(result, intrinsics);
+    }
+
+    #[test]
+    fn test_from_matrix_rescales() {
+        let intrinsics = CameraIntrinsics::new(812_f64, 806_f64, 331_f64, 247_f64, 0_f64);
+        let matrix = intrinsics.to_matrix() * 2_f64;
+        let result = CameraIntrinsics::from_matrix(&matrix).unwrap();
+
+        assert!(relative_eq!(result, intrinsics, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_matrix_not_upper_triangular() {
+        let matrix = Matrix3x3::new(
+            800_f64, 0_f64,   1_f64,
+            0_f64,   800_f64, 0_f64,
+            320_f64, 240_f64, 1_f64
+        );
+
+        assert!(CameraIntrinsics::from_matrix(&matrix).is_none());
+    }
+
+    #[test]
+    fn test_project_point_matches_matrix() {
+        let intrinsics = CameraIntrinsics::new(812_f64, 806_f64, 331_f64, 247_f64, 1.5_f64);
+        let point = Point3::new(0.3_f64, -0.7_f64, 2.5_f64);
+        let homogeneous = intrinsics.to_matrix() * point.to_vector();
+        let expected = Point2::from_homogeneous(homogeneous).unwrap();
+        let result = intrinsics.project_point(&point).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_project_point_behind_camera() {
+        let intrinsics = CameraIntrinsics::new(812_f64, 806_f64, 331_f64, 247_f64, 0_f64);
+
+        assert!(intrinsics.project_point(&Point3::new(0_f64, 0_f64, 0_f64)).is_none());
+        assert!(intrinsics.project_point(&Point3::new(1_f64, 2_f64, -3_f64)).is_none());
+    }
+
+    #[test]
+    fn test_unproject_principal_point_is_optical_axis() {
+        let intrinsics = CameraIntrinsics::new(812_f64, 806_f64, 331_f64, 247_f64, 0_f64);
+        let result = intrinsics.unproject_pixel(&intrinsics.principal_point()).unwrap();
+        let expected = Unit::from_value(Vector3::unit_z());
+
+        assert!(relative_eq!(result.into_inner(), expected.into_inner(), epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_unproject_pixel_inverts_project_point_with_distortion() {
+        let distortion = BrownConrady::new(-0.21_f64, 0.04_f64, 0_f64, 0.0015_f64, -0.001_f64);
+        let intrinsics = CameraIntrinsics::new(812_f64, 806_f64, 331_f64, 247_f64, 0.8_f64)
+            .with_distortion(&distortion);
+        let point = Point3::new(-1.2_f64, 0.9_f64, 3_f64);
+        let pixel = intrinsics.project_point(&point).unwrap();
+        let result = intrinsics.unproject_pixel(&pixel).unwrap();
+        let expected = Unit::from_value(point.to_vector());
+
+        assert!(relative_eq!(result.into_inner(), expected.into_inner(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_perspective_round_trip() {
+        let perspective = Perspective3::new(-0.4_f64, 0.6_f64, -0.3_f64, 0.45_f64, 0.5_f64, 200_f64);
+        let intrinsics = CameraIntrinsics::from_perspective(&perspective, 1280_f64, 720_f64);
+        let result = intrinsics.to_perspective(1280_f64, 720_f64, 0.5_f64, 200_f64);
+
+        assert!(relative_eq!(result, perspective, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_perspective_fov_round_trip() {
+        let perspective = PerspectiveFov3::new(Degrees(60_f64), 16_f64 / 9_f64, 0.1_f64, 50_f64);
+        let intrinsics = CameraIntrinsics::from_perspective_fov(&perspective, 1920_f64, 1080_f64);
+        let result = intrinsics.to_perspective_fov(1920_f64, 1080_f64, 0.1_f64, 50_f64);
+
+        assert!(relative_eq!(result, perspective, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_pixel_agrees_with_perspective_projection() {
+        let (width, height) = (640_f64, 480_f64);
+        let perspective = Perspective3::new(-0.5_f64, 0.7_f64, -0.4_f64, 0.5_f64, 1_f64, 100_f64);
+        let intrinsics = CameraIntrinsics::from_perspective(&perspective, width, height);
+        // The same point in the view space of the projection and in the
+        // camera space of the intrinsics.
+        let view_point = Point3::new(0.8_f64, 0.6_f64, -4_f64);
+        let camera_point = Point3::new(view_point.x, -view_point.y, -view_point.z);
+        let ndc = Point3::from_homogeneous(perspective.matrix() * view_point.to_homogeneous()).unwrap();
+        let expected = Point2::new(
+            (ndc.x + 1_f64) * width / 2_f64,
+            (1_f64 - ndc.y) * height / 2_f64
+        );
+        let result = intrinsics.project_point(&camera_point).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+}
@@ -0,0 +1,300 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod complex_tests {
+    use cglinalg::{
+        Complex,
+        Magnitude,
+    };
+    use approx::{
+        relative_eq,
+    };
+    use core::f64;
+
+
+    #[test]
+    fn test_components() {
+        let z = Complex::new(1_i32, 2_i32);
+
+        assert_eq!(z[0], z.re);
+        assert_eq!(z[1], z.im);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_complex_components_out_of_bounds() {
+        let z = Complex::new(1_i32, 2_i32);
+
+        assert_eq!(z[2], z[2]);
+    }
+
+    #[test]
+    fn test_addition() {
+        let z1 = Complex::new(1_i32, 2_i32);
+        let z2 = Complex::new(3_i32, -5_i32);
+        let expected = Complex::new(4_i32, -3_i32);
+
+        assert_eq!(z1 + z2, expected);
+    }
+
+    #[test]
+    fn test_multiplication_unit_imaginary_squared() {
+        let i = Complex::unit_im();
+        let expected = Complex::new(-1_i32, 0_i32);
+
+        assert_eq!(i * i, expected);
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let z1 = Complex::new(1_i32, 2_i32);
+        let z2 = Complex::new(3_i32, 4_i32);
+        let expected = Complex::new(-5_i32, 10_i32);
+
+        assert_eq!(z1 * z2, expected);
+        assert_eq!(z2 * z1, expected);
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let z = Complex::new(1_i32, 2_i32);
+        let expected = Complex::new(3_i32, 6_i32);
+
+        assert_eq!(z * 3_i32, expected);
+        assert_eq!(3_i32 * z, expected);
+    }
+
+    #[test]
+    fn test_division() {
+        let z1 = Complex::new(-5_f64, 10_f64);
+        let z2 = Complex::new(3_f64, 4_f64);
+        let expected = Complex::new(1_f64, 2_f64);
+        let result = z1 / z2;
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let z = Complex::new(3_i32, 4_i32);
+        let expected = Complex::new(25_i32, 0_i32);
+
+        assert_eq!(z * z.conjugate(), expected);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let z = Complex::new(3_f64, 4_f64);
+        let z_inv = z.inverse().unwrap();
+
+        assert!(relative_eq!(z * z_inv, Complex::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(z_inv * z, Complex::identity(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse_zero() {
+        let z: Complex<f64> = Complex::zero();
+
+        assert!(z.inverse().is_none());
+        assert!(!z.is_invertible());
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let z = Complex::new(3_f64, 4_f64);
+
+        assert_eq!(z.magnitude(), 5_f64);
+        assert_eq!(z.magnitude_squared(), 25_f64);
+    }
+
+    #[test]
+    fn test_polar_decomposition() {
+        let z = Complex::new(-2_f64, 2_f64);
+        let (modulus, angle) = z.polar_decomposition();
+        let result = Complex::from_polar_decomposition(modulus, angle);
+
+        assert!(relative_eq!(result, z, epsilon = 1e-10));
+        assert!(relative_eq!(angle.0, 3_f64 * f64::consts::FRAC_PI_4, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        let z = Complex::new(0.5_f64, -1.25_f64);
+        let result = z.ln().exp();
+
+        assert!(relative_eq!(result, z, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let z = Complex::new(-3_f64, 4_f64);
+        let sqrt_z = z.sqrt();
+        let expected = Complex::new(1_f64, 2_f64);
+
+        assert!(relative_eq!(sqrt_z, expected, epsilon = 1e-10));
+        assert!(relative_eq!(sqrt_z * sqrt_z, z, epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod unit_complex_tests {
+    use cglinalg::{
+        Complex,
+        UnitComplex,
+        Degrees,
+        Radians,
+        Isometry2,
+        Matrix2x2,
+        Point2,
+        Rotation2,
+        Similarity2,
+        Translation2,
+        Unit,
+        Vector2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_from_angle() {
+        let rotation = UnitComplex::from_angle(Degrees(30_f64));
+        let expected = Complex::new(f64::sqrt(3_f64) / 2_f64, 1_f64 / 2_f64);
+
+        assert!(relative_eq!(rotation.into_inner(), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_angle() {
+        let angle = Radians(2_f64);
+        let rotation = UnitComplex::from_angle(angle);
+
+        assert!(relative_eq!(rotation.angle(), angle, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotate_vector() {
+        let rotation = UnitComplex::from_angle(Degrees(90_f64));
+        let vector = Vector2::new(1_f64, 2_f64);
+        let expected = Vector2::new(-2_f64, 1_f64);
+
+        assert!(relative_eq!(rotation.rotate_vector(&vector), expected, epsilon = 1e-10));
+        assert!(relative_eq!(rotation * vector, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotate_point() {
+        let rotation = UnitComplex::from_angle(Degrees(135_f64));
+        let point = Point2::new(3_f64, -1_f64);
+        let expected = Rotation2::from_angle(Degrees(135_f64)).rotate_point(&point);
+
+        assert!(relative_eq!(rotation.rotate_point(&point), expected, epsilon = 1e-10));
+        assert!(relative_eq!(rotation * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse_rotate_vector() {
+        let rotation = UnitComplex::from_angle(Degrees(72_f64));
+        let vector = Vector2::new(1_f64, 2_f64);
+        let result = rotation.inverse_rotate_vector(&rotation.rotate_vector(&vector));
+
+        assert!(relative_eq!(result, vector, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_composition() {
+        let rotation1 = UnitComplex::from_angle(Degrees(40_f64));
+        let rotation2 = UnitComplex::from_angle(Degrees(75_f64));
+        let expected = UnitComplex::from_angle(Degrees(115_f64));
+
+        assert!(relative_eq!(rotation1 * rotation2, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let rotation = UnitComplex::from_angle(Degrees(-33_f64));
+        let identity = Unit::from_value(Complex::identity());
+
+        assert!(relative_eq!(rotation * rotation.inverse(), identity, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_takes_shortest_arc() {
+        let rotation1 = UnitComplex::from_angle(Degrees(170_f64));
+        let rotation2 = UnitComplex::from_angle(Degrees(-170_f64));
+        let expected = UnitComplex::from_angle(Degrees(180_f64));
+        let result = rotation1.slerp(&rotation2, 0.5_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_endpoints() {
+        let rotation1 = UnitComplex::from_angle(Degrees(20_f64));
+        let rotation2 = UnitComplex::from_angle(Degrees(110_f64));
+
+        assert!(relative_eq!(rotation1.slerp(&rotation2, 0_f64), rotation1, epsilon = 1e-10));
+        assert!(relative_eq!(rotation1.slerp(&rotation2, 1_f64), rotation2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_to_matrix() {
+        let angle = Degrees(57_f64);
+        let rotation = UnitComplex::from_angle(angle);
+        let expected = Matrix2x2::from_angle(angle);
+
+        assert!(relative_eq!(rotation.to_matrix(), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation2_round_trip() {
+        let rotation = Rotation2::from_angle(Degrees(-120_f64));
+        let unit_complex = UnitComplex::from_rotation(&rotation);
+        let result = Rotation2::from_unit_complex(&unit_complex);
+
+        assert!(relative_eq!(result, rotation, epsilon = 1e-10));
+        assert!(relative_eq!(unit_complex.to_rotation(), rotation, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation_between_axis() {
+        let a = Unit::from_value(Vector2::new(1_f64, 1_f64));
+        let b = Unit::from_value(Vector2::new(-1_f64, 0_f64));
+        let rotation = UnitComplex::rotation_between_axis(&a, &b);
+
+        assert!(relative_eq!(rotation.rotate_vector(a.as_ref()), b.into_inner(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_unit_complex_times_isometry() {
+        let rotation = UnitComplex::from_angle(Degrees(50_f64));
+        let isometry = Isometry2::from_parts(
+            &Translation2::new(1_f64, -2_f64),
+            &Rotation2::from_angle(Degrees(20_f64))
+        );
+        let point = Point2::new(3_f64, 4_f64);
+        let expected = rotation.rotate_point(&isometry.transform_point(&point));
+        let result = (rotation * isometry).transform_point(&point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_similarity_times_unit_complex() {
+        let rotation = UnitComplex::from_angle(Degrees(50_f64));
+        let similarity = Similarity2::from_parts(
+            &Translation2::new(1_f64, -2_f64),
+            &Rotation2::from_angle(Degrees(20_f64)),
+            3_f64
+        );
+        let point = Point2::new(3_f64, 4_f64);
+        let expected = similarity.transform_point(&rotation.rotate_point(&point));
+        let result = (similarity * rotation).transform_point(&point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+}
+
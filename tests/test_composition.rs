@@ -0,0 +1,337 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod composition2_tests {
+    use cglinalg::{
+        Degrees,
+        Isometry2,
+        Point2,
+        Reflection2,
+        Rotation2,
+        Scale2,
+        Shear2,
+        Similarity2,
+        Transform2,
+        Translation2,
+        Unit,
+        Vector2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_translation_times_rotation() {
+        let translation = Translation2::new(1_f64, 2_f64);
+        let rotation = Rotation2::from_angle(Degrees(72_f64));
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Isometry2<f64> = translation * rotation;
+        let expected = translation * (rotation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation_times_translation() {
+        let rotation = Rotation2::from_angle(Degrees(72_f64));
+        let translation = Translation2::new(1_f64, 2_f64);
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Isometry2<f64> = rotation * translation;
+        let expected = rotation * (translation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_isometry_times_rotation() {
+        let isometry = Isometry2::from_parts(&Translation2::new(-4_f64, 1_f64), &Rotation2::from_angle(Degrees(-35_f64)));
+        let rotation = Rotation2::from_angle(Degrees(72_f64));
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Isometry2<f64> = isometry * rotation;
+        let expected = isometry * (rotation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_translation_times_similarity() {
+        let translation = Translation2::new(1_f64, 2_f64);
+        let similarity = Similarity2::from_parts(
+            &Translation2::new(2_f64, 5_f64), &Rotation2::from_angle(Degrees(130_f64)), 3_f64
+        );
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Similarity2<f64> = translation * similarity;
+        let expected = translation * (similarity * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_isometry_times_similarity() {
+        let isometry = Isometry2::from_parts(&Translation2::new(-4_f64, 1_f64), &Rotation2::from_angle(Degrees(-35_f64)));
+        let similarity = Similarity2::from_parts(
+            &Translation2::new(2_f64, 5_f64), &Rotation2::from_angle(Degrees(130_f64)), 3_f64
+        );
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Similarity2<f64> = isometry * similarity;
+        let expected = isometry * (similarity * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_similarity_times_rotation() {
+        let similarity = Similarity2::from_parts(
+            &Translation2::new(2_f64, 5_f64), &Rotation2::from_angle(Degrees(130_f64)), 3_f64
+        );
+        let rotation = Rotation2::from_angle(Degrees(72_f64));
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Similarity2<f64> = similarity * rotation;
+        let expected = similarity * (rotation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation_times_scale() {
+        let rotation = Rotation2::from_angle(Degrees(72_f64));
+        let scale = Scale2::from_nonuniform_scale(2_f64, 5_f64);
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Transform2<f64> = rotation * scale;
+        let expected = rotation * (scale * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_shear_times_shear() {
+        let shear1 = Shear2::from_shear_x(2_f64);
+        let shear2 = Shear2::from_shear_y(-3_f64);
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Transform2<f64> = shear1 * shear2;
+        let expected = shear1 * (shear2 * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_times_isometry() {
+        let transform = Shear2::from_shear_x(2_f64).to_transform();
+        let isometry = Isometry2::from_parts(&Translation2::new(-4_f64, 1_f64), &Rotation2::from_angle(Degrees(-35_f64)));
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Transform2<f64> = transform * isometry;
+        let expected = transform * (isometry * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_reflection_times_reflection() {
+        let reflection1 = Reflection2::from_normal_bias(
+            &Unit::from_value(Vector2::new(1_f64, 2_f64)), &Vector2::new(1_f64, 1_f64)
+        );
+        let reflection2 = Reflection2::from_normal_bias(
+            &Unit::from_value(Vector2::new(-3_f64, 1_f64)), &Vector2::new(0_f64, 2_f64)
+        );
+        let point = Point2::new(3_f64, -2_f64);
+        let result: Isometry2<f64> = reflection1 * reflection2;
+        let expected = reflection1 * (reflection2 * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_reflection_times_reflection_through_origin_is_rotation() {
+        let reflection1 = Reflection2::from_normal_bias(
+            &Unit::from_value(Vector2::new(0_f64, 1_f64)), &Vector2::zero()
+        );
+        let reflection2 = Reflection2::from_normal_bias(
+            &Unit::from_value(Vector2::new(-1_f64, 1_f64)), &Vector2::zero()
+        );
+        let result = reflection1 * reflection2;
+        let expected = Rotation2::from_angle(Degrees(-90_f64));
+
+        assert!(relative_eq!(result.rotation(), &expected, epsilon = 1e-10));
+        assert!(relative_eq!(result.translation(), &Translation2::identity(), epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod composition3_tests {
+    use cglinalg::{
+        Degrees,
+        Isometry3,
+        Point3,
+        Reflection3,
+        Rotation3,
+        Scale3,
+        Shear3,
+        Similarity3,
+        Transform3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_translation_times_rotation() {
+        let translation = Translation3::new(1_f64, 2_f64, -3_f64);
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Isometry3<f64> = translation * rotation;
+        let expected = translation * (rotation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation_times_translation() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let translation = Translation3::new(1_f64, 2_f64, -3_f64);
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Isometry3<f64> = rotation * translation;
+        let expected = rotation * (translation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_translation_times_isometry() {
+        let translation = Translation3::new(1_f64, 2_f64, -3_f64);
+        let axis = Unit::from_value(Vector3::new(2_f64, 1_f64, 1_f64));
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(-35_f64), &Vector3::new(-4_f64, 1_f64, 2_f64));
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Isometry3<f64> = translation * isometry;
+        let expected = translation * (isometry * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_translation_times_similarity() {
+        let translation = Translation3::new(1_f64, 2_f64, -3_f64);
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(130_f64));
+        let similarity = Similarity3::from_parts(&Translation3::new(2_f64, 5_f64, 1_f64), &rotation, 3_f64);
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Similarity3<f64> = translation * similarity;
+        let expected = translation * (similarity * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_similarity_times_translation() {
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(130_f64));
+        let similarity = Similarity3::from_parts(&Translation3::new(2_f64, 5_f64, 1_f64), &rotation, 3_f64);
+        let translation = Translation3::new(1_f64, 2_f64, -3_f64);
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Similarity3<f64> = similarity * translation;
+        let expected = similarity * (translation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_isometry_times_scale() {
+        let axis = Unit::from_value(Vector3::new(2_f64, 1_f64, 1_f64));
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(-35_f64), &Vector3::new(-4_f64, 1_f64, 2_f64));
+        let scale = Scale3::from_nonuniform_scale(2_f64, 5_f64, 0.5_f64);
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Transform3<f64> = isometry * scale;
+        let expected = isometry * (scale * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_shear_times_similarity() {
+        let shear = Shear3::from_shear_x(2_f64, -1_f64);
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(130_f64));
+        let similarity = Similarity3::from_parts(&Translation3::new(2_f64, 5_f64, 1_f64), &rotation, 3_f64);
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Transform3<f64> = shear * similarity;
+        let expected = shear * (similarity * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation_times_transform() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let transform = Scale3::from_scale(2_f64).to_transform();
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Transform3<f64> = rotation * transform;
+        let expected = rotation * (transform * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_reflection_times_rotation() {
+        let reflection = Reflection3::from_normal_bias(
+            &Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64)), &Vector3::new(1_f64, 0_f64, 0_f64)
+        );
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Transform3<f64> = reflection * rotation;
+        let expected = reflection * (rotation * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_reflection_times_reflection() {
+        let reflection1 = Reflection3::from_normal_bias(
+            &Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64)), &Vector3::new(1_f64, 1_f64, 0_f64)
+        );
+        let reflection2 = Reflection3::from_normal_bias(
+            &Unit::from_value(Vector3::new(-3_f64, 1_f64, 4_f64)), &Vector3::new(0_f64, 2_f64, -1_f64)
+        );
+        let point = Point3::new(3_f64, -2_f64, 5_f64);
+        let result: Isometry3<f64> = reflection1 * reflection2;
+        let expected = reflection1 * (reflection2 * point);
+
+        assert!(relative_eq!(result * point, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_reflection_times_reflection_through_origin_is_rotation() {
+        let reflection1 = Reflection3::from_normal_bias(
+            &Unit::from_value(Vector3::new(0_f64, 1_f64, 0_f64)), &Vector3::zero()
+        );
+        let reflection2 = Reflection3::from_normal_bias(
+            &Unit::from_value(Vector3::new(-1_f64, 1_f64, 0_f64)), &Vector3::zero()
+        );
+        let result = reflection1 * reflection2;
+        let expected = Rotation3::from_angle_z(Degrees(-90_f64));
+
+        assert!(relative_eq!(result.rotation(), &expected, epsilon = 1e-10));
+        assert!(relative_eq!(result.translation(), &Translation3::identity(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_parallel_reflections_compose_to_translation() {
+        let normal = Unit::from_value(Vector3::unit_x());
+        let reflection1 = Reflection3::from_normal_bias(&normal, &Vector3::new(3_f64, 0_f64, 0_f64));
+        let reflection2 = Reflection3::from_normal_bias(&normal, &Vector3::new(1_f64, 0_f64, 0_f64));
+        let result = reflection1 * reflection2;
+        let expected = Translation3::new(4_f64, 0_f64, 0_f64);
+
+        assert!(relative_eq!(result.rotation(), &Rotation3::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(result.translation(), &expected, epsilon = 1e-10));
+    }
+}
@@ -0,0 +1,207 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod orbit_camera_tests {
+    use cglinalg::{
+        Degrees,
+        Magnitude,
+        OrbitCamera,
+        Point3,
+        Radians,
+        Rotation3,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_view_looks_at_target() {
+        let target = Point3::new(1_f64, -2_f64, 3_f64);
+        let camera = OrbitCamera::new(target, 4_f64, Degrees(35_f64), Degrees(20_f64));
+        let view = camera.view();
+
+        assert!(relative_eq!(view.transform_point(&camera.eye()), Point3::origin(), epsilon = 1e-10));
+        assert!(relative_eq!(view.transform_point(&target), Point3::new(0_f64, 0_f64, -4_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_positive_pitch_raises_camera() {
+        let camera = OrbitCamera::new(Point3::origin(), 2_f64, Degrees(0_f64), Degrees(30_f64));
+
+        assert!(relative_eq!(camera.eye(), Point3::new(0_f64, 1_f64, f64::sqrt(3_f64)), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_pitch_clamped() {
+        let mut camera = OrbitCamera::new(Point3::origin(), 2_f64, Degrees(0_f64), Degrees(0_f64));
+        camera.rotate(Degrees(10_f64), Degrees(200_f64));
+        let limit: Radians<f64> = Degrees(90_f64).into();
+
+        assert!(camera.pitch() < limit);
+        assert!(camera.pitch() > Radians(limit.0 - 0.01));
+
+        camera.rotate(Degrees(0_f64), Degrees(-400_f64));
+
+        assert!(camera.pitch() > -limit);
+        assert!(camera.view().transform_point(&camera.eye()).x.is_finite());
+    }
+
+    #[test]
+    fn test_orientation_matches_view() {
+        let camera = OrbitCamera::new(Point3::new(1_f64, 2_f64, 3_f64), 4_f64, Degrees(-70_f64), Degrees(45_f64));
+        let orientation = Rotation3::from_quaternion(&camera.orientation());
+        let view_rotation = camera.view().rotation().inverse();
+        let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+
+        assert!(relative_eq!(orientation.rotate_vector(&vector), view_rotation.rotate_vector(&vector), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_pan_keeps_offset() {
+        let mut camera = OrbitCamera::new(Point3::new(1_f64, 2_f64, 3_f64), 4_f64, Degrees(30_f64), Degrees(15_f64));
+        let offset = camera.eye() - camera.target();
+        let projected_target = camera.view().transform_point(&camera.target());
+        camera.pan(0.5_f64, -0.25_f64);
+
+        assert!(relative_eq!(camera.eye() - camera.target(), offset, epsilon = 1e-10));
+        assert!(relative_eq!(camera.view().transform_point(&camera.target()), projected_target, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_zoom_scales_distance() {
+        let mut camera = OrbitCamera::new(Point3::origin(), 4_f64, Degrees(30_f64), Degrees(15_f64));
+        camera.zoom(0.5_f64);
+
+        assert_eq!(camera.distance(), 2_f64);
+        assert!(relative_eq!((camera.eye() - camera.target()).magnitude(), 2_f64, epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod fly_camera_tests {
+    use cglinalg::{
+        Degrees,
+        FlyCamera,
+        Point3,
+        Rotation3,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_default_direction() {
+        let camera = FlyCamera::new(Point3::origin(), Degrees(0_f64), Degrees(0_f64));
+
+        assert!(relative_eq!(camera.forward(), -Vector3::unit_z(), epsilon = 1e-10));
+        assert!(relative_eq!(camera.right(), Vector3::unit_x(), epsilon = 1e-10));
+        assert!(relative_eq!(camera.up(), Vector3::unit_y(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_view_maps_forward_to_negative_z() {
+        let camera = FlyCamera::new(Point3::new(1_f64, 2_f64, 3_f64), Degrees(40_f64), Degrees(-25_f64));
+        let point = camera.position() + camera.forward() * 3_f64;
+
+        assert!(relative_eq!(camera.view().transform_point(&point), Point3::new(0_f64, 0_f64, -3_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_orientation_matches_basis() {
+        let camera = FlyCamera::new(Point3::origin(), Degrees(40_f64), Degrees(-25_f64));
+        let orientation = Rotation3::from_quaternion(&camera.orientation());
+
+        assert!(relative_eq!(orientation.rotate_vector(&-Vector3::unit_z()), camera.forward(), epsilon = 1e-10));
+        assert!(relative_eq!(orientation.rotate_vector(&Vector3::unit_x()), camera.right(), epsilon = 1e-10));
+        assert!(relative_eq!(orientation.rotate_vector(&Vector3::unit_y()), camera.up(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_movement() {
+        let mut camera = FlyCamera::new(Point3::origin(), Degrees(90_f64), Degrees(30_f64));
+        camera.move_right(1_f64);
+        camera.move_up(2_f64);
+
+        assert!(relative_eq!(camera.position(), Point3::new(0_f64, 2_f64, -1_f64), epsilon = 1e-10));
+
+        camera.move_forward(2_f64);
+
+        assert!(relative_eq!(camera.position(), Point3::new(-f64::sqrt(3_f64), 3_f64, -1_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_pitch_clamped() {
+        let mut camera = FlyCamera::new(Point3::origin(), Degrees(0_f64), Degrees(89_f64));
+        camera.rotate(Degrees(0_f64), Degrees(5_f64));
+
+        assert!(camera.forward().y < 1_f64);
+        assert!(camera.forward().y > 0.999_f64);
+        assert!(camera.view().transform_point(&Point3::new(1_f64, 1_f64, 1_f64)).x.is_finite());
+    }
+}
+
+
+#[cfg(test)]
+mod arcball_camera_tests {
+    use cglinalg::{
+        ArcballCamera,
+        Magnitude,
+        Point2,
+        Point3,
+        Rotation3,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_project_to_ball() {
+        let camera = ArcballCamera::new(Point3::origin(), 5_f64, 800_f64, 600_f64);
+
+        assert!(relative_eq!(camera.project_to_ball(&Point2::new(400_f64, 300_f64)), Vector3::unit_z(), epsilon = 1e-10));
+        assert!(relative_eq!(camera.project_to_ball(&Point2::new(400_f64, 0_f64)), Vector3::unit_y(), epsilon = 1e-10));
+        assert!(relative_eq!(camera.project_to_ball(&Point2::new(800_f64, 300_f64)), Vector3::unit_x(), epsilon = 1e-10));
+        assert!(relative_eq!(camera.project_to_ball(&Point2::new(550_f64, 150_f64)).magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_drag_rotation_is_unit() {
+        let camera = ArcballCamera::new(Point3::origin(), 5_f64, 800_f64, 600_f64);
+        let rotation = camera.drag_rotation(&Point2::new(120_f64, 80_f64), &Point2::new(500_f64, 410_f64));
+
+        assert!(relative_eq!(rotation.magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_drag_moves_scene_with_mouse() {
+        let mut camera = ArcballCamera::new(Point3::new(1_f64, 2_f64, 3_f64), 5_f64, 800_f64, 600_f64);
+        let (from, to) = (Point2::new(400_f64, 300_f64), Point2::new(400_f64, 200_f64));
+        let rotation = Rotation3::from_quaternion(&camera.drag_rotation(&from, &to));
+        let point = Point3::new(0.5_f64, -1_f64, 2_f64);
+        let view_point = camera.view().transform_point(&point);
+        camera.drag(&from, &to);
+        // The scene rotates about the target in camera space.
+        let view_target = Point3::new(0_f64, 0_f64, -5_f64);
+        let expected = view_target + rotation.rotate_vector(&(view_point - view_target));
+
+        assert!(relative_eq!(camera.view().transform_point(&point), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_view_looks_at_target() {
+        let mut camera = ArcballCamera::new(Point3::new(1_f64, 2_f64, 3_f64), 5_f64, 800_f64, 600_f64);
+        camera.drag(&Point2::new(100_f64, 100_f64), &Point2::new(600_f64, 450_f64));
+        camera.drag(&Point2::new(300_f64, 500_f64), &Point2::new(250_f64, 50_f64));
+
+        assert!(relative_eq!(camera.view().transform_point(&camera.target()), Point3::new(0_f64, 0_f64, -5_f64), epsilon = 1e-10));
+        assert!(relative_eq!(camera.view().transform_point(&camera.eye()), Point3::origin(), epsilon = 1e-10));
+    }
+}
@@ -0,0 +1,167 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod basis_change_tests {
+    use cglinalg::{
+        BasisChange,
+        CoordinateSystem,
+        Degrees,
+        EulerAngles,
+        Handedness,
+        Isometry3,
+        Matrix3x3,
+        Matrix4x4,
+        Point3,
+        Quaternion,
+        Radians,
+        Rotation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    const SYSTEMS: [CoordinateSystem; 4] = [
+        CoordinateSystem::YUpRightHanded,
+        CoordinateSystem::ZUpRightHanded,
+        CoordinateSystem::YUpLeftHanded,
+        CoordinateSystem::ZUpLeftHanded,
+    ];
+
+    fn pairs() -> impl Iterator<Item = (CoordinateSystem, CoordinateSystem)> {
+        SYSTEMS.iter().flat_map(|source| SYSTEMS.iter().map(move |target| (*source, *target)))
+    }
+
+    #[test]
+    fn test_same_convention_is_identity() {
+        for system in SYSTEMS.iter() {
+            let change: BasisChange<f64> = BasisChange::new(*system, *system);
+
+            assert_eq!(change.matrix(), &Matrix3x3::identity());
+        }
+    }
+
+    #[test]
+    fn test_determinant_matches_handedness() {
+        for (source, target) in pairs() {
+            let change: BasisChange<f64> = BasisChange::new(source, target);
+            let expected = if source.handedness() == target.handedness() { 1_f64 } else { -1_f64 };
+
+            assert_eq!(change.determinant(), expected);
+            assert_eq!(change.is_reflection(), expected < 0_f64);
+        }
+    }
+
+    #[test]
+    fn test_handedness_matches_basis() {
+        for system in SYSTEMS.iter() {
+            let right: Vector3<f64> = system.right();
+            // The right, up, and forward directions of a model form a 
+            // left-handed triple in right-handed coordinates, and vice versa.
+            let orientation = right.dot(&system.up().cross(&system.forward()));
+            let expected = match system.handedness() {
+                Handedness::RightHanded => -1_f64,
+                Handedness::LeftHanded => 1_f64,
+            };
+
+            assert_eq!(orientation, expected);
+        }
+    }
+
+    #[test]
+    fn test_unity_to_unreal() {
+        let change = BasisChange::new(CoordinateSystem::YUpLeftHanded, CoordinateSystem::ZUpLeftHanded);
+        let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+
+        assert_eq!(change.convert_vector(&vector), Vector3::new(3_f64, 1_f64, 2_f64));
+    }
+
+    #[test]
+    fn test_inverse_and_composition() {
+        let point = Point3::new(1_f64, -2_f64, 3_f64);
+        for (source, target) in pairs() {
+            let change = BasisChange::new(source, target);
+
+            assert_eq!(change.inverse().convert_point(&change.convert_point(&point)), point);
+            assert_eq!(change.inverse(), BasisChange::new(target, source));
+            for middle in SYSTEMS.iter() {
+                let first = BasisChange::new(source, *middle);
+                let second = BasisChange::new(*middle, target);
+
+                assert_eq!(second.convert_point(&first.convert_point(&point)), change.convert_point(&point));
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_quaternion() {
+        let axis = Unit::from_value(Vector3::new(-2_f64, 1_f64, 4_f64));
+        let quaternion = Quaternion::from_axis_angle(&axis, Degrees(110_f64));
+        let rotation = Rotation3::from_quaternion(&quaternion);
+        let vector = Vector3::new(3_f64, -1_f64, 0.5_f64);
+        for (source, target) in pairs() {
+            let change = BasisChange::new(source, target);
+            let converted = Rotation3::from_quaternion(&change.convert_quaternion(&quaternion));
+            let expected = change.convert_vector(&rotation.rotate_vector(&vector));
+            let result = converted.rotate_vector(&change.convert_vector(&vector));
+
+            assert!(relative_eq!(result, expected, epsilon = 1e-10));
+            assert!(relative_eq!(converted.matrix().determinant(), 1_f64, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_convert_isometry() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, -1_f64));
+        let distance = Vector3::new(4_f64, -5_f64, 6_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(75_f64), &distance);
+        let point = Point3::new(-1_f64, 2_f64, 0.5_f64);
+        for (source, target) in pairs() {
+            let change = BasisChange::new(source, target);
+            let converted = change.convert_isometry(&isometry);
+            let expected = change.convert_point(&isometry.transform_point(&point));
+            let result = converted.transform_point(&change.convert_point(&point));
+
+            assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_convert_matrix() {
+        let matrix = Matrix4x4::new(
+            1_f64,  2_f64, 0_f64,  0_f64,
+            -1_f64, 3_f64, 4_f64,  0_f64,
+            0.5_f64, 0_f64, 2_f64, 0_f64,
+            7_f64, -8_f64, 9_f64,  1_f64
+        );
+        let point = Point3::new(-1_f64, 2_f64, 0.5_f64);
+        for (source, target) in pairs() {
+            let change = BasisChange::new(source, target);
+            let converted = change.convert_matrix(&matrix);
+            let transformed = Point3::from_homogeneous(matrix * point.to_homogeneous()).unwrap();
+            let expected = change.convert_point(&transformed);
+            let converted_point = change.convert_point(&point);
+            let result = Point3::from_homogeneous(converted * converted_point.to_homogeneous()).unwrap();
+
+            assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_convert_euler_angles() {
+        let euler_angles = EulerAngles::new(Radians(0.3_f64), Radians(-0.7_f64), Radians(1.1_f64));
+        let rotation = euler_angles.to_matrix();
+        let vector = Vector3::new(3_f64, -1_f64, 0.5_f64);
+        for (source, target) in pairs() {
+            let change = BasisChange::new(source, target);
+            let converted = change.convert_euler_angles(&euler_angles).to_matrix();
+            let expected = change.convert_vector(&(rotation * vector));
+            let result = converted * change.convert_vector(&vector);
+
+            assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod cube_map_tests {
+    use cglinalg::{
+        CubeMapConvention,
+        CubeMapFace,
+        Point2,
+        Point3,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    fn sample_uvs() -> [Point2<f64>; 5] {
+        [
+            Point2::new(0.5_f64, 0.5_f64),
+            Point2::new(0.1_f64, 0.2_f64),
+            Point2::new(0.9_f64, 0.3_f64),
+            Point2::new(0.25_f64, 0.75_f64),
+            Point2::new(0.8_f64, 0.95_f64),
+        ]
+    }
+
+    #[test]
+    fn test_direction_round_trip() {
+        for face in CubeMapFace::ALL.iter() {
+            for uv in sample_uvs().iter() {
+                let direction = face.to_direction(uv) * 3_f64;
+                let (result_face, result_uv) = CubeMapFace::from_direction(&direction).unwrap();
+
+                assert_eq!(result_face, *face);
+                assert!(relative_eq!(result_uv, uv, epsilon = 1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_face_center_directions() {
+        let center = Point2::new(0.5_f64, 0.5_f64);
+        for face in CubeMapFace::ALL.iter() {
+            assert_eq!(face.to_direction(&center), face.forward());
+            assert_eq!(CubeMapFace::from_direction(&face.forward::<f64>()), Some((*face, center)));
+        }
+    }
+
+    #[test]
+    fn test_from_direction_zero_vector() {
+        let direction: Vector3<f64> = Vector3::zero();
+
+        assert_eq!(CubeMapFace::from_direction(&direction), None);
+    }
+
+    #[test]
+    fn test_opengl_views_render_texture_coordinates() {
+        let center = Point3::new(1_f64, -2_f64, 3_f64);
+        let projection = CubeMapFace::projection(0.1_f64, 100_f64, CubeMapConvention::OpenGl);
+        for face in CubeMapFace::ALL.iter() {
+            let view = face.view(&center, CubeMapConvention::OpenGl);
+            for uv in sample_uvs().iter() {
+                let point = center + face.to_direction(uv) * 5_f64;
+                let clip = projection * view.transform_point(&point).to_homogeneous();
+                let ndc = Point3::from_homogeneous(clip).unwrap();
+
+                // The framebuffer origin lies at the bottom left corner, the
+                // first texel of the face.
+                assert!(relative_eq!(ndc.x, 2_f64 * uv.x - 1_f64, epsilon = 1e-10));
+                assert!(relative_eq!(ndc.y, 2_f64 * uv.y - 1_f64, epsilon = 1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_direct3d_views_render_texture_coordinates() {
+        let center = Point3::new(1_f64, -2_f64, 3_f64);
+        let projection = CubeMapFace::projection(0.1_f64, 100_f64, CubeMapConvention::Direct3D);
+        for face in CubeMapFace::ALL.iter() {
+            let view = face.view(&center, CubeMapConvention::Direct3D);
+            for uv in sample_uvs().iter() {
+                let point = center + face.to_direction(uv) * 5_f64;
+                let view_point = view.transform_point(&point);
+                let clip = projection * view_point.to_homogeneous();
+                let ndc = Point3::from_homogeneous(clip).unwrap();
+
+                // The camera looks down the positive z-axis, and the framebuffer
+                // origin lies at the top left corner, the first texel of the face.
+                assert!(view_point.z > 0_f64);
+                assert!(relative_eq!(ndc.x, 2_f64 * uv.x - 1_f64, epsilon = 1e-10));
+                assert!(relative_eq!(ndc.y, 1_f64 - 2_f64 * uv.y, epsilon = 1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_direct3d_projection_maps_face_corner() {
+        let center = Point3::new(0_f64, 1_f64, 0_f64);
+        let projection = CubeMapFace::projection(0.5_f64, 10_f64, CubeMapConvention::Direct3D);
+        let view = CubeMapFace::PositiveX.view(&center, CubeMapConvention::Direct3D);
+        // The corner of the face in the directions `+y` and `+z` is the first
+        // texel of the face, at texture coordinates `(0, 0)`.
+        let point = center + Vector3::new(2_f64, 2_f64, 2_f64);
+        let clip = projection * view.transform_point(&point).to_homogeneous();
+        let ndc = Point3::from_homogeneous(clip).unwrap();
+        let u = (ndc.x + 1_f64) / 2_f64;
+        let v = (1_f64 - ndc.y) / 2_f64;
+
+        assert!(relative_eq!(u, 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(v, 0_f64, epsilon = 1e-10));
+        assert!(ndc.z > 0_f64 && ndc.z < 1_f64);
+    }
+
+    #[test]
+    fn test_direct3d_projection_depth_range() {
+        let projection = CubeMapFace::projection(0.5_f64, 10_f64, CubeMapConvention::Direct3D);
+        let near = Point3::from_homogeneous(projection * Point3::new(0_f64, 0_f64, 0.5_f64).to_homogeneous()).unwrap();
+        let far = Point3::from_homogeneous(projection * Point3::new(0_f64, 0_f64, 10_f64).to_homogeneous()).unwrap();
+
+        assert!(relative_eq!(near.z, 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(far.z, 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_views_in_layer_order() {
+        let center = Point3::new(4_f64, 5_f64, 6_f64);
+        let views = CubeMapFace::views(&center, CubeMapConvention::OpenGl);
+        for (index, view) in views.iter().enumerate() {
+            let face = CubeMapFace::from_index(index).unwrap();
+            let ahead = view.inverse_transform_point(&Point3::new(0_f64, 0_f64, -1_f64));
+
+            assert!(relative_eq!(ahead, center + face.forward(), epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_view_matrix_matches_view() {
+        let center = Point3::new(4_f64, 5_f64, 6_f64);
+        let point = Point3::new(-1_f64, 2_f64, 7_f64);
+        for face in CubeMapFace::ALL.iter() {
+            for convention in [CubeMapConvention::OpenGl, CubeMapConvention::Direct3D].iter() {
+                let view = face.view(&center, *convention);
+                let matrix = face.view_matrix(&center, *convention);
+                let expected = view.transform_point(&point);
+                let result = Point3::from_homogeneous(matrix * point.to_homogeneous()).unwrap();
+
+                assert!(relative_eq!(result, expected, epsilon = 1e-10));
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod polar2_tests {
+    use cglinalg::{
+        Degrees,
+        Point2,
+        Polar2,
+        Radians,
+        Vector2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_round_trip() {
+        let points = [
+            Point2::new(1_f64, 2_f64),
+            Point2::new(-3_f64, 0.5_f64),
+            Point2::new(-0.25_f64, -4_f64),
+            Point2::new(7_f64, -1_f64),
+        ];
+        for point in points.iter() {
+            let polar = Polar2::from(*point);
+            let result = Point2::from(polar);
+
+            assert!(relative_eq!(result, point, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_from_vector() {
+        let polar = Polar2::from_vector(&Vector2::new(1_f64, 1_f64));
+        let expected_angle: Radians<f64> = Degrees(45_f64).into();
+
+        assert!(relative_eq!(polar.radius(), f64::sqrt(2_f64), epsilon = 1e-10));
+        assert!(relative_eq!(polar.angle(), expected_angle, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_origin() {
+        let polar = Polar2::from_point(&Point2::origin());
+
+        assert_eq!(polar, Polar2::new(0_f64, Radians(0_f64)));
+    }
+
+    #[test]
+    fn test_lerp_takes_short_way_around() {
+        let polar1 = Polar2::new(2_f64, Degrees(-20_f64));
+        let polar2 = Polar2::new(2_f64, Degrees(300_f64));
+        let result = polar1.lerp(&polar2, 0.5_f64);
+        let expected = Polar2::new(2_f64, Degrees(-40_f64));
+
+        assert!(relative_eq!(result.to_point(), expected.to_point(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let polar1 = Polar2::new(1_f64, Degrees(100_f64));
+        let polar2 = Polar2::new(3_f64, Degrees(-120_f64));
+
+        assert!(relative_eq!(polar1.lerp(&polar2, 0_f64).to_point(), polar1.to_point(), epsilon = 1e-10));
+        assert!(relative_eq!(polar1.lerp(&polar2, 1_f64).to_point(), polar2.to_point(), epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod spherical3_tests {
+    use cglinalg::{
+        Angle,
+        Degrees,
+        Magnitude,
+        Point3,
+        Radians,
+        Spherical3,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_round_trip() {
+        let points = [
+            Point3::new(1_f64, 2_f64, 3_f64),
+            Point3::new(-3_f64, 0.5_f64, -2_f64),
+            Point3::new(-0.25_f64, -4_f64, 0_f64),
+            Point3::new(0_f64, 0_f64, -6_f64),
+        ];
+        for point in points.iter() {
+            let spherical = Spherical3::from(*point);
+            let result = Point3::from(spherical);
+
+            assert!(relative_eq!(result, point, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_angle_ranges() {
+        let spherical = Spherical3::from_vector(&Vector3::new(-1_f64, -1_f64, -1_f64));
+        let expected_azimuth: Radians<f64> = Degrees(-135_f64).into();
+
+        assert!(spherical.polar() > Radians::full_turn_div_4());
+        assert!(spherical.polar() < Radians::full_turn_div_2());
+        assert!(relative_eq!(spherical.azimuth(), expected_azimuth, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_direction_is_unit() {
+        let spherical = Spherical3::new(5_f64, Degrees(33_f64), Degrees(-71_f64));
+
+        assert!(relative_eq!(spherical.direction().magnitude(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(spherical.to_vector(), spherical.direction() * 5_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_constant_angular_speed() {
+        let spherical1 = Spherical3::new(1_f64, Degrees(90_f64), Degrees(0_f64));
+        let spherical2 = Spherical3::new(1_f64, Degrees(0_f64), Degrees(0_f64));
+        let result = spherical1.lerp(&spherical2, 1_f64 / 3_f64);
+        let expected = Spherical3::new(1_f64, Degrees(60_f64), Degrees(0_f64));
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_wraps_azimuth() {
+        let spherical1 = Spherical3::new(2_f64, Degrees(90_f64), Degrees(170_f64));
+        let spherical2 = Spherical3::new(4_f64, Degrees(90_f64), Degrees(-170_f64));
+        let result = spherical1.lerp(&spherical2, 0.5_f64);
+
+        assert!(relative_eq!(result.to_vector(), Vector3::new(-3_f64, 0_f64, 0_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_opposite_directions() {
+        let spherical1 = Spherical3::new(1_f64, Degrees(90_f64), Degrees(0_f64));
+        let spherical2 = Spherical3::new(1_f64, Degrees(90_f64), Degrees(180_f64));
+        let result = spherical1.lerp(&spherical2, 0.5_f64);
+
+        assert!(relative_eq!(result.radius(), 1_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.direction().dot(&spherical1.direction()), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let spherical1 = Spherical3::new(1_f64, Degrees(20_f64), Degrees(100_f64));
+        let spherical2 = Spherical3::new(3_f64, Degrees(140_f64), Degrees(-120_f64));
+
+        assert!(relative_eq!(spherical1.lerp(&spherical2, 0_f64).to_point(), spherical1.to_point(), epsilon = 1e-10));
+        assert!(relative_eq!(spherical1.lerp(&spherical2, 1_f64).to_point(), spherical2.to_point(), epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod cylindrical3_tests {
+    use cglinalg::{
+        Cylindrical3,
+        Degrees,
+        Point3,
+        Spherical3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_round_trip() {
+        let points = [
+            Point3::new(1_f64, 2_f64, 3_f64),
+            Point3::new(-3_f64, 0.5_f64, -2_f64),
+            Point3::new(-0.25_f64, -4_f64, 0_f64),
+        ];
+        for point in points.iter() {
+            let cylindrical = Cylindrical3::from(*point);
+            let result = Point3::from(cylindrical);
+
+            assert!(relative_eq!(result, point, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_spherical_conversion() {
+        let point = Point3::new(-1_f64, 2_f64, -3_f64);
+        let cylindrical = Cylindrical3::from_point(&point);
+        let spherical = Spherical3::from(cylindrical);
+
+        assert!(relative_eq!(spherical, Spherical3::from_point(&point), epsilon = 1e-10));
+        assert!(relative_eq!(Cylindrical3::from(spherical), cylindrical, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let cylindrical1 = Cylindrical3::new(1_f64, Degrees(170_f64), 0_f64);
+        let cylindrical2 = Cylindrical3::new(3_f64, Degrees(-150_f64), 4_f64);
+        let result = cylindrical1.lerp(&cylindrical2, 0.5_f64);
+        let expected = Cylindrical3::new(2_f64, Degrees(-170_f64), 2_f64);
+
+        assert!(relative_eq!(result.to_point(), expected.to_point(), epsilon = 1e-10));
+    }
+}
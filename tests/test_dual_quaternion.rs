@@ -0,0 +1,282 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod dual_quaternion_tests {
+    use cglinalg::{
+        DualQuaternion,
+        Degrees,
+        Isometry3,
+        Point3,
+        Rotation3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    fn isometry1() -> Isometry3<f64> {
+        let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 3_f64));
+        let distance = Vector3::new(4_f64, -1_f64, 2_f64);
+
+        Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &distance)
+    }
+
+    fn isometry2() -> Isometry3<f64> {
+        let axis = Unit::from_value(Vector3::new(-3_f64, 1_f64, 1_f64));
+        let distance = Vector3::new(-2_f64, 5_f64, 1_f64);
+
+        Isometry3::from_axis_angle_translation(&axis, Degrees(-130_f64), &distance)
+    }
+
+    #[test]
+    fn test_from_isometry_to_isometry() {
+        let expected = isometry1();
+        let result = DualQuaternion::from_isometry(&expected).to_isometry();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_isometry_transform_point() {
+        let isometry = isometry1();
+        let dual_quaternion = DualQuaternion::from(isometry);
+        let point = Point3::new(3_f64, 1_f64, -7_f64);
+        let expected = isometry.transform_point(&point);
+        let result = dual_quaternion * point;
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_isometry_transform_vector() {
+        let isometry = isometry1();
+        let dual_quaternion = DualQuaternion::from(isometry);
+        let vector = Vector3::new(3_f64, 1_f64, -7_f64);
+        let expected = isometry.transform_vector(&vector);
+        let result = dual_quaternion.transform_vector(&vector);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_from_rotation_translation() {
+        let rotation = Rotation3::from_angle_y(Degrees(20_f64));
+        let translation = Translation3::new(1_f64, 2_f64, 3_f64);
+        let dual_quaternion = DualQuaternion::from_rotation_translation(&rotation, &translation);
+
+        assert!(relative_eq!(dual_quaternion.rotation(), rotation, epsilon = 1e-10));
+        assert!(relative_eq!(dual_quaternion.translation(), translation, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_composition_matches_isometry_composition() {
+        let isometry1 = isometry1();
+        let isometry2 = isometry2();
+        let expected = DualQuaternion::from_isometry(&(isometry1 * isometry2));
+        let result = DualQuaternion::from_isometry(&isometry1) * DualQuaternion::from_isometry(&isometry2);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let isometry = isometry2();
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+        let expected = isometry.inverse();
+        let result = dual_quaternion.inverse().unwrap().to_isometry();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_inverse_of_unit_dual_quaternion_is_conjugate() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry1());
+        let expected = dual_quaternion.conjugate();
+        let result = dual_quaternion.inverse().unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_zero_dual_quaternion_is_not_invertible() {
+        let dual_quaternion: DualQuaternion<f64> = DualQuaternion::zero();
+
+        assert!(!dual_quaternion.is_invertible());
+        assert!(dual_quaternion.inverse().is_none());
+    }
+
+    #[test]
+    fn test_inverse_transform_point() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry2());
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let transformed_point = dual_quaternion.transform_point(&point);
+        let result = dual_quaternion.inverse_transform_point(&transformed_point);
+
+        assert!(relative_eq!(result, point, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_try_normalize() {
+        let expected = DualQuaternion::from_isometry(&isometry1());
+        let dual_quaternion = expected * 3_f64;
+        let result = dual_quaternion.try_normalize().unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_try_normalize_zero() {
+        let dual_quaternion: DualQuaternion<f64> = DualQuaternion::zero();
+
+        assert!(dual_quaternion.try_normalize().is_none());
+    }
+}
+
+#[cfg(test)]
+mod dual_quaternion_interpolation_tests {
+    use cglinalg::{
+        DualQuaternion,
+        Degrees,
+        Isometry3,
+        Point3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    fn isometry1() -> Isometry3<f64> {
+        let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 3_f64));
+        let distance = Vector3::new(4_f64, -1_f64, 2_f64);
+
+        Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &distance)
+    }
+
+    fn isometry2() -> Isometry3<f64> {
+        let axis = Unit::from_value(Vector3::new(-3_f64, 1_f64, 1_f64));
+        let distance = Vector3::new(-2_f64, 5_f64, 1_f64);
+
+        Isometry3::from_axis_angle_translation(&axis, Degrees(-130_f64), &distance)
+    }
+
+    #[test]
+    fn test_powf_zero_is_identity() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry1());
+        let expected = DualQuaternion::identity();
+        let result = dual_quaternion.powf(0_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_powf_one_is_dual_quaternion() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry1());
+        let result = dual_quaternion.powf(1_f64);
+
+        assert!(relative_eq!(result, dual_quaternion, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_powf_half_squared() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry2());
+        let half = dual_quaternion.powf(0.5_f64);
+        let result = half * half;
+
+        assert!(relative_eq!(result, dual_quaternion, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_powf_pure_translation() {
+        let distance = Vector3::new(2_f64, 4_f64, 6_f64);
+        let translation = Translation3::from_vector(&distance);
+        let dual_quaternion = DualQuaternion::from_translation(&translation);
+        let result = dual_quaternion.powf(0.5_f64).translation();
+        let expected = Translation3::from_vector(&(distance * 0.5_f64));
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sclerp_endpoints() {
+        let dual_quaternion1 = DualQuaternion::from_isometry(&isometry1());
+        let dual_quaternion2 = DualQuaternion::from_isometry(&isometry2());
+
+        assert!(relative_eq!(
+            dual_quaternion1.sclerp(&dual_quaternion2, 0_f64), dual_quaternion1, epsilon = 1e-10
+        ));
+        assert!(relative_eq!(
+            dual_quaternion1.sclerp(&dual_quaternion2, 1_f64).to_isometry(), 
+            dual_quaternion2.to_isometry(), 
+            epsilon = 1e-10
+        ));
+    }
+
+    #[test]
+    fn test_sclerp_is_constant_speed() {
+        let dual_quaternion1 = DualQuaternion::from_isometry(&isometry1());
+        let dual_quaternion2 = DualQuaternion::from_isometry(&isometry2());
+        let step1 = dual_quaternion1.sclerp(&dual_quaternion2, 0.25_f64);
+        let step2 = dual_quaternion1.sclerp(&dual_quaternion2, 0.5_f64);
+        let expected = step1.conjugate() * step2;
+        let result = dual_quaternion1.conjugate() * step1;
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sclerp_short_way_around() {
+        let dual_quaternion1 = DualQuaternion::from_isometry(&isometry1());
+        let dual_quaternion2 = DualQuaternion::from_isometry(&isometry2());
+        let expected = dual_quaternion1.sclerp(&dual_quaternion2, 0.3_f64);
+        let result = dual_quaternion1.sclerp(&(-dual_quaternion2), 0.3_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_dlb_single_dual_quaternion() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry1());
+        let result = DualQuaternion::dlb(&[dual_quaternion], &[0.7_f64]).unwrap();
+
+        assert!(relative_eq!(result, dual_quaternion, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_dlb_is_unit() {
+        let dual_quaternion1 = DualQuaternion::from_isometry(&isometry1());
+        let dual_quaternion2 = DualQuaternion::from_isometry(&isometry2());
+        let result = DualQuaternion::dlb(
+            &[dual_quaternion1, dual_quaternion2], &[0.3_f64, 0.7_f64]
+        ).unwrap();
+        let point = Point3::new(1_f64, 1_f64, 1_f64);
+        let isometry = result.to_isometry();
+
+        assert!(relative_eq!(result.real.dot(&result.dual), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(isometry * point, result * point, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_dlb_sign_invariant() {
+        let dual_quaternion1 = DualQuaternion::from_isometry(&isometry1());
+        let dual_quaternion2 = DualQuaternion::from_isometry(&isometry2());
+        let weights = [0.4_f64, 0.6_f64];
+        let expected = DualQuaternion::dlb(&[dual_quaternion1, dual_quaternion2], &weights).unwrap();
+        let result = DualQuaternion::dlb(&[dual_quaternion1, -dual_quaternion2], &weights).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_dlb_mismatched_lengths() {
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry1());
+
+        assert!(DualQuaternion::dlb(&[dual_quaternion], &[0.5_f64, 0.5_f64]).is_none());
+    }
+}
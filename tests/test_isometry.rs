@@ -177,6 +177,29 @@ mod isometry2_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_kabsch_recovers_isometry() {
+        let isometry = Isometry2::from_angle_translation(Degrees(-110_f64), &Vector2::new(4_f64, -1_f64));
+        let source = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(2_f64, 1_f64),
+            Point2::new(-1_f64, 3_f64),
+            Point2::new(5_f64, -2_f64),
+        ];
+        let target = source.map(|point| isometry.transform_point(&point));
+        let (result, residual) = Isometry2::kabsch(&source, &target).unwrap();
+
+        assert!(relative_eq!(result, isometry, epsilon = 1e-10));
+        assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_kabsch_mismatched_lengths() {
+        let source = [Point2::new(0_f64, 0_f64), Point2::new(1_f64, 0_f64)];
+
+        assert!(Isometry2::kabsch(&source, &source[..1]).is_none());
+    }
 }
 
 
@@ -186,6 +209,7 @@ mod isometry3_tests {
         Angle,
         Degrees,
         Isometry3,
+        Magnitude,
         Matrix4x4,
         Point3,
         Rotation3,
@@ -399,4 +423,127 @@ mod isometry3_tests {
 
         assert_eq!(result, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_lerp_slerp_endpoints() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+        let isometry1 = Isometry3::from_axis_angle_translation(&axis, Degrees(20_f64), &Vector3::new(1_f64, 2_f64, 3_f64));
+        let isometry2 = Isometry3::from_axis_angle_translation(&axis, Degrees(140_f64), &Vector3::new(-4_f64, 0_f64, 5_f64));
+
+        assert!(relative_eq!(isometry1.lerp_slerp(&isometry2, 0_f64), isometry1, epsilon = 1e-10));
+        assert!(relative_eq!(isometry1.lerp_slerp(&isometry2, 1_f64), isometry2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_slerp_constant_speed() {
+        let axis = Unit::from_value(Vector3::unit_y());
+        let isometry1 = Isometry3::identity();
+        let isometry2 = Isometry3::from_axis_angle_translation(&axis, Degrees(120_f64), &Vector3::new(6_f64, 0_f64, 0_f64));
+        let expected = Isometry3::from_axis_angle_translation(&axis, Degrees(40_f64), &Vector3::new(2_f64, 0_f64, 0_f64));
+        let result = isometry1.lerp_slerp(&isometry2, 1_f64 / 3_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sclerp_endpoints() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+        let isometry1 = Isometry3::from_axis_angle_translation(&axis, Degrees(20_f64), &Vector3::new(1_f64, 2_f64, 3_f64));
+        let isometry2 = Isometry3::from_axis_angle_translation(&axis, Degrees(140_f64), &Vector3::new(-4_f64, 0_f64, 5_f64));
+
+        assert!(relative_eq!(isometry1.sclerp(&isometry2, 0_f64), isometry1, epsilon = 1e-10));
+        assert!(relative_eq!(isometry1.sclerp(&isometry2, 1_f64), isometry2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sclerp_pure_rotation_about_offset_axis() {
+        // A half turn about the vertical line through (1, 0, 0) sweeps the 
+        // origin along a circular arc rather than a straight line.
+        let axis = Unit::from_value(Vector3::unit_z());
+        let center = Vector3::new(1_f64, 0_f64, 0_f64);
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(180_f64));
+        let isometry2 = Isometry3::from_parts(
+            &Translation3::from_vector(&(center - rotation.rotate_vector(&center))), 
+            &rotation
+        );
+        let isometry1 = Isometry3::identity();
+        let expected = Point3::new(1_f64, -1_f64, 0_f64);
+        let result = isometry1.sclerp(&isometry2, 0.5_f64).transform_point(&Point3::origin());
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    fn registration_points() -> [Point3<f64>; 6] {
+        [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(2_f64, 0_f64, 1_f64),
+            Point3::new(0_f64, 3_f64, -1_f64),
+            Point3::new(1_f64, 1_f64, 4_f64),
+            Point3::new(-2_f64, 5_f64, 2_f64),
+            Point3::new(3_f64, -1_f64, -2_f64),
+        ]
+    }
+
+    #[test]
+    fn test_kabsch_recovers_isometry() {
+        let axis = Unit::from_value(Vector3::new(-2_f64, 1_f64, 3_f64));
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(155_f64), &Vector3::new(4_f64, -1_f64, 2_f64));
+        let source = registration_points();
+        let target = source.map(|point| isometry.transform_point(&point));
+        let (result, residual) = Isometry3::kabsch(&source, &target).unwrap();
+
+        assert!(relative_eq!(result, isometry, epsilon = 1e-10));
+        assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_kabsch_half_turn() {
+        let isometry = Isometry3::from_angle_x(Degrees(180_f64));
+        let source = registration_points();
+        let target = source.map(|point| isometry.transform_point(&point));
+        let (result, _) = Isometry3::kabsch(&source, &target).unwrap();
+
+        for point in source.iter() {
+            assert!(relative_eq!(result.transform_point(point), isometry.transform_point(point), epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_kabsch_does_not_return_reflection() {
+        let source = registration_points();
+        let target = source.map(|point| Point3::new(-point.x, point.y, point.z));
+        let (result, residual) = Isometry3::kabsch(&source, &target).unwrap();
+        let determinant = result.rotation().matrix().determinant();
+
+        assert!(relative_eq!(determinant, 1_f64, epsilon = 1e-10));
+        assert!(residual > 0_f64);
+    }
+
+    #[test]
+    fn test_kabsch_residual() {
+        let source = registration_points();
+        let offsets = [1_f64, -1_f64, 1_f64, -1_f64, 1_f64, -1_f64];
+        let mut target = source;
+        for (point, offset) in target.iter_mut().zip(offsets) {
+            point.z += 0.1_f64 * offset;
+        }
+        let (result, residual) = Isometry3::kabsch(&source, &target).unwrap();
+        let expected = f64::sqrt(
+            source.iter()
+                .zip(target.iter())
+                .map(|(p, q)| (result.transform_point(p) - q).magnitude_squared())
+                .sum::<f64>() / 6_f64
+        );
+
+        assert!(relative_eq!(residual, expected, epsilon = 1e-10));
+        assert!(residual <= 0.1_f64);
+    }
+
+    #[test]
+    fn test_kabsch_mismatched_lengths() {
+        let source = registration_points();
+
+        assert!(Isometry3::kabsch(&source, &source[1..]).is_none());
+        assert!(Isometry3::<f64>::kabsch(&[], &[]).is_none());
+    }
+}
@@ -0,0 +1,400 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod normal2_tests {
+    use cglinalg::{
+        Affine2,
+        Degrees,
+        Isometry2,
+        Magnitude,
+        Matrix2x2,
+        Matrix3x3,
+        Normal2,
+        Point2,
+        Projective2,
+        Reflection2,
+        Rotation2,
+        Scale2,
+        Shear2,
+        Similarity2,
+        Translation2,
+        Unit,
+        UnitComplex,
+        Vector2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_from_vector_normalizes() {
+        let normal = Normal2::from_vector(&Vector2::new(3_f64, 4_f64));
+
+        assert!(relative_eq!(normal.as_vector().magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_try_from_vector_zero() {
+        assert!(Normal2::try_from_vector(&Vector2::<f64>::zero(), 1e-10).is_none());
+    }
+
+    #[test]
+    fn test_unit_vector_round_trip() {
+        let unit_vector = Unit::from_value(Vector2::new(1_f64, 1_f64));
+        let normal = Normal2::from_unit_vector(&unit_vector);
+
+        assert_eq!(normal.to_unit_vector(), unit_vector);
+        assert_eq!(Unit::from(normal), unit_vector);
+    }
+
+    #[test]
+    fn test_nonuniform_scale_keeps_normal_perpendicular() {
+        let tangent = Vector2::new(2_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let scale = Scale2::from_nonuniform_scale(3_f64, 0.5_f64);
+        let result = scale * normal;
+
+        assert!(relative_eq!(result.dot(&scale.scale_vector(&tangent)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.as_vector().magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_shear_keeps_normal_perpendicular() {
+        let tangent = Vector2::new(2_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let shear = Shear2::from_shear(2_f64, 0.25_f64);
+        let result = shear * normal;
+
+        assert!(relative_eq!(result.dot(&shear.shear_vector(&tangent)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let rotation = Rotation2::from_angle(Degrees(90_f64));
+        let expected = Normal2::from_vector(&rotation.rotate_vector(normal.as_vector()));
+
+        assert!(relative_eq!(rotation * normal, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_translation_leaves_normal_unchanged() {
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let translation = Translation2::new(5_f64, -3_f64);
+
+        assert_eq!(translation * normal, normal);
+    }
+
+    #[test]
+    fn test_reflection_keeps_normal_perpendicular() {
+        let tangent = Vector2::new(2_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let reflection = Reflection2::from_normal_bias(
+            &Unit::from_value(Vector2::new(1_f64, 1_f64)), &Vector2::new(1_f64, 0_f64)
+        );
+        let result = reflection * normal;
+
+        assert!(relative_eq!(result.dot(&reflection.reflect_vector(&tangent)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_isometry() {
+        let tangent = Vector2::new(2_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let isometry = Isometry2::from_angle_translation(Degrees(30_f64), &Vector2::new(1_f64, 2_f64));
+        let result = isometry * normal;
+
+        assert!(relative_eq!(result.dot(&isometry.transform_vector(&tangent)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_similarity_negative_scale() {
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let similarity = Similarity2::from_parts(
+            &Translation2::new(1_f64, 2_f64), &Rotation2::from_angle(Degrees(30_f64)), -2_f64
+        );
+        let point = Point2::new(1_f64, 1_f64);
+        let outside_point = point + normal.to_vector();
+        let result = similarity * normal;
+        let transformed_point = similarity.transform_point(&point);
+        let transformed_outside_point = similarity.transform_point(&outside_point);
+
+        assert!(result.dot(&(transformed_outside_point - transformed_point)) > 0_f64);
+    }
+
+    #[test]
+    fn test_unit_complex() {
+        let tangent = Vector2::new(2_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let rotation = UnitComplex::from_angle(Degrees(30_f64));
+        let result = rotation.transform_normal(&normal);
+
+        assert!(relative_eq!(result.dot(&rotation.rotate_vector(&tangent)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.as_vector().magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_projective_keeps_normal_perpendicular() {
+        let tangent = Vector2::new(2_f64, 1_f64);
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let projective = Projective2::from_matrix(Matrix3x3::new(
+            2_f64,  0.5_f64, 0.1_f64,
+            -1_f64, 1_f64,   0.2_f64,
+            3_f64,  1_f64,   1_f64
+        ));
+        let point = Point2::new(1_f64, 1_f64);
+        let transformed_point = projective.transform_point(&point).unwrap();
+        let transformed_tangent_point = projective.transform_point(&(point + tangent)).unwrap();
+        let result = projective.transform_normal(&point, &normal).unwrap();
+
+        assert!(relative_eq!(result.dot(&(transformed_tangent_point - transformed_point)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.as_vector().magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_projective_agrees_with_affine() {
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let affine = Affine2::from_parts(
+            &Matrix2x2::new(2_f64, 1_f64, 0.5_f64, 3_f64), &Vector2::new(4_f64, -1_f64)
+        );
+        let projective = Projective2::from(affine);
+        let expected = affine.transform_normal(&normal).unwrap();
+        let result = projective.transform_normal(&Point2::new(-3_f64, 7_f64), &normal).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_projective_transform_normal_singular() {
+        let normal = Normal2::from_vector(&Vector2::new(-1_f64, 2_f64));
+        let projective = Projective2::from_matrix(Matrix3x3::zero());
+
+        assert!(projective.transform_normal(&Point2::new(1_f64, 1_f64), &normal).is_none());
+    }
+}
+
+
+#[cfg(test)]
+mod normal3_tests {
+    use cglinalg::{
+        Degrees,
+        DualQuaternion,
+        Isometry3,
+        Magnitude,
+        Matrix4x4,
+        Normal3,
+        Point3,
+        Projective3,
+        Quaternion,
+        Reflection3,
+        Rotation3,
+        Scale3,
+        Shear3,
+        Similarity3,
+        Transform3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_from_vector_normalizes() {
+        let normal = Normal3::from_vector(&Vector3::new(1_f64, 2_f64, 2_f64));
+        let expected = Vector3::new(1_f64 / 3_f64, 2_f64 / 3_f64, 2_f64 / 3_f64);
+
+        assert!(relative_eq!(normal.as_vector(), &expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_negation() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let expected = Normal3::from_vector(&(-normal.to_vector()));
+
+        assert_eq!(-normal, expected);
+    }
+
+    #[test]
+    fn test_nonuniform_scale_keeps_normal_perpendicular() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let scale = Scale3::from_nonuniform_scale(3_f64, 0.5_f64, 2_f64);
+        let result = scale * normal;
+
+        assert!(relative_eq!(result.dot(&scale.scale_vector(&tangent1)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&scale.scale_vector(&tangent2)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.as_vector().magnitude(), 1_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_nonuniform_scale_differs_from_vector_transformation() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let scale = Scale3::from_nonuniform_scale(3_f64, 0.5_f64, 2_f64);
+        let result = scale * normal;
+        let naive = Normal3::from_vector(&scale.scale_vector(normal.as_vector()));
+
+        assert!(!relative_eq!(result, naive, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_shear_keeps_normal_perpendicular() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let shear = Shear3::from_shear_x(2_f64, -1_f64);
+        let result = shear * normal;
+
+        assert!(relative_eq!(result.dot(&shear.shear_vector(&tangent1)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&shear.shear_vector(&tangent2)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let expected = Normal3::from_vector(&rotation.rotate_vector(normal.as_vector()));
+
+        assert!(relative_eq!(rotation * normal, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_translation_leaves_normal_unchanged() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let translation = Translation3::new(5_f64, -3_f64, 1_f64);
+
+        assert_eq!(translation * normal, normal);
+    }
+
+    #[test]
+    fn test_reflection_keeps_normal_perpendicular() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let reflection = Reflection3::from_normal_bias(
+            &Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64)), &Vector3::new(1_f64, 0_f64, 0_f64)
+        );
+        let result = reflection * normal;
+
+        assert!(relative_eq!(result.dot(&reflection.reflect_vector(&tangent1)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&reflection.reflect_vector(&tangent2)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_isometry() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let axis = Unit::from_value(Vector3::new(2_f64, 1_f64, 1_f64));
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(-35_f64), &Vector3::new(-4_f64, 1_f64, 2_f64));
+        let result = isometry * normal;
+
+        assert!(relative_eq!(result.dot(&isometry.transform_vector(&tangent1)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&isometry.transform_vector(&tangent2)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_similarity() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let axis = Unit::from_value(Vector3::new(0_f64, 1_f64, -1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(130_f64));
+        let similarity = Similarity3::from_parts(&Translation3::new(2_f64, 5_f64, 1_f64), &rotation, 3_f64);
+        let expected = rotation * normal;
+
+        assert!(relative_eq!(similarity * normal, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_keeps_normal_perpendicular() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let transform = Shear3::from_shear_x(2_f64, -1_f64).to_transform() * 
+            Scale3::from_nonuniform_scale(3_f64, 0.5_f64, 2_f64).to_transform();
+        let result = transform.transform_normal(&normal).unwrap();
+
+        assert!(relative_eq!(result.dot(&transform.transform_vector(&tangent1)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&transform.transform_vector(&tangent2)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_transform_normal_singular() {
+        let transform = Transform3::from_matrix_unchecked(Matrix4x4::zero());
+
+        assert!(transform.transform_normal(&Normal3::<f64>::unit_x()).is_none());
+    }
+
+    #[test]
+    fn test_quaternion() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+        let quaternion = Quaternion::from_axis_angle(&axis, Degrees(72_f64)) * 3_f64;
+        let expected = rotation * normal;
+
+        assert!(relative_eq!(quaternion.transform_normal(&normal), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_dual_quaternion() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let axis = Unit::from_value(Vector3::new(2_f64, 1_f64, 1_f64));
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(-35_f64), &Vector3::new(-4_f64, 1_f64, 2_f64));
+        let dual_quaternion = DualQuaternion::from_isometry(&isometry);
+        let expected = isometry * normal;
+
+        assert!(relative_eq!(dual_quaternion.transform_normal(&normal), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_projective_keeps_normal_perpendicular() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let projective = Projective3::from_matrix(Matrix4x4::new(
+            2_f64,  0.5_f64, 0_f64,   0.1_f64,
+            -1_f64, 1_f64,   0.5_f64, 0.2_f64,
+            0_f64,  1_f64,   3_f64,   -0.1_f64,
+            1_f64,  2_f64,   -1_f64,  1_f64
+        ));
+        let point = Point3::new(1_f64, 1_f64, 1_f64);
+        let transformed_point = projective.transform_point(&point).unwrap();
+        let transformed_point1 = projective.transform_point(&(point + tangent1)).unwrap();
+        let transformed_point2 = projective.transform_point(&(point + tangent2)).unwrap();
+        let result = projective.transform_normal(&point, &normal).unwrap();
+
+        assert!(relative_eq!(result.dot(&(transformed_point1 - transformed_point)), 0_f64, epsilon = 1e-10));
+        assert!(relative_eq!(result.dot(&(transformed_point2 - transformed_point)), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_projective_transform_normal_singular() {
+        let tangent1 = Vector3::new(1_f64, 1_f64, 0_f64);
+        let tangent2 = Vector3::new(0_f64, 1_f64, -1_f64);
+        let normal = Normal3::from_vector(&tangent1.cross(&tangent2));
+        let projective = Projective3::from_matrix(Matrix4x4::zero());
+
+        assert!(projective.transform_normal(&Point3::new(1_f64, 1_f64, 1_f64), &normal).is_none());
+    }
+}
+
@@ -9,9 +9,16 @@ use cglinalg::{
     Matrix4x4,
     Angle,
     Degrees,
+    DepthRange,
+    Handedness,
     Radians,
     Point3,
+    StereoEye,
+    Vector2,
     Vector3,
+    Vector4,
+    halton_jitter,
+    oblique_near_plane,
 };
 use approx::{
     relative_eq,
@@ -152,6 +159,30 @@ fn test_perspective_projection_unproject_vector2() {
     assert!(relative_eq!(result, expected, epsilon = 1e-8));
 }
 
+#[test]
+fn test_perspective_projection_planes() {
+    let projection = Perspective3::new(-4.0, 3.0, -2.0, 1.0, 1.0, 100.0);
+
+    assert_eq!(projection.left_x(), -4.0);
+    assert_eq!(projection.right_x(), 3.0);
+    assert_eq!(projection.bottom_y(), -2.0);
+    assert_eq!(projection.top_y(), 1.0);
+    assert_eq!(projection.near_z(), 1.0);
+    assert_eq!(projection.far_z(), 100.0);
+}
+
+#[test]
+fn test_orthographic_projection_planes() {
+    let projection = Orthographic3::new(-4.0, 3.0, -2.0, 1.0, 1.0, 100.0);
+
+    assert_eq!(projection.left_x(), -4.0);
+    assert_eq!(projection.right_x(), 3.0);
+    assert_eq!(projection.bottom_y(), -2.0);
+    assert_eq!(projection.top_y(), 1.0);
+    assert_eq!(projection.near_z(), 1.0);
+    assert_eq!(projection.far_z(), 100.0);
+}
+
 #[test]
 fn test_orthographic_projection_matrix() {
     let left = -4.0;
@@ -289,3 +320,319 @@ fn test_orthographic_fov_projection_unproject_vector() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_perspective_projection_project_point_asymmetric_frustum() {
+    let projection = Perspective3::new(-0.5, 0.7, -0.4, 0.5, 1.0, 100.0);
+    let point = Point3::new(0.8, 0.6, -4.0);
+    let expected = Point3::from_homogeneous(projection.matrix() * point.to_homogeneous()).unwrap();
+    let result = projection.project_point(&point);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+}
+
+#[test]
+fn test_perspective_projection_stereo_eyes_are_mirror_images() {
+    let left = Perspective3::from_stereo_eye(StereoEye::Left, Degrees(70.0), 1.5, 0.1, 100.0, 0.065, 1.5);
+    let right = Perspective3::from_stereo_eye(StereoEye::Right, Degrees(70.0), 1.5, 0.1, 100.0, 0.065, 1.5);
+
+    assert!(relative_eq!(left.left_x(), -right.right_x(), epsilon = 1e-12));
+    assert!(relative_eq!(left.right_x(), -right.left_x(), epsilon = 1e-12));
+    assert!(left.left_x() + left.right_x() > 0.0);
+    assert_eq!(left.top_y(), right.top_y());
+    assert_eq!(left.bottom_y(), right.bottom_y());
+}
+
+#[test]
+fn test_perspective_projection_stereo_parallax() {
+    let ipd = 0.065;
+    let convergence = 1.5;
+    let perspective = PerspectiveFov3::new(Degrees(70.0), 1.5, 0.1, 100.0);
+    let left = perspective.to_stereo_eye(StereoEye::Left, ipd, convergence);
+    let right = perspective.to_stereo_eye(StereoEye::Right, ipd, convergence);
+    let parallax = |z: f64| {
+        let point = Point3::new(0.1, 0.2, z);
+        let left_projected = left.project_point(&(point - StereoEye::Left.offset(ipd)));
+        let right_projected = right.project_point(&(point - StereoEye::Right.offset(ipd)));
+
+        assert!(relative_eq!(left_projected.y, right_projected.y, epsilon = 1e-12));
+
+        right_projected.x - left_projected.x
+    };
+
+    assert!(relative_eq!(parallax(-convergence), 0.0, epsilon = 1e-12));
+    // Points beyond the convergence distance appear behind the screen, and 
+    // points in front of it appear in front of the screen.
+    assert!(parallax(-10.0) > 0.0);
+    assert!(parallax(-0.5) < 0.0);
+}
+
+#[test]
+fn test_perspective_projection_stereo_without_separation_is_symmetric() {
+    let perspective = PerspectiveFov3::new(Degrees(70.0), 1.5, 0.1, 100.0);
+    let result = perspective.to_stereo_eye(StereoEye::Left, 0.0, 2.0);
+
+    assert!(relative_eq!(result.matrix(), perspective.matrix(), epsilon = 1e-12));
+}
+
+#[test]
+fn test_perspective_projection_jittered_moves_points_in_ndc() {
+    let projection = Perspective3::new(-0.5, 0.7, -0.4, 0.5, 1.0, 100.0);
+    let (width, height) = (1280.0, 720.0);
+    let jitter = Vector2::new(-0.3, 0.45);
+    let jittered = projection.jittered(&jitter, width, height);
+    let point = Point3::new(2.0, -1.0, -7.0);
+    let offset = Vector3::new(2.0 * jitter.x / width, 2.0 * jitter.y / height, 0.0);
+    let expected = projection.project_point(&point) + offset;
+    let result = jittered.project_point(&point);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-12));
+}
+
+#[test]
+fn test_perspective_projection_fov_jittered() {
+    let perspective = PerspectiveFov3::new(Degrees(60.0), 16.0 / 9.0, 0.1, 100.0);
+    let jitter = Vector2::new(0.2, -0.1);
+    let point = Point3::new(-1.0, 0.5, -3.0);
+    let offset = Vector3::new(2.0 * jitter.x / 1920.0, 2.0 * jitter.y / 1080.0, 0.0);
+    let expected = perspective.project_point(&point) + offset;
+    let result = perspective.jittered(&jitter, 1920.0, 1080.0).project_point(&point);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-12));
+}
+
+#[test]
+fn test_orthographic_projection_jittered_moves_points_in_ndc() {
+    let projection = OrthographicFov3::new(Degrees(90.0), 4.0 / 3.0, 0.1, 100.0);
+    let (width, height) = (800.0, 600.0);
+    let jitter: Vector2<f64> = halton_jitter(3);
+    let jittered = projection.jittered_halton(3, width, height);
+    let point = Point3::new(2.0, -1.0, -7.0);
+    let offset = Vector3::new(2.0 * jitter.x / width, 2.0 * jitter.y / height, 0.0);
+    let expected = projection.project_point(&point) + offset;
+    let result = jittered.project_point(&point);
+
+    assert!(relative_eq!(result, expected, epsilon = 1e-12));
+}
+
+#[test]
+fn test_halton_jitter_sequence() {
+    let expected = [
+        Vector2::new( 0.0,    -1.0 / 6.0),
+        Vector2::new(-0.25,    1.0 / 6.0),
+        Vector2::new( 0.25,   -7.0 / 18.0),
+        Vector2::new(-0.375,  -1.0 / 18.0),
+    ];
+    for (index, expected_jitter) in expected.iter().enumerate() {
+        let result: Vector2<f64> = halton_jitter(index);
+
+        assert!(relative_eq!(result, expected_jitter, epsilon = 1e-12));
+    }
+}
+
+#[test]
+fn test_halton_jitter_stays_within_pixel() {
+    for index in 0..256 {
+        let jitter: Vector2<f64> = halton_jitter(index);
+
+        assert!(jitter.x >= -0.5 && jitter.x < 0.5);
+        assert!(jitter.y >= -0.5 && jitter.y < 0.5);
+    }
+}
+
+#[test]
+fn test_perspective_projection_decompose_opengl() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+    let (result, handedness) = Perspective3::decompose(perspective.matrix(), DepthRange::NegativeOneToOne).unwrap();
+
+    assert_eq!(handedness, Handedness::RightHanded);
+    assert!(relative_eq!(result, perspective, epsilon = 1e-8));
+}
+
+#[test]
+fn test_perspective_projection_decompose_scaled_matrix() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+    let matrix = perspective.matrix() * 3.0;
+    let (result, handedness) = Perspective3::decompose(&matrix, DepthRange::NegativeOneToOne).unwrap();
+
+    assert_eq!(handedness, Handedness::RightHanded);
+    assert!(relative_eq!(result, perspective, epsilon = 1e-8));
+}
+
+#[test]
+fn test_perspective_projection_decompose_left_handed_off_center() {
+    // An off-center left-handed projection with depth range `[0, 1]`.
+    let (left, right, bottom, top, near, far) = (-0.2, 0.6, -0.45, 0.3, 0.5, 40.0);
+    let matrix = Matrix4x4::new(
+        2.0 * near / (right - left),       0.0,                               0.0,                       0.0,
+        0.0,                               2.0 * near / (top - bottom),       0.0,                       0.0,
+        -(right + left) / (right - left), -(top + bottom) / (top - bottom),   far / (far - near),        1.0,
+        0.0,                               0.0,                              -near * far / (far - near), 0.0
+    );
+    let (result, handedness) = Perspective3::decompose(&matrix, DepthRange::ZeroToOne).unwrap();
+    let expected = Perspective3::new(left, right, bottom, top, near, far);
+
+    assert_eq!(handedness, Handedness::LeftHanded);
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+}
+
+#[test]
+fn test_perspective_projection_decompose_reversed_depth() {
+    let (near, far) = (0.1, 1000.0);
+    let matrix = Matrix4x4::new(
+        1.0, 0.0, 0.0,                         0.0,
+        0.0, 1.5, 0.0,                         0.0,
+        0.0, 0.0, near / (far - near),        -1.0,
+        0.0, 0.0, near * far / (far - near),   0.0
+    );
+    let (result, handedness) = Perspective3::decompose(&matrix, DepthRange::ReversedZeroToOne).unwrap();
+
+    assert_eq!(handedness, Handedness::RightHanded);
+    assert!(relative_eq!(result.near_z(), near, epsilon = 1e-10));
+    assert!(relative_eq!(result.far_z(), far, epsilon = 1e-6));
+    assert!(Perspective3::decompose(&matrix, DepthRange::ZeroToOne).is_none());
+}
+
+#[test]
+fn test_perspective_projection_decompose_flipped_y() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+    let mut matrix = *perspective.matrix();
+    matrix.c1r1 = -matrix.c1r1;
+    matrix.c2r1 = -matrix.c2r1;
+    let (result, _) = Perspective3::decompose(&matrix, DepthRange::NegativeOneToOne).unwrap();
+
+    assert!(relative_eq!(result.bottom_y(), 0.4, epsilon = 1e-10));
+    assert!(relative_eq!(result.top_y(), -0.2, epsilon = 1e-10));
+}
+
+#[test]
+fn test_perspective_projection_decompose_infinite_far_plane() {
+    let near = 0.1;
+    let matrix = Matrix4x4::new(
+        1.0, 0.0,  0.0,        0.0,
+        0.0, 1.0,  0.0,        0.0,
+        0.0, 0.0, -1.0,       -1.0,
+        0.0, 0.0, -2.0 * near, 0.0
+    );
+
+    assert!(Perspective3::decompose(&matrix, DepthRange::NegativeOneToOne).is_none());
+}
+
+#[test]
+fn test_perspective_projection_decompose_not_a_projection() {
+    let orthographic = Orthographic3::new(-4.0, 4.0, -2.0, 2.0, 1.0, 100.0);
+    let rotation = Matrix4x4::from_affine_angle_z(Degrees(30.0));
+
+    assert!(Perspective3::decompose(orthographic.matrix(), DepthRange::NegativeOneToOne).is_none());
+    assert!(Perspective3::decompose(&rotation, DepthRange::NegativeOneToOne).is_none());
+    assert!(Perspective3::decompose(&Matrix4x4::<f64>::zero(), DepthRange::NegativeOneToOne).is_none());
+}
+
+#[test]
+fn test_perspective_projection_fov_decompose_off_center() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+
+    assert!(PerspectiveFov3::decompose(perspective.matrix(), DepthRange::NegativeOneToOne).is_none());
+}
+
+#[test]
+fn test_orthographic_projection_decompose_opengl() {
+    let orthographic = OrthographicFov3::new(Degrees(90.0), 4.0 / 3.0, 0.1, 100.0);
+    let (result, handedness) = Orthographic3::decompose(orthographic.matrix(), DepthRange::NegativeOneToOne).unwrap();
+
+    assert_eq!(handedness, Handedness::RightHanded);
+    assert!(relative_eq!(result, orthographic.to_orthographic(), epsilon = 1e-10));
+}
+
+#[test]
+fn test_orthographic_projection_decompose_left_handed() {
+    // A left-handed orthographic projection with depth range `[0, 1]`.
+    let (left, right, bottom, top, near, far) = (-5.0, 3.0, -2.0, 4.0, -1.0, 30.0);
+    let matrix = Matrix4x4::new(
+        2.0 / (right - left),             0.0,                              0.0,                  0.0,
+        0.0,                              2.0 / (top - bottom),             0.0,                  0.0,
+        0.0,                              0.0,                              1.0 / (far - near),   0.0,
+        -(right + left) / (right - left), -(top + bottom) / (top - bottom), -near / (far - near), 1.0
+    );
+    let (result, handedness) = Orthographic3::decompose(&matrix, DepthRange::ZeroToOne).unwrap();
+    let expected = Orthographic3::new(left, right, bottom, top, near, far);
+
+    assert_eq!(handedness, Handedness::LeftHanded);
+    assert!(relative_eq!(result, expected, epsilon = 1e-10));
+}
+
+#[test]
+fn test_orthographic_projection_decompose_not_a_projection() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+
+    assert!(Orthographic3::decompose(perspective.matrix(), DepthRange::NegativeOneToOne).is_none());
+}
+
+#[test]
+fn test_oblique_near_plane_maps_clip_plane_to_near_plane() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+    let clip_plane = Vector4::new(0.2, 0.6, -0.8, -2.0);
+    let oblique = perspective.oblique_near_plane(&clip_plane).unwrap();
+    let points = [
+        Point3::new(0.0, 0.0, -2.5),
+        Point3::new(1.0, -1.0, -3.0),
+        Point3::new(-2.0, 3.0, -0.75),
+    ];
+    for point in points.iter() {
+        let projected = Point3::from_homogeneous(oblique * point.to_homogeneous()).unwrap();
+        let expected = perspective.project_point(point);
+
+        assert!(relative_eq!(clip_plane.dot(&point.to_homogeneous()), 0.0, epsilon = 1e-10));
+        assert!(relative_eq!(projected.z, -1.0, epsilon = 1e-10));
+        assert!(relative_eq!(projected.x, expected.x, epsilon = 1e-10));
+        assert!(relative_eq!(projected.y, expected.y, epsilon = 1e-10));
+    }
+}
+
+#[test]
+fn test_oblique_near_plane_clips_points_behind_clip_plane() {
+    let perspective = PerspectiveFov3::new(Degrees(70.0), 1.5, 0.1, 100.0);
+    let clip_plane = Vector4::new(0.0, 1.0, -1.0, -4.0);
+    let oblique = perspective.oblique_near_plane(&clip_plane).unwrap();
+    let in_front = Point3::new(0.1, 0.5, -6.0);
+    let behind = Point3::new(0.1, 0.5, -3.0);
+    let projected_in_front = Point3::from_homogeneous(oblique * in_front.to_homogeneous()).unwrap();
+    let projected_behind = Point3::from_homogeneous(oblique * behind.to_homogeneous()).unwrap();
+
+    assert!(clip_plane.dot(&in_front.to_homogeneous()) > 0.0);
+    assert!(projected_in_front.z > -1.0 && projected_in_front.z < 1.0);
+    assert!(clip_plane.dot(&behind.to_homogeneous()) < 0.0);
+    assert!(projected_behind.z < -1.0);
+}
+
+#[test]
+fn test_oblique_near_plane_far_corner_stays_on_far_plane() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+    let clip_plane = Vector4::new(-0.3, 0.4, -0.5, -1.0);
+    let oblique = perspective.oblique_near_plane(&clip_plane).unwrap();
+    // The far plane corner opposite the clipping plane, with `x` and `y` 
+    // matching the signs of the clipping plane in clip space.
+    let corner = perspective.unproject_point(&Point3::new(-1.0, 1.0, 1.0));
+    let projected = Point3::from_homogeneous(oblique * corner.to_homogeneous()).unwrap();
+
+    assert!(relative_eq!(projected, Point3::new(-1.0, 1.0, 1.0), epsilon = 1e-8));
+}
+
+#[test]
+fn test_oblique_near_plane_matrix_matches_methods() {
+    let perspective = PerspectiveFov3::new(Degrees(70.0), 1.5, 0.1, 100.0);
+    let clip_plane = Vector4::new(0.0, 1.0, -1.0, -4.0);
+    let expected = oblique_near_plane(perspective.matrix(), &clip_plane);
+    let result = perspective.oblique_near_plane(&clip_plane);
+
+    assert_eq!(result, expected);
+    assert!(result.is_some());
+}
+
+#[test]
+fn test_oblique_near_plane_camera_in_front_of_clip_plane() {
+    let perspective = Perspective3::new(-0.3, 0.5, -0.2, 0.4, 0.1, 250.0);
+    let clip_plane = Vector4::new(0.0, 0.0, -1.0, 2.0);
+
+    assert!(perspective.oblique_near_plane(&clip_plane).is_none());
+    assert!(oblique_near_plane(&Matrix4x4::<f64>::zero(), &Vector4::new(0.0, 0.0, -1.0, -2.0)).is_none());
+}
@@ -0,0 +1,367 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod projective2_tests {
+    use cglinalg::{
+        Degrees,
+        Isometry2,
+        Matrix3x3,
+        Point2,
+        Projective2,
+        Rotation3,
+        Unit,
+        Vector2,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_from_point_correspondences_four_points() {
+        let homography = Projective2::from_matrix(Matrix3x3::new(
+            0.8_f64,  0.3_f64,  0.0015_f64,
+            -0.1_f64, 1.1_f64,  -0.0007_f64,
+            12_f64,   -40_f64,  1_f64
+        ));
+        let unit_square = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 0_f64),
+            Point2::new(1_f64, 1_f64),
+            Point2::new(0_f64, 1_f64),
+        ];
+        let source = unit_square.map(|point| Point2::new(point.x * 640_f64, point.y * 480_f64));
+        let target = source.map(|point| homography.transform_point(&point).unwrap());
+        let result = Projective2::from_point_correspondences(&source, &target).unwrap();
+
+        assert!(relative_eq!(result, homography, epsilon = 1e-8, max_relative = 1e-8));
+    }
+
+    #[test]
+    fn test_from_point_correspondences_overdetermined() {
+        let homography = Projective2::from_matrix(Matrix3x3::new(
+            0.8_f64,  0.3_f64,  0.0015_f64,
+            -0.1_f64, 1.1_f64,  -0.0007_f64,
+            12_f64,   -40_f64,  1_f64
+        ));
+        let source = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(640_f64, 0_f64),
+            Point2::new(640_f64, 480_f64),
+            Point2::new(0_f64, 480_f64),
+            Point2::new(320_f64, 240_f64),
+            Point2::new(100_f64, 400_f64),
+            Point2::new(500_f64, 60_f64),
+        ];
+        let target = source.map(|point| homography.transform_point(&point).unwrap());
+        let result = Projective2::from_point_correspondences(&source, &target).unwrap();
+
+        for (source_point, target_point) in source.iter().zip(target.iter()) {
+            assert!(relative_eq!(result.transform_point(source_point).unwrap(), target_point, epsilon = 1e-8));
+        }
+    }
+
+    #[test]
+    fn test_from_point_correspondences_too_few_points() {
+        let source = [Point2::new(0_f64, 0_f64), Point2::new(1_f64, 0_f64), Point2::new(0_f64, 1_f64)];
+
+        assert!(Projective2::from_point_correspondences(&source, &source).is_none());
+    }
+
+    #[test]
+    fn test_from_point_correspondences_collinear_points() {
+        let source = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 1_f64),
+            Point2::new(2_f64, 2_f64),
+            Point2::new(3_f64, 3_f64),
+        ];
+
+        assert!(Projective2::from_point_correspondences(&source, &source).is_none());
+    }
+
+    #[test]
+    fn test_square_to_quad() {
+        let quad = [
+            Point2::new(10_f64, 20_f64),
+            Point2::new(310_f64, 5_f64),
+            Point2::new(290_f64, 250_f64),
+            Point2::new(-5_f64, 230_f64),
+        ];
+        let unit_square = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 0_f64),
+            Point2::new(1_f64, 1_f64),
+            Point2::new(0_f64, 1_f64),
+        ];
+        let projective = Projective2::from_square_to_quad(&quad).unwrap();
+
+        for (corner, expected) in unit_square.iter().zip(quad.iter()) {
+            assert!(relative_eq!(projective.transform_point(corner).unwrap(), expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_square_to_parallelogram_is_affine() {
+        let parallelogram = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(2_f64, 1_f64),
+            Point2::new(3_f64, 4_f64),
+            Point2::new(1_f64, 3_f64),
+        ];
+        let projective = Projective2::from_square_to_quad(&parallelogram).unwrap();
+
+        assert!(projective.is_affine());
+    }
+
+    #[test]
+    fn test_quad_to_square() {
+        let quad = [
+            Point2::new(10_f64, 20_f64),
+            Point2::new(310_f64, 5_f64),
+            Point2::new(290_f64, 250_f64),
+            Point2::new(-5_f64, 230_f64),
+        ];
+        let unit_square = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 0_f64),
+            Point2::new(1_f64, 1_f64),
+            Point2::new(0_f64, 1_f64),
+        ];
+        let projective = Projective2::from_quad_to_square(&quad).unwrap();
+
+        for (corner, expected) in quad.iter().zip(unit_square.iter()) {
+            assert!(relative_eq!(projective.transform_point(corner).unwrap(), expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_quad_to_quad() {
+        let quad = [
+            Point2::new(10_f64, 20_f64),
+            Point2::new(310_f64, 5_f64),
+            Point2::new(290_f64, 250_f64),
+            Point2::new(-5_f64, 230_f64),
+        ];
+        let target = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(4_f64, 1_f64),
+            Point2::new(5_f64, 5_f64),
+            Point2::new(1_f64, 3_f64),
+        ];
+        let projective = Projective2::from_quad_to_quad(&quad, &target).unwrap();
+
+        for (corner, expected) in quad.iter().zip(target.iter()) {
+            assert!(relative_eq!(projective.transform_point(corner).unwrap(), expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_degenerate_quad() {
+        let quad = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 0_f64),
+            Point2::new(2_f64, 0_f64),
+            Point2::new(3_f64, 0_f64),
+        ];
+
+        assert!(Projective2::from_square_to_quad(&quad).is_none());
+    }
+
+    #[test]
+    fn test_inverse_transform_point() {
+        let homography = Projective2::from_matrix(Matrix3x3::new(
+            0.8_f64,  0.3_f64,  0.0015_f64,
+            -0.1_f64, 1.1_f64,  -0.0007_f64,
+            12_f64,   -40_f64,  1_f64
+        ));
+        let point = Point2::new(100_f64, 50_f64);
+        let transformed_point = homography.transform_point(&point).unwrap();
+        let result = homography.inverse_transform_point(&transformed_point).unwrap();
+
+        assert!(relative_eq!(result, point, epsilon = 1e-8));
+    }
+
+    #[test]
+    fn test_projective_times_isometry() {
+        let homography = Projective2::from_matrix(Matrix3x3::new(
+            0.8_f64,  0.3_f64,  0.0015_f64,
+            -0.1_f64, 1.1_f64,  -0.0007_f64,
+            12_f64,   -40_f64,  1_f64
+        ));
+        let isometry = Isometry2::from_angle_translation(Degrees(30_f64), &Vector2::new(2_f64, -1_f64));
+        let result: Projective2<f64> = homography * isometry;
+        let point = Point2::new(3_f64, 7_f64);
+        let expected = homography.transform_point(&isometry.transform_point(&point)).unwrap();
+
+        assert!(relative_eq!(result.transform_point(&point).unwrap(), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_decompose_planar_pose() {
+        let intrinsics = Matrix3x3::new(
+            700_f64, 0_f64,   0_f64,
+            0_f64,   710_f64, 0_f64,
+            330_f64, 250_f64, 1_f64
+        );
+        let axis = Unit::from_value(Vector3::new(-1_f64, 3_f64, 0.25_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(40_f64));
+        let translation = Vector3::new(-0.3_f64, 0.2_f64, 2.5_f64);
+        let rotation_matrix = rotation.matrix();
+        let plane_to_camera = Matrix3x3::from_columns(
+            rotation_matrix.column(0), rotation_matrix.column(1), translation
+        );
+        let projective = Projective2::from_matrix(intrinsics * plane_to_camera * -0.5_f64);
+        let (result_rotation, result_translation) = projective.decompose_planar_pose(&intrinsics).unwrap();
+
+        assert!(relative_eq!(result_rotation, rotation, epsilon = 1e-10));
+        assert!(relative_eq!(result_translation, translation, epsilon = 1e-10));
+    }
+}
+
+
+#[cfg(test)]
+mod projective3_tests {
+    use cglinalg::{
+        Affine3,
+        Degrees,
+        Isometry3,
+        Matrix4x4,
+        Orthographic3,
+        Perspective3,
+        PerspectiveFov3,
+        Point3,
+        Projective3,
+        Rotation3,
+        Scale3,
+        Translation3,
+        Unit,
+        Vector3,
+        Vector4,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_identity() {
+        let projective = Projective3::identity();
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+
+        assert_eq!(projective.transform_point(&point), Some(point));
+        assert!(projective.is_affine());
+    }
+
+    #[test]
+    fn test_transform_point_perspective_division() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let projective = Projective3::from(perspective);
+        let point = Point3::new(-1_f64, -1_f64, -4_f64);
+        let expected = perspective.project_point(&point);
+        let result = projective.transform_point(&point).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        assert!(!projective.is_affine());
+    }
+
+    #[test]
+    fn test_transform_point_at_infinity() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let projective = Projective3::from(perspective);
+        let point = Point3::new(1_f64, 1_f64, 0_f64);
+
+        assert!(projective.transform_point(&point).is_none());
+    }
+
+    #[test]
+    fn test_transform_homogeneous() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let projective = Projective3::from(perspective);
+        let vector = Vector4::new(1_f64, -2_f64, -3_f64, 1_f64);
+        let expected = perspective.matrix() * vector;
+
+        assert_eq!(projective.transform_homogeneous(&vector), expected);
+        assert_eq!(projective * vector, expected);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let projective = Projective3::from(PerspectiveFov3::new(Degrees(72_f64), 16_f64 / 9_f64, 0.1_f64, 100_f64));
+        let projective_inv = projective.inverse().unwrap();
+
+        assert!(relative_eq!(projective * projective_inv, Projective3::identity(), epsilon = 1e-8));
+    }
+
+    #[test]
+    fn test_inverse_transform_point() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let projective = Projective3::from(perspective);
+        let point = Point3::new(2_f64, 1_f64, -7_f64);
+        let projected_point = projective.transform_point(&point).unwrap();
+        let result = projective.inverse_transform_point(&projected_point).unwrap();
+
+        assert!(relative_eq!(result, point, epsilon = 1e-8));
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let projective: Projective3<f64> = Projective3::from_matrix(Matrix4x4::zero());
+
+        assert!(projective.inverse().is_none());
+        assert!(!projective.is_invertible());
+    }
+
+    #[test]
+    fn test_perspective_times_view() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let eye = Point3::new(1_f64, 2_f64, 5_f64);
+        let target = Point3::new(0_f64, 0_f64, 0_f64);
+        let up = Vector3::unit_y();
+        let view = Isometry3::look_at_rh(&eye, &target, &up);
+        let result: Projective3<f64> = perspective * view;
+        let point = Point3::new(1_f64, -1_f64, 1_f64);
+        let expected = perspective.project_point(&view.transform_point(&point));
+
+        assert!(relative_eq!(result.transform_point(&point).unwrap(), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_orthographic_times_transform() {
+        let orthographic = Orthographic3::new(-4_f64, 4_f64, -3_f64, 3_f64, 0.1_f64, 50_f64);
+        let transform = Scale3::from_scale(2_f64).to_transform();
+        let result: Projective3<f64> = orthographic * transform;
+        let point = Point3::new(1_f64, 1_f64, -2_f64);
+        let expected = orthographic.project_point(&transform.transform_point(&point));
+
+        assert!(relative_eq!(result.transform_point(&point).unwrap(), expected, epsilon = 1e-10));
+        assert!(result.is_affine());
+    }
+
+    #[test]
+    fn test_projective_times_rotation() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let projective = Projective3::from(perspective);
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(20_f64));
+        let result: Projective3<f64> = projective * rotation;
+        let point = Point3::new(0_f64, 1_f64, -5_f64);
+        let expected = projective.transform_point(&rotation.rotate_point(&point)).unwrap();
+
+        assert!(relative_eq!(result.transform_point(&point).unwrap(), expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_to_affine() {
+        let perspective = Perspective3::new(-3_f64, 3_f64, -2_f64, 2_f64, 1_f64, 100_f64);
+        let translation = Translation3::new(1_f64, 2_f64, 3_f64);
+        let projective = Projective3::from(translation);
+        let expected = Affine3::from(translation);
+
+        assert_eq!(projective.to_affine(), Some(expected));
+        assert!(Projective3::from(perspective).to_affine().is_none());
+    }
+}
+
@@ -768,4 +768,82 @@ mod inverse_tests {
 
         assert!(!quaternion.is_invertible());
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod swing_twist_tests {
+    use cglinalg::{
+        Quaternion,
+        Degrees,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_swing_twist_decomposition_recovers_parts() {
+        let axis_x = Unit::from_value(Vector3::unit_x());
+        let axis_z = Unit::from_value(Vector3::unit_z());
+        let expected_swing = Quaternion::from_axis_angle(&axis_x, Degrees(25_f64));
+        let expected_twist = Quaternion::from_axis_angle(&axis_z, Degrees(110_f64));
+        let quaternion = expected_swing * expected_twist;
+        let (swing, twist) = quaternion.swing_twist_decomposition(&axis_z);
+
+        assert!(relative_eq!(swing, expected_swing, epsilon = 1e-10));
+        assert!(relative_eq!(twist, expected_twist, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_swing_twist_decomposition_product_is_original() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation_axis = Unit::from_value(Vector3::new(-2_f64, 1_f64, 5_f64));
+        let quaternion = Quaternion::from_axis_angle(&rotation_axis, Degrees(75_f64));
+        let (swing, twist) = quaternion.swing_twist_decomposition(&axis);
+
+        assert!(relative_eq!(swing * twist, quaternion, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_swing_twist_decomposition_twist_rotates_about_axis() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation_axis = Unit::from_value(Vector3::new(-2_f64, 1_f64, 5_f64));
+        let quaternion = Quaternion::from_axis_angle(&rotation_axis, Degrees(75_f64));
+        let (_, twist) = quaternion.swing_twist_decomposition(&axis);
+
+        assert!(relative_eq!(twist.v.cross(axis.as_ref()), Vector3::zero(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_swing_twist_decomposition_swing_is_perpendicular_to_axis() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation_axis = Unit::from_value(Vector3::new(-2_f64, 1_f64, 5_f64));
+        let quaternion = Quaternion::from_axis_angle(&rotation_axis, Degrees(75_f64));
+        let (swing, _) = quaternion.swing_twist_decomposition(&axis);
+
+        assert!(relative_eq!(swing.v.dot(axis.as_ref()), 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_swing_twist_decomposition_pure_twist() {
+        let axis = Unit::from_value(Vector3::unit_y());
+        let quaternion = Quaternion::from_axis_angle(&axis, Degrees(40_f64));
+        let (swing, twist) = quaternion.swing_twist_decomposition(&axis);
+
+        assert!(relative_eq!(swing, Quaternion::identity(), epsilon = 1e-10));
+        assert!(relative_eq!(twist, quaternion, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_swing_twist_decomposition_half_turn_swing() {
+        let axis = Unit::from_value(Vector3::unit_z());
+        let swing_axis = Unit::from_value(Vector3::unit_x());
+        let quaternion = Quaternion::from_axis_angle(&swing_axis, Degrees(180_f64));
+        let (swing, twist) = quaternion.swing_twist_decomposition(&axis);
+
+        assert_eq!(twist, Quaternion::identity());
+        assert!(relative_eq!(swing, quaternion, epsilon = 1e-10));
+    }
+}
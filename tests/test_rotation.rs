@@ -733,5 +733,39 @@ mod rotation3_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_swing_twist_decomposition() {
+        let axis_y = Unit::from_value(Vector3::unit_y());
+        let axis_z = Unit::from_value(Vector3::unit_z());
+        let expected_swing = Rotation3::from_axis_angle(&axis_y, Degrees(-35_f64));
+        let expected_twist = Rotation3::from_axis_angle(&axis_z, Degrees(80_f64));
+        let rotation = expected_swing * expected_twist;
+        let (swing, twist) = rotation.swing_twist_decomposition(&axis_z);
+
+        assert!(relative_eq!(swing, expected_swing, epsilon = 1e-10));
+        assert!(relative_eq!(twist, expected_twist, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_swing_twist_decomposition_product_is_original() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let rotation_axis = Unit::from_value(Vector3::new(3_f64, -1_f64, 2_f64));
+        let rotation = Rotation3::from_axis_angle(&rotation_axis, Degrees(130_f64));
+        let (swing, twist) = rotation.swing_twist_decomposition(&axis);
+
+        assert!(relative_eq!(swing * twist, rotation, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_slerp_takes_shortest_arc() {
+        let axis = Unit::from_value(Vector3::unit_x());
+        let rotation1 = Rotation3::from_axis_angle(&axis, Degrees(170_f64));
+        let rotation2 = Rotation3::from_axis_angle(&axis, Degrees(-170_f64));
+        let expected = Rotation3::from_axis_angle(&axis, Degrees(180_f64));
+        let result = rotation1.slerp(&rotation2, 0.5_f64);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
 }
 
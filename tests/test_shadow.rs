@@ -0,0 +1,164 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod shadow_cascade_tests {
+    use cglinalg::{
+        Angle,
+        Degrees,
+        Isometry3,
+        PerspectiveFov3,
+        Point3,
+        ShadowCascade,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    const RESOLUTION: f64 = 1024_f64;
+
+    fn frustum_slice_corners(view: &Isometry3<f64>, projection: &PerspectiveFov3<f64>, near: f64, far: f64) -> [Point3<f64>; 8] {
+        let tan_half_vfov = (projection.vfov() / 2_f64).tan();
+        let mut corners = [Point3::origin(); 8];
+        let mut i = 0;
+        for &distance in [near, far].iter() {
+            let half_height = distance * tan_half_vfov;
+            let half_width = half_height * projection.aspect();
+            for &(sx, sy) in [(-1_f64, -1_f64), (1_f64, -1_f64), (1_f64, 1_f64), (-1_f64, 1_f64)].iter() {
+                let corner = Point3::new(sx * half_width, sy * half_height, -distance);
+                corners[i] = view.inverse_transform_point(&corner);
+                i += 1;
+            }
+        }
+
+        corners
+    }
+
+    #[test]
+    fn test_practical_splits_cover_range() {
+        let splits: [(f64, f64); 4] = ShadowCascade::practical_splits(0.5_f64, 300_f64, 0.6_f64);
+
+        assert_eq!(splits[0].0, 0.5_f64);
+        assert_eq!(splits[3].1, 300_f64);
+        for i in 0..3 {
+            assert_eq!(splits[i].1, splits[i + 1].0);
+            assert!(splits[i].0 < splits[i].1);
+        }
+    }
+
+    #[test]
+    fn test_practical_splits_blend() {
+        let logarithmic: [(f64, f64); 4] = ShadowCascade::practical_splits(0.5_f64, 300_f64, 1_f64);
+        let uniform: [(f64, f64); 4] = ShadowCascade::practical_splits(0.5_f64, 300_f64, 0_f64);
+        let blended: [(f64, f64); 4] = ShadowCascade::practical_splits(0.5_f64, 300_f64, 0.25_f64);
+        for i in 0..4 {
+            let expected = 0.25_f64 * logarithmic[i].1 + 0.75_f64 * uniform[i].1;
+
+            assert!(relative_eq!(blended[i].1, expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_cascades_enclose_frustum_slices() {
+        let projection = PerspectiveFov3::new(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 200_f64);
+        let light_direction = Unit::from_value(Vector3::new(-0.4_f64, -1_f64, 0.3_f64));
+        let up = Vector3::unit_y();
+        let views = [
+            Isometry3::look_at_rh(&Point3::new(0_f64, 2_f64, 10_f64), &Point3::origin(), &up),
+            Isometry3::look_at_rh(&Point3::new(-30_f64, 5_f64, 4_f64), &Point3::new(12_f64, 0_f64, -7_f64), &up),
+            Isometry3::look_at_rh(&Point3::new(3_f64, 40_f64, 3_f64), &Point3::new(0_f64, 0_f64, 0_f64), &up),
+        ];
+        for view in views.iter() {
+            let cascades: [ShadowCascade<f64>; 4] = ShadowCascade::from_frustum(
+                view, &projection, &light_direction, 0.75_f64, RESOLUTION
+            );
+            for cascade in cascades.iter() {
+                for corner in frustum_slice_corners(view, &projection, cascade.near_z(), cascade.far_z()).iter() {
+                    let projected = cascade.project_point(corner);
+
+                    assert!(projected.x.abs() <= 1_f64 + 1e-10, "{}", projected);
+                    assert!(projected.y.abs() <= 1_f64 + 1e-10, "{}", projected);
+                    assert!(projected.z.abs() <= 1_f64 + 1e-10, "{}", projected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cascade_size_does_not_depend_on_camera_rotation() {
+        let projection = PerspectiveFov3::new(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 200_f64);
+        let light_direction = Unit::from_value(Vector3::new(-0.4_f64, -1_f64, 0.3_f64));
+        let eye = Point3::new(0_f64, 2_f64, 10_f64);
+        let up = Vector3::unit_y();
+        let view1 = Isometry3::look_at_rh(&eye, &Point3::origin(), &up);
+        let view2 = Isometry3::look_at_rh(&eye, &Point3::new(25_f64, -3_f64, 1_f64), &up);
+        let cascade1 = ShadowCascade::from_frustum_slice(
+            &view1, &projection, &light_direction, 1_f64, 20_f64, RESOLUTION
+        );
+        let cascade2 = ShadowCascade::from_frustum_slice(
+            &view2, &projection, &light_direction, 1_f64, 20_f64, RESOLUTION
+        );
+
+        assert!(relative_eq!(cascade1.projection(), cascade2.projection(), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_cascade_texels_stay_fixed_in_world_space() {
+        let projection = PerspectiveFov3::new(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 200_f64);
+        let light_direction = Unit::from_value(Vector3::new(-0.4_f64, -1_f64, 0.3_f64));
+        let up = Vector3::unit_y();
+        let target = Point3::new(0_f64, 0_f64, 0_f64);
+        let view1 = Isometry3::look_at_rh(&Point3::new(0_f64, 2_f64, 10_f64), &target, &up);
+        let view2 = Isometry3::look_at_rh(
+            &Point3::new(0.137_f64, 2.051_f64, 9.9_f64), &(target + Vector3::new(0.137_f64, 0.051_f64, -0.1_f64)), &up
+        );
+        let cascade1 = ShadowCascade::from_frustum_slice(
+            &view1, &projection, &light_direction, 1_f64, 20_f64, RESOLUTION
+        );
+        let cascade2 = ShadowCascade::from_frustum_slice(
+            &view2, &projection, &light_direction, 1_f64, 20_f64, RESOLUTION
+        );
+        let point = Point3::new(1_f64, 0.5_f64, -2_f64);
+        let texel1 = (cascade1.project_point(&point).x + 1_f64) * RESOLUTION / 2_f64;
+        let texel2 = (cascade2.project_point(&point).x + 1_f64) * RESOLUTION / 2_f64;
+        let shift = texel2 - texel1;
+
+        assert!(relative_eq!(shift, shift.round(), epsilon = 1e-6));
+    }
+
+    #[test]
+    fn test_matrix_agrees_with_project_point() {
+        let projection = PerspectiveFov3::new(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 200_f64);
+        let light_direction = Unit::from_value(Vector3::new(-0.4_f64, -1_f64, 0.3_f64));
+        let view = Isometry3::look_at_rh(&Point3::new(0_f64, 2_f64, 10_f64), &Point3::origin(), &Vector3::unit_y());
+        let cascade = ShadowCascade::from_frustum_slice(
+            &view, &projection, &light_direction, 1_f64, 20_f64, RESOLUTION
+        );
+        let point = Point3::new(1_f64, 0.5_f64, -2_f64);
+        let expected = cascade.project_point(&point);
+        let result = Point3::from_homogeneous(cascade.to_matrix() * point.to_homogeneous()).unwrap();
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_extend_toward_light() {
+        let projection = PerspectiveFov3::new(Degrees(70_f64), 16_f64 / 9_f64, 0.1_f64, 200_f64);
+        let light_direction = Unit::from_value(Vector3::new(-0.4_f64, -1_f64, 0.3_f64));
+        let view = Isometry3::look_at_rh(&Point3::new(0_f64, 2_f64, 10_f64), &Point3::origin(), &Vector3::unit_y());
+        let cascade = ShadowCascade::from_frustum_slice(
+            &view, &projection, &light_direction, 1_f64, 20_f64, RESOLUTION
+        );
+        let extended = cascade.extend_toward_light(50_f64);
+        // A shadow caster far up along the light direction from the slice.
+        let caster = Point3::new(0_f64, 0_f64, 0_f64) - light_direction.into_inner() * 40_f64;
+
+        assert!(cascade.project_point(&caster).z < -1_f64);
+        assert!(extended.project_point(&caster).z.abs() <= 1_f64);
+        assert_eq!(extended.projection().left_x(), cascade.projection().left_x());
+        assert_eq!(extended.light_view(), cascade.light_view());
+    }
+}
@@ -200,6 +200,24 @@ mod similarity2_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_umeyama_recovers_similarity() {
+        let similarity = Similarity2::from_parts(
+            &Translation2::new(4_f64, -1_f64), &Rotation2::from_angle(Degrees(-110_f64)), 3_f64
+        );
+        let source = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(2_f64, 1_f64),
+            Point2::new(-1_f64, 3_f64),
+            Point2::new(5_f64, -2_f64),
+        ];
+        let target = source.map(|point| similarity.transform_point(&point));
+        let (result, residual) = Similarity2::umeyama(&source, &target).unwrap();
+
+        assert!(relative_eq!(result, similarity, epsilon = 1e-10));
+        assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    }
 }
 
 
@@ -461,5 +479,89 @@ mod similarity3_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_lerp_slerp_endpoints() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+        let similarity1 = Similarity3::from_parts(
+            &Translation3::new(1_f64, 2_f64, 3_f64), &Rotation3::from_axis_angle(&axis, Degrees(20_f64)), 0.5_f64
+        );
+        let similarity2 = Similarity3::from_parts(
+            &Translation3::new(-4_f64, 0_f64, 5_f64), &Rotation3::from_axis_angle(&axis, Degrees(140_f64)), 3_f64
+        );
+
+        assert!(relative_eq!(similarity1.lerp_slerp(&similarity2, 0_f64), similarity1, epsilon = 1e-10));
+        assert!(relative_eq!(similarity1.lerp_slerp(&similarity2, 1_f64), similarity2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_lerp_slerp_scale_is_logarithmic() {
+        let similarity1 = Similarity3::from_scale(1_f64);
+        let similarity2 = Similarity3::from_scale(27_f64);
+        let result = similarity1.lerp_slerp(&similarity2, 1_f64 / 3_f64);
+
+        assert!(relative_eq!(result.scale(), 3_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sclerp_endpoints() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, -1_f64));
+        let similarity1 = Similarity3::from_parts(
+            &Translation3::new(1_f64, 2_f64, 3_f64), &Rotation3::from_axis_angle(&axis, Degrees(20_f64)), 0.5_f64
+        );
+        let similarity2 = Similarity3::from_parts(
+            &Translation3::new(-4_f64, 0_f64, 5_f64), &Rotation3::from_axis_angle(&axis, Degrees(140_f64)), 3_f64
+        );
+
+        assert!(relative_eq!(similarity1.sclerp(&similarity2, 0_f64), similarity1, epsilon = 1e-10));
+        assert!(relative_eq!(similarity1.sclerp(&similarity2, 1_f64), similarity2, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_sclerp_isometry_part() {
+        let axis = Unit::from_value(Vector3::unit_x());
+        let isometry2 = Isometry3::from_axis_angle_translation(&axis, Degrees(100_f64), &Vector3::new(1_f64, 2_f64, 3_f64));
+        let similarity1 = Similarity3::from_scale(2_f64);
+        let similarity2 = Similarity3::from_parts(isometry2.translation(), isometry2.rotation(), 8_f64);
+        let expected_isometry = Isometry3::identity().sclerp(&isometry2, 0.25_f64);
+        let result = similarity1.sclerp(&similarity2, 0.25_f64);
+
+        assert!(relative_eq!(result.rotation(), expected_isometry.rotation(), epsilon = 1e-10));
+        assert!(relative_eq!(result.translation(), expected_isometry.translation(), epsilon = 1e-10));
+        assert!(relative_eq!(result.scale(), f64::sqrt(8_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_umeyama_recovers_similarity() {
+        let axis = Unit::from_value(Vector3::new(-2_f64, 1_f64, 3_f64));
+        let similarity = Similarity3::from_parts(
+            &Translation3::new(4_f64, -1_f64, 2_f64), &Rotation3::from_axis_angle(&axis, Degrees(155_f64)), 0.25_f64
+        );
+        let source = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(2_f64, 0_f64, 1_f64),
+            Point3::new(0_f64, 3_f64, -1_f64),
+            Point3::new(1_f64, 1_f64, 4_f64),
+            Point3::new(-2_f64, 5_f64, 2_f64),
+        ];
+        let target = source.map(|point| similarity.transform_point(&point));
+        let (result, residual) = Similarity3::umeyama(&source, &target).unwrap();
+
+        assert!(relative_eq!(result, similarity, epsilon = 1e-10));
+        assert!(relative_eq!(residual, 0_f64, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_umeyama_coincident_source_points() {
+        let source = [Point3::new(1_f64, 2_f64, 3_f64); 4];
+        let target = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(1_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 1_f64, 0_f64),
+            Point3::new(0_f64, 0_f64, 1_f64),
+        ];
+
+        assert!(Similarity3::umeyama(&source, &target).is_none());
+    }
 }
 
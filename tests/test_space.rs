@@ -0,0 +1,138 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod typed_space_tests {
+    use cglinalg::{
+        Degrees,
+        Isometry3,
+        LocalSpace,
+        Point3,
+        TypedIsometry3,
+        TypedPoint3,
+        TypedVector3,
+        Unit,
+        Vector3,
+        ViewSpace,
+        WorldSpace,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_typed_point_transform_matches_untyped() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let translation = Vector3::new(3_f64, -2_f64, 5_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &translation);
+        let model: TypedIsometry3<f64, LocalSpace, WorldSpace> = TypedIsometry3::from_isometry(isometry);
+        let point = Point3::new(1_f64, -2_f64, 3_f64);
+        let typed_point: TypedPoint3<f64, LocalSpace> = TypedPoint3::from_point(point);
+        let expected = isometry.transform_point(&point);
+        let result = model.transform_point(&typed_point).to_untyped();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_typed_vector_transform_matches_untyped() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let translation = Vector3::new(3_f64, -2_f64, 5_f64);
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &translation);
+        let model: TypedIsometry3<f64, LocalSpace, WorldSpace> = TypedIsometry3::from_isometry(isometry);
+        let vector = Vector3::new(-1_f64, 4_f64, 2_f64);
+        let typed_vector: TypedVector3<f64, LocalSpace> = TypedVector3::from_vector(vector);
+        let expected = isometry.transform_vector(&vector);
+        let result = model.transform_vector(&typed_vector).to_untyped();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_typed_isometry_inverse() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let translation = Vector3::new(3_f64, -2_f64, 5_f64);
+        let model_isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &translation);
+        let model: TypedIsometry3<f64, LocalSpace, WorldSpace> = TypedIsometry3::from_isometry(model_isometry);
+        let point: TypedPoint3<f64, LocalSpace> = TypedPoint3::new(1_f64, -2_f64, 3_f64);
+        let world_point = model.transform_point(&point);
+        let result = model.inverse().transform_point(&world_point);
+
+        assert!(relative_eq!(result, point, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_typed_isometry_composition() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let translation = Vector3::new(3_f64, -2_f64, 5_f64);
+        let model_isometry = Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &translation);
+        let eye = Point3::new(-4_f64, 3_f64, 10_f64);
+        let target = Point3::origin();
+        let up = Vector3::unit_y();
+        let view_isometry = Isometry3::look_at_rh(&eye, &target, &up);
+        let model: TypedIsometry3<f64, LocalSpace, WorldSpace> = TypedIsometry3::from_isometry(model_isometry);
+        let view: TypedIsometry3<f64, WorldSpace, ViewSpace> = TypedIsometry3::from_isometry(view_isometry);
+        let point: TypedPoint3<f64, LocalSpace> = TypedPoint3::new(1_f64, -2_f64, 3_f64);
+        let expected = view.transform_point(&model.transform_point(&point));
+        let model_view = view * model;
+        let result = model_view.transform_point(&point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        assert!(relative_eq!(model.then(&view), model_view, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_typed_isometry_identity() {
+        let identity: TypedIsometry3<f64, WorldSpace, WorldSpace> = TypedIsometry3::identity();
+        let point: TypedPoint3<f64, WorldSpace> = TypedPoint3::new(1_f64, -2_f64, 3_f64);
+
+        assert_eq!(identity.transform_point(&point), point);
+    }
+
+    #[test]
+    fn test_typed_point_arithmetic() {
+        let p: TypedPoint3<f64, WorldSpace> = TypedPoint3::new(1_f64, 2_f64, 3_f64);
+        let q: TypedPoint3<f64, WorldSpace> = TypedPoint3::new(4_f64, 6_f64, 8_f64);
+        let v = q - p;
+
+        assert_eq!(v, TypedVector3::new(3_f64, 4_f64, 5_f64));
+        assert_eq!(p + v, q);
+        assert_eq!(q - v, p);
+        assert_eq!(p.to_vector(), TypedVector3::new(1_f64, 2_f64, 3_f64));
+        assert_eq!(TypedPoint3::<f64, WorldSpace>::origin() + p.to_vector(), p);
+    }
+
+    #[test]
+    fn test_typed_vector_arithmetic() {
+        let u: TypedVector3<f64, ViewSpace> = TypedVector3::new(1_f64, 0_f64, 0_f64);
+        let v: TypedVector3<f64, ViewSpace> = TypedVector3::new(0_f64, 1_f64, 0_f64);
+
+        assert_eq!(u + v, TypedVector3::new(1_f64, 1_f64, 0_f64));
+        assert_eq!(u - v, TypedVector3::new(1_f64, -1_f64, 0_f64));
+        assert_eq!(u * 2_f64, TypedVector3::new(2_f64, 0_f64, 0_f64));
+        assert_eq!(-u, TypedVector3::new(-1_f64, 0_f64, 0_f64));
+        assert_eq!(u.dot(&v), 0_f64);
+        assert_eq!(u.cross(&v), TypedVector3::new(0_f64, 0_f64, 1_f64));
+        assert_eq!(TypedVector3::<f64, ViewSpace>::zero(), TypedVector3::new(0_f64, 0_f64, 0_f64));
+    }
+
+    #[test]
+    fn test_typed_point_cast_space() {
+        let point: TypedPoint3<f64, LocalSpace> = TypedPoint3::new(1_f64, 2_f64, 3_f64);
+        let result: TypedPoint3<f64, WorldSpace> = point.cast_space();
+
+        assert_eq!(result.as_point(), point.as_point());
+    }
+
+    #[test]
+    fn test_typed_wrappers_are_zero_cost() {
+        assert_eq!(core::mem::size_of::<TypedPoint3<f64, WorldSpace>>(), core::mem::size_of::<Point3<f64>>());
+        assert_eq!(core::mem::size_of::<TypedVector3<f32, WorldSpace>>(), core::mem::size_of::<Vector3<f32>>());
+        assert_eq!(
+            core::mem::size_of::<TypedIsometry3<f64, LocalSpace, WorldSpace>>(),
+            core::mem::size_of::<Isometry3<f64>>()
+        );
+    }
+}
+
@@ -0,0 +1,192 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod transformation2_tests {
+    use cglinalg::{
+        Transformation2,
+        InvertibleTransformation2,
+        Degrees,
+        Isometry2,
+        Point2,
+        Reflection2,
+        Rotation2,
+        Scale2,
+        Shear2,
+        Similarity2,
+        Transform2,
+        Translation2,
+        Unit,
+        Vector2,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    fn transform_point<T>(transform: &T, point: &Point2<f64>) -> Point2<f64> 
+    where
+        T: Transformation2<Scalar = f64>
+    {
+        transform.transform_point(point)
+    }
+
+    fn round_trip<T>(transform: &T, point: &Point2<f64>, vector: &Vector2<f64>) -> (Point2<f64>, Vector2<f64>)
+    where
+        T: InvertibleTransformation2<Scalar = f64>
+    {
+        let inverse = transform.inverse();
+        let point_result = inverse.transform_point(&transform.transform_point(point));
+        let vector_result = transform.inverse_transform_vector(&transform.transform_vector(vector));
+
+        (point_result, vector_result)
+    }
+
+    fn check_round_trip<T>(transform: &T) 
+    where
+        T: InvertibleTransformation2<Scalar = f64>
+    {
+        let point = Point2::new(3_f64, -2_f64);
+        let vector = Vector2::new(-1_f64, 5_f64);
+        let (point_result, vector_result) = round_trip(transform, &point, &vector);
+
+        assert!(relative_eq!(point_result, point, epsilon = 1e-10));
+        assert!(relative_eq!(vector_result, vector, epsilon = 1e-10));
+        assert!(relative_eq!(
+            transform.inverse_transform_point(&transform.transform_point(&point)), point, epsilon = 1e-10
+        ));
+    }
+
+    fn check_matches_transform<T>(transform: &T) 
+    where
+        T: Transformation2<Scalar = f64>
+    {
+        let point = Point2::new(3_f64, -2_f64);
+        let expected = transform.to_transform().transform_point(&point);
+        let result = transform_point(transform, &point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let normal = Unit::from_value(Vector2::new(1_f64, 1_f64));
+
+        check_round_trip(&Translation2::new(1_f64, 2_f64));
+        check_round_trip(&Rotation2::from_angle(Degrees(72_f64)));
+        check_round_trip(&Scale2::from_nonuniform_scale(2_f64, 5_f64));
+        check_round_trip(&Shear2::from_shear(2_f64, 0.25_f64));
+        check_round_trip(&Reflection2::from_normal_bias(&normal, &Vector2::new(1_f64, 0_f64)));
+        check_round_trip(&Isometry2::from_angle_translation(Degrees(72_f64), &Vector2::new(1_f64, 2_f64)));
+        check_round_trip(&Similarity2::from_parts(
+            &Translation2::new(1_f64, 2_f64), &Rotation2::from_angle(Degrees(72_f64)), 3_f64
+        ));
+    }
+
+    #[test]
+    fn test_matches_transform() {
+        let normal = Unit::from_value(Vector2::new(1_f64, 1_f64));
+
+        check_matches_transform(&Translation2::new(1_f64, 2_f64));
+        check_matches_transform(&Rotation2::from_angle(Degrees(72_f64)));
+        check_matches_transform(&Scale2::from_nonuniform_scale(2_f64, 5_f64));
+        check_matches_transform(&Shear2::from_shear(2_f64, 0.25_f64));
+        check_matches_transform(&Reflection2::from_normal_bias(&normal, &Vector2::new(1_f64, 0_f64)));
+        check_matches_transform(&Transform2::from_matrix_unchecked(
+            Shear2::from_shear(2_f64, 0.25_f64).to_transform().matrix() * 2_f64
+        ));
+    }
+
+    #[test]
+    fn test_identity() {
+        let point = Point2::new(3_f64, -2_f64);
+
+        assert_eq!(<Translation2<f64> as Transformation2>::identity().transform_point(&point), point);
+        assert_eq!(<Similarity2<f64> as Transformation2>::identity().transform_point(&point), point);
+        assert_eq!(<Transform2<f64> as Transformation2>::identity().transform_point(&point), point);
+    }
+}
+
+
+#[cfg(test)]
+mod transformation3_tests {
+    use cglinalg::{
+        Transformation3,
+        InvertibleTransformation3,
+        Degrees,
+        Isometry3,
+        Point3,
+        Reflection3,
+        Rotation3,
+        Scale3,
+        Shear3,
+        Similarity3,
+        Transform3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    fn check_round_trip<T>(transform: &T) 
+    where
+        T: InvertibleTransformation3<Scalar = f64>
+    {
+        let point = Point3::new(3_f64, -2_f64, 1_f64);
+        let vector = Vector3::new(-1_f64, 5_f64, 2_f64);
+        let inverse = transform.inverse();
+
+        assert!(relative_eq!(inverse.transform_point(&transform.transform_point(&point)), point, epsilon = 1e-10));
+        assert!(relative_eq!(
+            transform.inverse_transform_point(&transform.transform_point(&point)), point, epsilon = 1e-10
+        ));
+        assert!(relative_eq!(
+            transform.inverse_transform_vector(&transform.transform_vector(&vector)), vector, epsilon = 1e-10
+        ));
+    }
+
+    fn check_matches_transform<T>(transform: &T) 
+    where
+        T: Transformation3<Scalar = f64>
+    {
+        let point = Point3::new(3_f64, -2_f64, 1_f64);
+        let vector = Vector3::new(-1_f64, 5_f64, 2_f64);
+        let generic = transform.to_transform();
+
+        assert!(relative_eq!(transform.transform_point(&point), generic.transform_point(&point), epsilon = 1e-10));
+        assert!(relative_eq!(transform.transform_vector(&vector), generic.transform_vector(&vector), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+
+        check_round_trip(&Translation3::new(1_f64, 2_f64, 3_f64));
+        check_round_trip(&rotation);
+        check_round_trip(&Scale3::from_nonuniform_scale(2_f64, 5_f64, 0.5_f64));
+        check_round_trip(&Shear3::from_shear_x(2_f64, 0.5_f64));
+        check_round_trip(&Reflection3::from_normal_bias(&axis, &Vector3::new(1_f64, 0_f64, 0_f64)));
+        check_round_trip(&Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &Vector3::new(1_f64, 2_f64, 3_f64)));
+        check_round_trip(&Similarity3::from_parts(&Translation3::new(1_f64, 2_f64, 3_f64), &rotation, 3_f64));
+    }
+
+    #[test]
+    fn test_matches_transform() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(72_f64));
+
+        check_matches_transform(&Translation3::new(1_f64, 2_f64, 3_f64));
+        check_matches_transform(&rotation);
+        check_matches_transform(&Scale3::from_nonuniform_scale(2_f64, 5_f64, 0.5_f64));
+        check_matches_transform(&Shear3::from_shear_x(2_f64, 0.5_f64));
+        check_matches_transform(&Reflection3::from_normal_bias(&axis, &Vector3::new(1_f64, 0_f64, 0_f64)));
+        check_matches_transform(&Isometry3::from_axis_angle_translation(&axis, Degrees(72_f64), &Vector3::new(1_f64, 2_f64, 3_f64)));
+        check_matches_transform(&Similarity3::from_parts(&Translation3::new(1_f64, 2_f64, 3_f64), &rotation, 3_f64));
+        check_matches_transform(&Transform3::identity());
+    }
+}
+
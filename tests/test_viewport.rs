@@ -0,0 +1,125 @@
+extern crate cglinalg;
+
+
+#[cfg(test)]
+mod viewport_tests {
+    use cglinalg::{
+        Degrees,
+        Orthographic3,
+        Perspective3,
+        PerspectiveFov3,
+        Point3,
+        Viewport,
+        ViewportOrigin,
+    };
+    use approx::{
+        relative_eq,
+    };
+
+
+    #[test]
+    fn test_ndc_corners_top_left() {
+        let viewport = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft);
+
+        assert_eq!(viewport.ndc_to_window(&Point3::new(-1_f64, 1_f64, -1_f64)), Point3::new(16_f64, 8_f64, 0_f64));
+        assert_eq!(viewport.ndc_to_window(&Point3::new(1_f64, -1_f64, 1_f64)), Point3::new(1296_f64, 728_f64, 1_f64));
+        assert_eq!(viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, 0_f64)), Point3::new(656_f64, 368_f64, 0.5_f64));
+    }
+
+    #[test]
+    fn test_ndc_corners_bottom_left() {
+        let viewport = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::BottomLeft);
+
+        assert_eq!(viewport.ndc_to_window(&Point3::new(-1_f64, -1_f64, -1_f64)), Point3::new(16_f64, 8_f64, 0_f64));
+        assert_eq!(viewport.ndc_to_window(&Point3::new(1_f64, 1_f64, 1_f64)), Point3::new(1296_f64, 728_f64, 1_f64));
+    }
+
+    #[test]
+    fn test_origins_mirror_each_other() {
+        let viewport_top_left = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft);
+        let viewport_bottom_left = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::BottomLeft);
+        let point = Point3::new(0.3_f64, 0.6_f64, -0.2_f64);
+        let top_left = viewport_top_left.ndc_to_window(&point);
+        let bottom_left = viewport_bottom_left.ndc_to_window(&point);
+
+        assert_eq!(top_left.x, bottom_left.x);
+        assert_eq!(top_left.z, bottom_left.z);
+        assert!(relative_eq!(top_left.y - 8_f64, 720_f64 - (bottom_left.y - 8_f64), epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_depth_range() {
+        let viewport = Viewport::new(0_f64, 0_f64, 100_f64, 100_f64, 0.2_f64, 0.6_f64, ViewportOrigin::TopLeft);
+
+        assert!(relative_eq!(viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, -1_f64)).z, 0.2_f64, epsilon = 1e-12));
+        assert!(relative_eq!(viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, 1_f64)).z, 0.6_f64, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_reversed_depth_range() {
+        let viewport = Viewport::new(0_f64, 0_f64, 100_f64, 100_f64, 1_f64, 0_f64, ViewportOrigin::TopLeft);
+        let point = Point3::new(0.2_f64, -0.1_f64, -0.5_f64);
+
+        assert!(relative_eq!(viewport.ndc_to_window(&point).z, 0.75_f64, epsilon = 1e-12));
+        assert!(relative_eq!(viewport.window_to_ndc(&viewport.ndc_to_window(&point)), point, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_window_to_ndc_inverts_ndc_to_window() {
+        let viewport_top_left = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft);
+        let viewport_bottom_left = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::BottomLeft);
+        let point = Point3::new(-0.35_f64, 0.8_f64, 0.1_f64);
+        for viewport in [viewport_top_left, viewport_bottom_left].iter() {
+            let result = viewport.window_to_ndc(&viewport.ndc_to_window(&point));
+
+            assert!(relative_eq!(result, point, epsilon = 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_matrix_agrees_with_ndc_to_window() {
+        let viewport_top_left = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft);
+        let viewport_bottom_left = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::BottomLeft);
+        let point = Point3::new(-0.35_f64, 0.8_f64, 0.1_f64);
+        for viewport in [viewport_top_left, viewport_bottom_left].iter() {
+            let expected = viewport.ndc_to_window(&point);
+            let result = Point3::from_homogeneous(viewport.to_matrix() * point.to_homogeneous()).unwrap();
+
+            assert!(relative_eq!(result, expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_project_unproject_perspective() {
+        let viewport = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::TopLeft);
+        let perspective = Perspective3::new(-0.4_f64, 0.6_f64, -0.3_f64, 0.35_f64, 0.5_f64, 100_f64);
+        let point = Point3::new(1.5_f64, -0.75_f64, -6_f64);
+        let window_point = viewport.project_point(&perspective, &point);
+        let result = viewport.unproject_point(&perspective, &window_point);
+
+        assert!(relative_eq!(result, point, epsilon = 1e-8));
+    }
+
+    #[test]
+    fn test_project_unproject_perspective_fov() {
+        let viewport = Viewport::new(16_f64, 8_f64, 1280_f64, 720_f64, 0_f64, 1_f64, ViewportOrigin::BottomLeft);
+        let perspective = PerspectiveFov3::new(Degrees(60_f64), viewport.aspect(), 0.1_f64, 50_f64);
+        let point = Point3::new(-0.5_f64, 0.25_f64, -3_f64);
+        let window_point = viewport.project_point(&perspective, &point);
+        let result = viewport.unproject_point(&perspective, &window_point);
+
+        assert!(relative_eq!(result, point, epsilon = 1e-8));
+    }
+
+    #[test]
+    fn test_project_orthographic() {
+        let viewport = Viewport::from_size(800_f64, 600_f64, ViewportOrigin::TopLeft);
+        let orthographic = Orthographic3::new(-4_f64, 4_f64, -3_f64, 3_f64, 1_f64, 101_f64);
+        let point = Point3::new(2_f64, 1.5_f64, -51_f64);
+        let expected = Point3::new(600_f64, 150_f64, 0.5_f64);
+        let result = viewport.project_point(&orthographic, &point);
+
+        assert!(relative_eq!(result, expected, epsilon = 1e-10));
+    }
+}
+